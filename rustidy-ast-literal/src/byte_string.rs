@@ -37,7 +37,7 @@ impl ByteStringLiteral {
 
 		loop {
 			match s.strip_prefix(
-				|ch: char| ch.is_ascii() && !matches!(ch, '"' | '\\' | '\r')
+				|ch: char| ch.is_ascii() && !matches!(ch, '"' | '\\' | '\r'),
 			) {
 				Some(rest) => *s = rest,
 				None => {
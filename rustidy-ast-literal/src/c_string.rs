@@ -40,7 +40,7 @@ impl CStringLiteral {
 
 		loop {
 			match s.strip_prefix(
-				|ch: char| !matches!(ch, '"' | '\\' | '\r' | '\0')
+				|ch: char| !matches!(ch, '"' | '\\' | '\r' | '\0'),
 			) {
 				Some(rest) => *s = rest,
 				None => {
@@ -35,7 +35,7 @@ impl CharLiteral {
 			.strip_prefix('\'')
 			.ok_or(CharLiteralError::StartQuote)?;
 		match s.strip_prefix(
-			|ch| !matches!(ch, '\'' | '\\' | '\n' | '\r' | '\t')
+			|ch| !matches!(ch, '\'' | '\\' | '\n' | '\r' | '\t'),
 		) {
 			Some(rest) => *s = rest,
 			None => {
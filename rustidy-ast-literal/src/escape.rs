@@ -57,7 +57,9 @@ impl AsciiEscape {
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
-#[parse(error(name = Escape, fmt = "Expected `\\xXX`, `\\n`, `\\r`, `\\t`, `\\\\`, `\\0`, `'` or `\"`"))]
+#[parse(
+	error(name = Escape, fmt = "Expected `\\xXX`, `\\n`, `\\r`, `\\t`, `\\\\`, `\\0`, `'` or `\"`")
+)]
 #[parse(error(name = Hex, fmt = "Expected hex digit"))]
 pub struct ByteEscape(#[parse(try_update_with = Self::parse)] #[format(str)] pub AstStr);
 
@@ -46,12 +46,12 @@ impl Parse for FloatLiteral {
 
 		let (dot, frac) = match parser.with_tag(
 			ParserTag::SkipWhitespace,
-			Parser::try_parse::<ast_token::Dot>
+			Parser::try_parse::<ast_token::Dot>,
 		)? {
 			Ok(dot) => match parser.try_parse::<DecLiteral>()? {
 				Ok(frac) => (Some(dot), Some(frac)),
 				Err(_) => match parser.remaining().starts_with(
-					|ch| matches!(ch, '.' | '_') || unicode_ident::is_xid_start(ch)
+					|ch| matches!(ch, '.' | '_') || unicode_ident::is_xid_start(ch),
 				) {
 					true => return Err(Self::Error::FractionalPartMissing),
 					false => (Some(dot), None),
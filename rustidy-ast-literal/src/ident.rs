@@ -47,12 +47,12 @@ impl Identifier {
 		}
 	}
 
-	/// Returns the identifier length not including whitespace
+	/// Returns the identifier display width not including whitespace
 	#[must_use]
-	pub fn non_ws_len(&self) -> usize {
+	pub fn non_ws_width(&self, tab_width: usize) -> usize {
 		match self {
-			Self::Raw(ident) => ident.1.len(),
-			Self::NonKw(ident) => ident.0.1.len(),
+			Self::Raw(ident) => ident.1.width(tab_width),
+			Self::NonKw(ident) => ident.0.1.width(tab_width),
 		}
 	}
 }
@@ -66,7 +66,10 @@ impl Identifier {
 pub struct NonKeywordIdentifier(pub IdentifierOrKeyword);
 
 impl NonKeywordIdentifier {
-	pub fn check_strict_reserved(&mut self, _parser: &mut Parser) -> Result<(), NonKeywordIdentifierError> {
+	pub fn check_strict_reserved(
+		&mut self,
+		_parser: &mut Parser,
+	) -> Result<(), NonKeywordIdentifierError> {
 		if STRICT_OR_RESERVED_KEYWORDS
 			.contains(&&*self.0.1.str()) {
 			return Err(NonKeywordIdentifierError::StrictOrReserved);
@@ -55,7 +55,7 @@ impl<T: Parse> QuoteNotQuote<T> {
 		if parser
 			.with_tag(
 				ParserTag::SkipWhitespace,
-				Parser::try_parse::<ast_token::Quote>
+				Parser::try_parse::<ast_token::Quote>,
 			)
 			.map_err(QuoteNotQuoteError::Quote)?
 			.is_ok() {
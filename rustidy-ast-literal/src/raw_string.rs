@@ -6,6 +6,7 @@ use {
 	format::{Format, Formattable},
 	parse::Parse,
 	print::Print,
+	std::borrow::Cow,
 	util::{AstStr, Whitespace},
 };
 
@@ -27,6 +28,30 @@ pub struct RawStringLiteral {
 }
 
 impl RawStringLiteral {
+	/// Returns the contents of this string.
+	///
+	/// Doesn't include the quotes, hashes or suffix
+	#[must_use]
+	pub fn contents(&self) -> Cow<'_, str> {
+		let mut s = self.s.str();
+
+		// Note: The prefix is `r`, followed by the hashes and the quote,
+		//       while the suffix is the quote followed by the hashes.
+		let prefix_len = s
+			.find('"')
+			.expect("Raw string literal had no quotes") + 1;
+		let suffix_len = prefix_len - 1;
+		match &mut s {
+			Cow::Borrowed(s) => *s = &s[prefix_len..s.len() - suffix_len],
+			Cow::Owned(s) => {
+				s.truncate(s.len() - suffix_len);
+				s.drain(..prefix_len);
+			},
+		}
+
+		s
+	}
+
 	fn parse(s: &mut &str) -> Result<(), RawStringLiteralError> {
 		*s = s
 			.strip_prefix('r')
@@ -55,3 +80,34 @@ impl RawStringLiteral {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use {super::*, parse::Parser};
+
+	#[test]
+	fn contents() {
+		let cases = [
+			("r\"\"", ""),
+			("r\"a\"", "a"),
+			("r\"\\n\"", "\\n"),
+			("r#\"\"\"#", "\""),
+			("r##\"a\"#b\"##", "a\"#b"),
+		];
+
+		for (input, contents_expected) in cases {
+			let mut parser = Parser::new(input);
+			let literal = parser
+				.parse::<RawStringLiteral>()
+				.unwrap_or_else(
+					|err| panic!("Unable to parse input case {input:?}: {err:?}"),
+				);
+
+			let contents_found = literal.contents();
+			assert_eq!(
+				contents_found, contents_expected,
+				"Found wrong contents for string {input:?}"
+			);
+		}
+	}
+}
@@ -130,7 +130,7 @@ mod tests {
 			let literal = parser
 				.parse::<StringLiteral>()
 				.unwrap_or_else(
-					|err| panic!("Unable to parse input case {input:?}: {err:?}")
+					|err| panic!("Unable to parse input case {input:?}: {err:?}"),
 				);
 
 			let contents_found = literal.contents();
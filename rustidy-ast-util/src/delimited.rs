@@ -37,8 +37,30 @@ impl<T, L, R> Delimited<T, L, R> {
 	}
 }
 
+impl<T, L, R: Formattable> Delimited<T, L, R> {
+	/// Returns whether the suffix is on a different line than the value.
+	///
+	/// This is the case for values that were formatted one per line.
+	pub fn is_suffix_on_new_line(&mut self, ctx: &mut format::Context) -> bool {
+		self
+			.suffix
+			.prefix_ws_has_newlines(ctx)
+			.unwrap_or(false)
+	}
+}
+
 // TODO: Create another impl where we don't care about empty/non-empty?
-impl<T, L, R, LPrefixWs, TPrefixWs, RPrefixWs, LArgs, TArgs, RArgs> Format<LPrefixWs, FmtArgs<TPrefixWs, RPrefixWs, LArgs, TArgs, RArgs>> for Delimited<T, L, R>
+impl<
+	T,
+	L,
+	R,
+	LPrefixWs,
+	TPrefixWs,
+	RPrefixWs,
+	LArgs,
+	TArgs,
+	RArgs,
+> Format<LPrefixWs, FmtArgs<TPrefixWs, RPrefixWs, LArgs, TArgs, RArgs>> for Delimited<T, L, R>
 where
 	L: Format<LPrefixWs, LArgs>,
 	T: Format<TPrefixWs, TArgs>,
@@ -53,6 +75,7 @@ where
 	) -> FormatOutput {
 		// TODO: Should we handle the case of the prefix being empty and needing to
 		//       pass the prefix whitespace along?
+		let column = ctx.column();
 		let mut output = ctx
 			.format_with(&mut self.prefix, prefix_ws, args.prefix_args);
 		if !output.has_prefix_ws() {
@@ -60,17 +83,22 @@ where
 		}
 
 		ctx.with_indent_if(args.indent, |ctx| {
-			let value_output = ctx.format_with(
-				&mut self.value,
-				args.value_non_blank,
-				args.value_args.clone()
-			);
-			let value_output = match value_output.is_blank {
-				true => ctx.format_with(
+			ctx.set_column(output.end_column(column));
+			// Note: Skip regions can't extend past the delimiters, even if they're
+			//       unterminated, so we start each pass outside of one.
+			let value_output = ctx
+				.without_tag::<format::tag::SkipRegion, _>(|ctx| ctx.format_with(
 					&mut self.value,
-					args.value_blank,
-					args.value_args
-				),
+					args.value_non_blank,
+					args.value_args.clone(),
+				));
+			let value_output = match value_output.is_blank {
+				true => ctx
+					.without_tag::<format::tag::SkipRegion, _>(|ctx| ctx.format_with(
+						&mut self.value,
+						args.value_blank,
+						args.value_args,
+					)),
 				false => value_output,
 			};
 			value_output.append_to(&mut output);
@@ -79,12 +107,14 @@ where
 				true => args.suffix_blank,
 				false => args.suffix_non_blank,
 			};
+			ctx.set_column(output.end_column(column));
 			ctx.format_with(
 				&mut self.suffix,
 				suffix_prefix_ws,
-				args.suffix_args
+				args.suffix_args,
 			).append_to(&mut output);
 		});
+		ctx.set_column(column);
 
 		output
 	}
@@ -108,7 +138,11 @@ pub struct FmtArgs<TPrefixWs, SPrefixWs, LArgs, TArgs, RArgs> {
 }
 
 #[must_use]
-pub const fn fmt_preserve_with<LArgs, TArgs, RArgs>(prefix_args: LArgs, value_args: TArgs, suffix_args: RArgs,) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, LArgs, TArgs, RArgs> {
+pub const fn fmt_preserve_with<LArgs, TArgs, RArgs>(
+	prefix_args: LArgs,
+	value_args: TArgs,
+	suffix_args: RArgs,
+) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, LArgs, TArgs, RArgs> {
 	FmtArgs {
 		indent: false,
 
@@ -131,7 +165,11 @@ pub const fn fmt_preserve() -> FmtArgs<WhitespaceConfig, WhitespaceConfig, (), (
 }
 
 #[must_use]
-pub const fn fmt_single_if_non_blank_with<LArgs, TArgs, RArgs>(prefix_args: LArgs, value_args: TArgs, suffix_args: RArgs,) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, LArgs, TArgs, RArgs> {
+pub const fn fmt_single_if_non_blank_with<LArgs, TArgs, RArgs>(
+	prefix_args: LArgs,
+	value_args: TArgs,
+	suffix_args: RArgs,
+) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, LArgs, TArgs, RArgs> {
 	FmtArgs {
 		indent: false,
 
@@ -148,7 +186,9 @@ pub const fn fmt_single_if_non_blank_with<LArgs, TArgs, RArgs>(prefix_args: LArg
 }
 
 #[must_use]
-pub const fn fmt_single_if_non_blank_with_value<TArgs>(value_args: TArgs) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, (), TArgs, ()> {
+pub const fn fmt_single_if_non_blank_with_value<TArgs>(
+	value_args: TArgs,
+) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, (), TArgs, ()> {
 	self::fmt_single_if_non_blank_with((), value_args, ())
 }
 
@@ -158,7 +198,11 @@ pub const fn fmt_single_if_non_blank() -> FmtArgs<WhitespaceConfig, WhitespaceCo
 }
 
 #[must_use]
-pub const fn fmt_indent_if_non_blank_with<LArgs, TArgs, RArgs>(prefix_args: LArgs, value_args: TArgs, suffix_args: RArgs,) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, LArgs, TArgs, RArgs> {
+pub const fn fmt_indent_if_non_blank_with<LArgs, TArgs, RArgs>(
+	prefix_args: LArgs,
+	value_args: TArgs,
+	suffix_args: RArgs,
+) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, LArgs, TArgs, RArgs> {
 	FmtArgs {
 		indent: true,
 
@@ -175,7 +219,9 @@ pub const fn fmt_indent_if_non_blank_with<LArgs, TArgs, RArgs>(prefix_args: LArg
 }
 
 #[must_use]
-pub const fn fmt_indent_if_non_blank_with_value<TArgs>(value_args: TArgs) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, (), TArgs, ()> {
+pub const fn fmt_indent_if_non_blank_with_value<TArgs>(
+	value_args: TArgs,
+) -> FmtArgs<WhitespaceConfig, WhitespaceConfig, (), TArgs, ()> {
 	self::fmt_indent_if_non_blank_with((), value_args, ())
 }
 
@@ -186,30 +232,49 @@ pub const fn fmt_indent_if_non_blank() -> FmtArgs<WhitespaceConfig, WhitespaceCo
 
 pub struct FmtRemoveWith<TArgs>(pub TArgs);
 
-impl<T: Format<WhitespaceConfig, TArgs>, L: Format<WhitespaceConfig, ()>, R: Format<WhitespaceConfig, ()>, TArgs> Format<WhitespaceConfig, FmtRemoveWith<TArgs>> for Delimited<T, L, R> {
+impl<
+	T: Format<WhitespaceConfig, TArgs>,
+	L: Format<WhitespaceConfig, ()>,
+	R: Format<WhitespaceConfig, ()>,
+	TArgs,
+> Format<WhitespaceConfig, FmtRemoveWith<TArgs>> for Delimited<T, L, R> {
 	fn format(
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		args: FmtRemoveWith<TArgs>
+		args: FmtRemoveWith<TArgs>,
 	) -> FormatOutput {
-		#[rustidy::config(max_chain_len = 100)]
-		FormatOutput::from([
-			ctx.format(&mut self.prefix, prefix_ws),
-			ctx.format_with(&mut self.value, Whitespace::REMOVE, args.0),
-			ctx.format(&mut self.suffix, Whitespace::REMOVE),
-		])
+		let column = ctx.column();
+		let mut output = ctx.format(&mut self.prefix, prefix_ws);
+
+		ctx.set_column(output.end_column(column));
+		ctx.without_tag::<format::tag::SkipRegion, _>(
+			|ctx| ctx
+				.format_with(&mut self.value, Whitespace::REMOVE, args.0),
+		).append_to(&mut output);
+
+		ctx.set_column(output.end_column(column));
+		ctx
+			.format(&mut self.suffix, Whitespace::REMOVE)
+			.append_to(&mut output);
+		ctx.set_column(column);
+
+		output
 	}
 }
 
 pub struct FmtRemove;
 
-impl<T: Format<WhitespaceConfig, ()>, L: Format<WhitespaceConfig, ()>, R: Format<WhitespaceConfig, ()>> Format<WhitespaceConfig, FmtRemove> for Delimited<T, L, R> {
+impl<
+	T: Format<WhitespaceConfig, ()>,
+	L: Format<WhitespaceConfig, ()>,
+	R: Format<WhitespaceConfig, ()>,
+> Format<WhitespaceConfig, FmtRemove> for Delimited<T, L, R> {
 	fn format(
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		_args: FmtRemove
+		_args: FmtRemove,
 	) -> FormatOutput {
 		self.format(ctx, prefix_ws, FmtRemoveWith(()))
 	}
@@ -230,7 +295,7 @@ pub struct FmtArgsSingleOrIndentIfNonBlank<TArgs> {
 pub const fn fmt_single_or_indent_if_non_blank<TArgs>(
 	max_len: usize,
 	value_args_single: TArgs,
-	value_args_indent: TArgs
+	value_args_indent: TArgs,
 ) -> FmtArgsSingleOrIndentIfNonBlank<TArgs> {
 	FmtArgsSingleOrIndentIfNonBlank {
 		force_indent_on_multiline: false,
@@ -246,28 +311,40 @@ where
 	L: Format<WhitespaceConfig, ()>,
 	T: Format<WhitespaceConfig, TArgs>,
 	R: Format<WhitespaceConfig, ()>,
-	TArgs: Clone {
+	TArgs: Clone, {
 	fn format(
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		args: FmtArgsSingleOrIndentIfNonBlank<TArgs>
+		args: FmtArgsSingleOrIndentIfNonBlank<TArgs>,
 	) -> FormatOutput {
-		let output = self.format(
-			ctx,
-			prefix_ws,
-			fmt_single_if_non_blank_with_value(args.value_args_single)
-		);
-
-		let should_indent = (args.force_indent_on_multiline && output.multiline.is_some()) || output.len_non_multiline_ws() > args.max_len;
-		match should_indent {
-			true => self.format(
+		let format_single = |this: &mut Self, ctx: &mut format::Context| {
+			let value_args = args.value_args_single.clone();
+			this.format(
 				ctx,
 				prefix_ws,
-				self::fmt_indent_if_non_blank_with_value(args.value_args_indent)
-			),
-			false => output,
-		}
+				self::fmt_single_if_non_blank_with_value(value_args),
+			)
+		};
+		let should_indent = |output: &FormatOutput| {
+			let is_multiline = args.force_indent_on_multiline && output.multiline.is_some();
+			is_multiline || output.len_non_multiline_ws() > args.max_len
+		};
+		let format_indent = |this: &mut Self, ctx: &mut format::Context| {
+			let value_args = args.value_args_indent;
+			this.format(
+				ctx,
+				prefix_ws,
+				self::fmt_indent_if_non_blank_with_value(value_args),
+			)
+		};
+
+		ctx.format_single_or_break(
+			self,
+			format_single,
+			should_indent,
+			format_indent,
+		)
 	}
 }
 
@@ -286,7 +363,7 @@ pub struct FmtArgsRemoveOrIndentIfNonBlank<TArgs> {
 pub const fn fmt_remove_or_indent_if_non_blank<TArgs>(
 	max_len: usize,
 	value_args_remove: TArgs,
-	value_args_indent: TArgs
+	value_args_indent: TArgs,
 ) -> FmtArgsRemoveOrIndentIfNonBlank<TArgs> {
 	FmtArgsRemoveOrIndentIfNonBlank {
 		force_indent_on_multiline: false,
@@ -301,27 +378,36 @@ where
 	L: Format<WhitespaceConfig, ()>,
 	T: Format<WhitespaceConfig, TArgs>,
 	R: Format<WhitespaceConfig, ()>,
-	TArgs: Clone {
+	TArgs: Clone, {
 	fn format(
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		args: FmtArgsRemoveOrIndentIfNonBlank<TArgs>
+		args: FmtArgsRemoveOrIndentIfNonBlank<TArgs>,
 	) -> FormatOutput {
-		let output = self.format(
-			ctx,
-			prefix_ws,
-			FmtRemoveWith(args.value_args_remove)
-		);
-
-		let should_indent = (args.force_indent_on_multiline && output.multiline.is_some()) || output.len_non_multiline_ws() > args.max_len;
-		match should_indent {
-			true => self.format(
+		let format_remove = |this: &mut Self, ctx: &mut format::Context| {
+			let value_args = args.value_args_remove.clone();
+			this
+				.format(ctx, prefix_ws, FmtRemoveWith(value_args))
+		};
+		let should_indent = |output: &FormatOutput| {
+			let is_multiline = args.force_indent_on_multiline && output.multiline.is_some();
+			is_multiline || output.len_non_multiline_ws() > args.max_len
+		};
+		let format_indent = |this: &mut Self, ctx: &mut format::Context| {
+			let value_args = args.value_args_indent;
+			this.format(
 				ctx,
 				prefix_ws,
-				self::fmt_indent_if_non_blank_with_value(args.value_args_indent)
-			),
-			false => output,
-		}
+				self::fmt_indent_if_non_blank_with_value(value_args),
+			)
+		};
+
+		ctx.format_single_or_break(
+			self,
+			format_remove,
+			should_indent,
+			format_indent,
+		)
 	}
 }
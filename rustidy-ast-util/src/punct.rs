@@ -60,7 +60,10 @@ impl<T, P> Punctuated<T, P> {
 	}
 
 	/// Splits this punctuated at the first value
-	pub fn split_first_mut(&mut self,) -> (&mut T, impl DoubleEndedIterator<Item = (&mut P, &mut T)> + ExactSizeIterator,) {
+	pub fn split_first_mut(
+		&mut self,
+	) -> (&mut T, impl DoubleEndedIterator<Item = (&mut P, &mut T)>
+		+ ExactSizeIterator,) {
 		(&mut self.first, self
 			.rest
 			.iter_mut()
@@ -189,7 +192,7 @@ impl<T, P> PunctuatedTrailing<T, P> {
 	/// Extends this container with another punctuated trailing
 	pub fn extend_from_punctuated_trailing(&mut self, other: Self)
 	where
-		P: Default
+		P: Default,
 	{
 		self.punctuated.rest.push(PunctuatedRest {
 			punct: self.trailing.take().unwrap_or_default(),
@@ -233,7 +236,7 @@ impl<T, P> PunctuatedTrailing<T, P> {
 	/// Uses this punctuated as a `Vec<(T, Option<P>)>`
 	fn with_values_vec(&mut self, f: impl FnOnce(&mut Vec<(T, Option<P>)>))
 	where
-		P: Default
+		P: Default,
 	{
 		replace_with::replace_with_or_abort(self, |this| {
 			let mut values = vec![];
@@ -277,10 +280,10 @@ impl<T, P> PunctuatedTrailing<T, P> {
 	/// Sorts the values in this punctuated by a key
 	pub fn sort_values_by_key(
 		&mut self,
-		mut f: impl for<'a> FnMut<(&'a T, Option<&'a P>), Output: Ord>
+		mut f: impl for<'a> FnMut<(&'a T, Option<&'a P>), Output: Ord>,
 	)
 	where
-		P: Default
+		P: Default,
 	{
 		self.with_values_vec(|values| values.sort_by(
 			|(lhs_value, lhs_punct), (rhs_value, rhs_punct)| {
@@ -288,7 +291,7 @@ impl<T, P> PunctuatedTrailing<T, P> {
 				let rhs = f(rhs_value, rhs_punct.as_ref());
 
 				lhs.cmp(&rhs)
-			}
+			},
 		));
 	}
 
@@ -420,24 +423,30 @@ pub struct FmtIndentColumns {
 	pub columns: Option<usize>,
 }
 
-impl<T: Format<WhitespaceConfig, ()>, P: Format<WhitespaceConfig, ()>> Format<WhitespaceConfig, FmtIndentColumns> for Punctuated<T, P> {
+impl<
+	T: Format<WhitespaceConfig, ()>,
+	P: Format<WhitespaceConfig, ()>,
+> Format<WhitespaceConfig, FmtIndentColumns> for Punctuated<T, P> {
 	fn format(
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		args: FmtIndentColumns
+		args: FmtIndentColumns,
 	) -> format::FormatOutput {
 		let mut output = FormatOutput::default();
+		let column = ctx.column();
 
 		let mut cur_idx = 0;
 		let mut prefix_ws = Some(prefix_ws);
 		'values: while let Some(first) = self.value_mut(cur_idx) {
+			ctx.set_column(output.end_column(column));
 			ctx
 				.format(first, prefix_ws.unwrap_or(Whitespace::INDENT))
 				.append_to(&mut output);
 			prefix_ws.take_if(|_| !output.is_empty);
 
 			let Some(punct) = self.punct_mut(cur_idx) else { break };
+			ctx.set_column(output.end_column(column));
 			ctx
 				.format(punct, prefix_ws.unwrap_or(Whitespace::REMOVE))
 				.append_to(&mut output);
@@ -448,12 +457,14 @@ impl<T: Format<WhitespaceConfig, ()>, P: Format<WhitespaceConfig, ()>> Format<Wh
 			let row_rest_len = row_len.saturating_sub(1);
 			for _ in 0..row_rest_len {
 				let Some(value) = self.value_mut(cur_idx) else { break 'values };
+				ctx.set_column(output.end_column(column));
 				ctx
 					.format(value, prefix_ws.unwrap_or(Whitespace::SINGLE))
 					.append_to(&mut output);
 				prefix_ws.take_if(|_| !output.is_empty);
 
 				let Some(punct) = self.punct_mut(cur_idx) else { break 'values };
+				ctx.set_column(output.end_column(column));
 				ctx
 					.format(punct, prefix_ws.unwrap_or(Whitespace::REMOVE))
 					.append_to(&mut output);
@@ -462,25 +473,93 @@ impl<T: Format<WhitespaceConfig, ()>, P: Format<WhitespaceConfig, ()>> Format<Wh
 				cur_idx += 1;
 			}
 		}
+		ctx.set_column(column);
 
 		output
 	}
 }
 
-impl<T: Format<WhitespaceConfig, ()>, P: Format<WhitespaceConfig, ()>> Format<WhitespaceConfig, FmtIndentColumns> for PunctuatedTrailing<T, P> {
+impl<
+	T: Format<WhitespaceConfig, ()>,
+	P: Format<WhitespaceConfig, ()>,
+> Format<WhitespaceConfig, FmtIndentColumns> for PunctuatedTrailing<T, P> {
 	fn format(
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		args: FmtIndentColumns
+		args: FmtIndentColumns,
 	) -> format::FormatOutput {
-		let mut output = FormatOutput::default();
+		let column = ctx.column();
+		let mut output = ctx
+			.format_with(&mut self.punctuated, prefix_ws, args);
+
+		ctx.set_column(output.end_column(column));
 		ctx
-			.format_with(&mut self.punctuated, prefix_ws, args)
+			.format(&mut self.trailing, Whitespace::REMOVE)
 			.append_to(&mut output);
+		ctx.set_column(column);
+
+		output
+	}
+}
+
+/// Formats each punctuation on a new line, with the values after the first indented.
+///
+/// The first value is kept at the current indentation, since it stays on the
+/// same line as whatever comes before.
+#[derive(Clone, Copy, Debug)]
+pub struct FmtBreakIndentRest;
+
+impl<
+	T: Format<WhitespaceConfig, ()>,
+	P: Format<WhitespaceConfig, ()>,
+> Format<WhitespaceConfig, FmtBreakIndentRest> for Punctuated<T, P> {
+	fn format(
+		&mut self,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		_args: FmtBreakIndentRest,
+	) -> format::FormatOutput {
+		let column = ctx.column();
+		let mut output = ctx.format(&mut self.first, prefix_ws);
+		ctx.with_indent(|ctx| {
+			for PunctuatedRest { punct, value } in &mut self.rest {
+				ctx.set_column(output.end_column(column));
+				ctx
+					.format(punct, Whitespace::INDENT)
+					.append_to(&mut output);
+
+				ctx.set_column(output.end_column(column));
+				ctx
+					.format(value, Whitespace::SINGLE)
+					.append_to(&mut output);
+			}
+		});
+		ctx.set_column(column);
+
+		output
+	}
+}
+
+impl<
+	T: Format<WhitespaceConfig, ()>,
+	P: Format<WhitespaceConfig, ()>,
+> Format<WhitespaceConfig, FmtBreakIndentRest> for PunctuatedTrailing<T, P> {
+	fn format(
+		&mut self,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		args: FmtBreakIndentRest,
+	) -> format::FormatOutput {
+		let column = ctx.column();
+		let mut output = ctx
+			.format_with(&mut self.punctuated, prefix_ws, args);
+
+		ctx.set_column(output.end_column(column));
 		ctx
 			.format(&mut self.trailing, Whitespace::REMOVE)
 			.append_to(&mut output);
+		ctx.set_column(column);
 
 		output
 	}
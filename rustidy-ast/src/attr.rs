@@ -14,15 +14,16 @@ use {
 		path::SimplePath,
 		util::{Braced, Bracketed, Parenthesized},
 	},
-	self::meta::MetaItem,
-	app_error::{AppError, Context, bail},
+	self::meta::{MetaItem, MetaItemInner},
+	app_error::{AppError, Context, app_error, bail},
 	ast_literal::Token,
 	ast_util::{Longest, RemainingBlockComment, RemainingLine, delimited},
-	core::fmt::Debug,
-	format::{Format, Formattable, WhitespaceFormat},
-	parse::{ParsableFrom, Parse, ParserTag},
+	core::{fmt::Debug, ops::ControlFlow},
+	format::{Format, FormatWarning, Formattable, WhitespaceFormat},
+	itertools::Itertools,
+	parse::{ParsableFrom, Parse, Parser, ParserTag},
 	print::Print,
-	util::{Config, Whitespace},
+	util::{CONFIG_FIELDS, Config, WhereStyle, Whitespace},
 };
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -238,91 +239,314 @@ pub enum TokenTree {
 #[derive(Parse, Formattable, Format, Print)]
 pub struct TokenNonDelimited(#[parse(with_tag = ParserTag::SkipDelimiters)] pub Token);
 
-/// Updates the configuration based on an attribute
-// TODO: We need to return the position for better error messages.
-pub fn update_from_attr(attr: &AttrOrMetaItem, ctx: &mut format::Context) -> Result<(), AppError> {
-	let meta = match attr {
-		AttrOrMetaItem::Meta(meta) => match meta.path().starts_with("rustidy") {
-			true => meta,
-			false => return Ok(()),
+/// Updates the configuration based on an attribute.
+///
+/// Malformed `#[rustidy]` attributes are emitted as warnings.
+pub fn update_from_attr(attr: &mut AttrOrMetaItem, ctx: &mut format::Context) {
+	if let Err(err) = self::try_update_from_attr(attr, ctx) {
+		let pos = err.pos.or_else(|| self::input_pos(attr, ctx));
+		ctx.warn(FormatWarning {
+			pos,
+			message: format!("Malformed `#[rustidy]` attribute: {}", err.err.pretty()),
+		});
+	}
+}
+
+/// Updates the configuration based on a comment directive, such as
+/// `// rustidy::config(...)`.
+///
+/// The directive is handled the same as the equivalent attribute, and
+/// any errors are emitted as warnings.
+pub fn update_from_directive(
+	directive: &str,
+	directive_pos: Option<usize>,
+	ctx: &mut format::Context,
+) {
+	let mut parser = Parser::new(directive);
+	let mut attr = match parser.parse::<AttrOrMetaItem>() {
+		Ok(attr) if parser.is_finished() => attr,
+		_ => {
+			ctx.warn(FormatWarning {
+				pos: directive_pos,
+				message: format!("Malformed `rustidy` directive: {directive:?}"),
+			});
+			return;
 		},
+	};
+
+	if let Err(err) = self::try_update_from_attr(&mut attr, ctx) {
+		// Note: Positions within the directive are relative to it.
+		let pos = err
+			.pos
+			.or_else(|| self::input_pos(&mut attr, ctx));
+		let pos = try { directive_pos? + pos? };
+		ctx.warn(FormatWarning {
+			pos: pos.or(directive_pos),
+			message: format!("Malformed `rustidy` directive: {}", err.err.pretty()),
+		});
+	}
+}
+
+/// Error while updating the configuration from an attribute
+struct UpdateConfigError {
+	/// Byte position of the error.
+	///
+	/// If `None`, the error refers to the whole attribute.
+	pos: Option<usize>,
+	err: AppError,
+}
+
+impl From<AppError> for UpdateConfigError {
+	fn from(err: AppError) -> Self {
+		Self { pos: None, err }
+	}
+}
+
+fn try_update_from_attr(
+	attr: &mut AttrOrMetaItem,
+	ctx: &mut format::Context,
+) -> Result<(), UpdateConfigError> {
+	match attr {
+		AttrOrMetaItem::Meta(meta) => self::try_update_from_meta(meta, ctx),
 		AttrOrMetaItem::Attr(attr) => match attr.path.starts_with("rustidy") {
 			true => bail!("`#[rustidy]` attributes must be meta items"),
-			false => return Ok(()),
+			false => Ok(()),
 		},
-	};
+	}
+}
+
+fn try_update_from_meta(
+	meta: &mut MetaItem,
+	ctx: &mut format::Context,
+) -> Result<(), UpdateConfigError> {
+	if meta.path().is_str("cfg_attr") {
+		return self::try_update_from_cfg_attr(meta, ctx);
+	}
+	if !meta.path().starts_with("rustidy") {
+		return Ok(());
+	}
 
 	match meta.path().as_str().as_str() {
 		"rustidy::config" => self::update_config(meta, ctx)?,
 		"rustidy::skip" => ctx.config_mut().skip = true,
-		path => bail!("Unknown `#[rustidy]` attribute: {path:?}"),
+		path => {
+			let attrs = ["rustidy::config", "rustidy::skip"];
+			bail!("Unknown attribute `{path}`{}", util::did_you_mean(path, attrs))
+		},
 	}
 
 	Ok(())
 }
 
+/// Updates the configuration based on a `#[cfg_attr(<predicate>, <attrs>...)]`
+/// attribute, if `<predicate>` holds when formatting.
+fn try_update_from_cfg_attr(
+	meta: &mut MetaItem,
+	ctx: &mut format::Context,
+) -> Result<(), UpdateConfigError> {
+	// Note: Malformed `cfg_attr`s are rejected by the compiler, so we don't warn about them.
+	let Some(seq) = meta
+		.try_as_seq_mut()
+		.and_then(|meta| meta.seq.value.as_mut()) else { return Ok(()) };
+
+	let mut attrs = seq.0.values_mut();
+	let Some(predicate) = attrs.next() else { return Ok(()) };
+	match self::eval_cfg_predicate(predicate) {
+		Some(true) => (),
+		Some(false) => return Ok(()),
+		// Note: Since we can't evaluate most cfgs, we ignore the attributes
+		//       whenever we don't know if the predicate holds, but warn if any
+		//       of them are ours, since they'd be silently ignored otherwise.
+		None => {
+			if attrs.any(|attr| self::is_rustidy_attr(attr)) {
+				let pos = self::input_pos(predicate, ctx);
+				return Err(UpdateConfigError {
+					pos,
+					err: app_error!("Unable to evaluate `cfg_attr` predicate, only the `rustidy` cfg is known"),
+				});
+			}
+
+			return Ok(());
+		},
+	}
+
+	for attr in attrs {
+		// Note: If it's not a meta item, it can't be `rustidy` nor `cfg_attr`.
+		let Some(attr) = attr.try_as_meta_mut() else { continue };
+		self::try_update_from_meta(attr, ctx)?;
+	}
+
+	Ok(())
+}
+
+/// Evaluates a `cfg` predicate while formatting.
+///
+/// Only the `rustidy` cfg is known to be set, so any predicate that depends
+/// on other cfgs returns `None`.
+fn eval_cfg_predicate(predicate: &MetaItemInner) -> Option<bool> {
+	let predicate = predicate.try_as_meta_ref()?;
+	let MetaItem::Seq(seq) = &**predicate else {
+		return predicate
+			.path()
+			.is_str("rustidy")
+			.then_some(true);
+	};
+	let values = seq
+		.seq
+		.value
+		.iter()
+		.flat_map(|seq| seq.0.values())
+		.map(self::eval_cfg_predicate)
+		.collect::<Vec<_>>();
+
+	// Note: `any` holds if any predicate holds, even if we don't know the others,
+	//       and `all` doesn't if any predicate doesn't.
+	match (seq.path.as_str().as_str(), &*values) {
+		("any", _) if values.contains(&Some(true)) => Some(true),
+		("any", _) => values
+			.iter()
+			.all(|&value| value == Some(false))
+			.then_some(false),
+		("all", _) if values.contains(&Some(false)) => Some(false),
+		("all", _) => values
+			.iter()
+			.all(|&value| value == Some(true))
+			.then_some(true),
+		("not", &[value]) => value.map(|value| !value),
+		_ => None,
+	}
+}
+
+/// Returns if an attribute is a `rustidy` attribute, possibly within a `cfg_attr`
+fn is_rustidy_attr(attr: &MetaItemInner) -> bool {
+	let Some(meta) = attr.try_as_meta_ref() else { return false };
+	if !meta.path().is_str("cfg_attr") {
+		return meta.path().starts_with("rustidy");
+	}
+
+	let Some(seq) = meta
+		.try_as_seq_ref()
+		.and_then(|meta| meta.seq.value.as_ref()) else { return false };
+
+	seq.0
+		.values()
+		.skip(1)
+		.any(self::is_rustidy_attr)
+}
+
 /// Parses a `#[rustidy::config]` attribute
-fn update_config(meta: &MetaItem, ctx: &mut format::Context) -> Result<(), AppError> {
+fn update_config(meta: &mut MetaItem, ctx: &mut format::Context) -> Result<(), UpdateConfigError> {
 	let MetaItem::Seq(meta) = meta else { bail!("Expected `rustidy::config([...])`") };
 
-	let Some(configs) = &meta.seq.value else { return Ok(()) };
-
-	for config in configs.0.values() {
-		let config = try {
-			config.try_as_meta_ref()?.try_as_eq_expr_ref()?
-		};
-		let Some(config) = config else {
-			bail!("Expected `rustidy::config(<config-name> = <value>)`")
-		};
-
-		macro str() {
-			config
-				.expr
-				.as_string_literal()
-				.context("Expected a string literal")?
-				.contents()
-		}
-		macro int() {
-			config
-				.expr
-				.as_integer_literal()
-				.context("Expected an integer literal")?
-				.value()
-				.context("Unable to parse integer")?
-				.try_into()
-				.expect("`u64` didn't fit into `usize`")
-		}
+	let Some(configs) = &mut meta.seq.value else { return Ok(()) };
 
-		macro fields(
-			$( $field:ident = $value:expr ),* $(,)?
-		) {
-			let Config {
-				$( $field, )*
-
-				// Note: Skip is controlled by `rustidy::skip`.
-				skip: _,
-			} = ctx.config_mut();
-
-			match config.path.as_str().as_str() {
-				$(
-					stringify!($field) => *$field = $value,
-				)*
-				ident => bail!("Unknown configuration: {ident:?}"),
-			}
+	for config in configs.0.values_mut() {
+		if let Err(err) = self::update_config_field(config, ctx) {
+			let pos = self::input_pos(config, ctx);
+			return Err(UpdateConfigError { pos, err });
 		}
+	}
 
-		// TODO: Should we allow resetting `Option` types?
-		fields! {
-			indent = str!().into(),
-			min_empty_lines = int!(),
-			max_empty_lines = int!(),
-			max_use_tree_len = int!(),
-			array_expr_cols = Some(int!()),
-			max_array_expr_len = int!(),
-			max_chain_len = int!(),
-			max_inline_tuple_struct_len = int!(),
-		}
+	Ok(())
+}
+
+/// Parses a single `<config-name> = <value>` of a `#[rustidy::config]` attribute
+fn update_config_field(config: &MetaItemInner, ctx: &mut format::Context) -> Result<(), AppError> {
+	let config = try {
+		config.try_as_meta_ref()?.try_as_eq_expr_ref()?
+	};
+	let Some(config) = config else {
+		bail!("Expected `rustidy::config(<config-name> = <value>)`")
 	};
 
+	macro str() {
+		config
+			.expr
+			.as_string_literal()
+			.context("Expected a string literal")?
+			.contents()
+	}
+	macro int() {
+		config
+			.expr
+			.as_integer_literal()
+			.context("Expected an integer literal")?
+			.value()
+			.context("Unable to parse integer")?
+			.try_into()
+			.expect("`u64` didn't fit into `usize`")
+	}
+
+	macro enum_($T:ty) {
+		str!()
+			.parse::<$T>()
+			.with_context(|| {
+				let variants = <$T as strum::VariantNames>::VARIANTS
+					.iter()
+					.map(|variant| format!("`{variant}`"))
+					.join(", ");
+				format!("Expected one of {variants}")
+			})?
+	}
+
+	macro fields(
+		$( $field:ident = $value:expr ),* $(,)?
+	) {
+		let Config {
+			$( $field, )*
+
+			// Note: Skip is controlled by `rustidy::skip`.
+			skip: _,
+		} = ctx.config_mut();
+
+		match config.path.as_str().as_str() {
+			$(
+				stringify!($field) => *$field = $value,
+			)*
+			ident => {
+				let fields = CONFIG_FIELDS
+					.iter()
+					.map(|field| field.name)
+					.filter(|&name| name != "skip");
+				bail!("Unknown configuration `{ident}`{}", util::did_you_mean(ident, fields))
+			},
+		}
+	}
+
+	// TODO: Should we allow resetting `Option` types?
+	fields! {
+		indent = str!().into(),
+		min_empty_lines = int!(),
+		max_empty_lines = int!(),
+		max_width = int!(),
+		tab_width = int!(),
+		max_use_tree_len = int!(),
+		array_expr_cols = Some(int!()),
+		max_array_expr_len = int!(),
+		max_chain_len = int!(),
+		max_inline_tuple_struct_len = int!(),
+		max_fn_params_len = int!(),
+		max_call_args_len = int!(),
+		max_binary_expr_len = int!(),
+		max_generic_params_len = int!(),
+		max_bounds_len = int!(),
+		where_style = enum_!(WhereStyle),
+		max_where_clause_len = int!(),
+	}
+
 	Ok(())
 }
+
+/// Returns the byte position of a value in the input, if it has one
+fn input_pos(value: &mut impl Formattable, ctx: &mut format::Context) -> Option<usize> {
+	let res = value
+		.with_strings(ctx, true, &mut |s, _ctx| match s.input() {
+			Some(input) => ControlFlow::Break(input.range().start),
+			None => ControlFlow::Continue(()),
+		});
+
+	match res {
+		ControlFlow::Break(pos) => Some(pos),
+		ControlFlow::Continue(_) => None,
+	}
+}
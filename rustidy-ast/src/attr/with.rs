@@ -5,7 +5,14 @@ use {
 	crate::{attr::{InnerDocComment, OuterDocComment}, util::Braced},
 	super::{InnerAttrOrDocComment, OuterAttrOrDocComment},
 	ast_util::delimited,
-	format::{Format, FormatOutput, Formattable, WhitespaceConfig, WhitespaceFormat},
+	format::{
+		Directive,
+		Format,
+		FormatOutput,
+		Formattable,
+		WhitespaceConfig,
+		WhitespaceFormat,
+	},
 	parse::{ParsableRecursive, Parse, Parser},
 	print::Print,
 	util::Whitespace,
@@ -39,7 +46,28 @@ impl<A, T: Format<WhitespaceConfig, A>> Format<WhitespaceConfig, FmtArgs<A>> for
 		prefix_ws: WhitespaceConfig,
 		args: FmtArgs<A>,
 	) -> FormatOutput {
+		// Note: Comment directives before us work just like our attributes.
+		let directives = self.with_prefix_ws(
+			ctx,
+			&mut |ws, _ctx| Directive::from_whitespace(ws),
+		).unwrap_or_default();
+		for directive in &directives {
+			match directive {
+				Directive::SkipStart => {
+					ctx.add_tag::<format::tag::SkipRegion>();
+				},
+				Directive::SkipEnd => {
+					ctx.remove_tag::<format::tag::SkipRegion>();
+				},
+				Directive::Attr { .. } => (),
+			}
+		}
+		if ctx.has_tag::<format::tag::SkipRegion>() {
+			return self.format_output(ctx);
+		}
+
 		let mut output = FormatOutput::default();
+		let column = ctx.column();
 
 		let mut is_after_newline = false;
 		let mut has_prefix_ws = true;
@@ -48,6 +76,7 @@ impl<A, T: Format<WhitespaceConfig, A>> Format<WhitespaceConfig, FmtArgs<A>> for
 				ctx.add_tag::<format::tag::AfterNewline>();
 			}
 
+			ctx.set_column(output.end_column(column));
 			match has_prefix_ws {
 				true => ctx.format(attr, prefix_ws),
 				false => ctx.format(attr, args.prefix_ws),
@@ -56,11 +85,17 @@ impl<A, T: Format<WhitespaceConfig, A>> Format<WhitespaceConfig, FmtArgs<A>> for
 			is_after_newline = matches!(attr, OuterAttrOrDocComment::DocComment(OuterDocComment::Line(_)));
 			has_prefix_ws = false;
 		}
+		ctx.set_column(column);
 
 		let mut value_ctx = ctx.sub_context();
+		for directive in &directives {
+			if let Directive::Attr { attr, pos } = directive {
+				super::update_from_directive(attr, *pos, &mut value_ctx);
+			}
+		}
 		for attr in &mut self.attrs {
-			if let Some(attr) = attr.try_as_attr_ref() && let Err(err) = super::update_from_attr(&attr.open.value, &mut value_ctx) {
-				tracing::warn!("Malformed `#[rustidy::config(...)]` attribute: {err:?}");
+			if let Some(attr) = attr.try_as_attr_mut() {
+				super::update_from_attr(&mut attr.open.value, &mut value_ctx);
 			}
 		}
 
@@ -70,6 +105,7 @@ impl<A, T: Format<WhitespaceConfig, A>> Format<WhitespaceConfig, FmtArgs<A>> for
 				.add_tag::<format::tag::AfterNewline>();
 		}
 
+		value_ctx.set_column(output.end_column(column));
 		match has_prefix_ws {
 			true => value_ctx.format_with(&mut self.inner, prefix_ws, args.inner_args),
 			false => value_ctx.format_with(&mut self.inner, args.prefix_ws, args.inner_args),
@@ -87,7 +123,7 @@ impl<T> From<T> for WithOuterAttributes<T> {
 
 impl<T, R> ParsableRecursive<R> for WithOuterAttributes<T>
 where
-	T: ParsableRecursive<R> {
+	T: ParsableRecursive<R>, {
 	type Base = WithOuterAttributes<T::Base>;
 	type Infix = T::Infix;
 	type Prefix = T::Prefix;
@@ -131,7 +167,7 @@ pub struct BracedWithInnerAttributes<T>(pub Braced<WithInnerAttributes<T>>);
 impl<T, A> Format<WhitespaceConfig, FmtBracedArgs<A>> for BracedWithInnerAttributes<T>
 where
 	Braced<WithInnerAttributes<T>>: Format<WhitespaceConfig, A>,
-	A: Clone {
+	A: Clone, {
 	fn format(
 		&mut self,
 		ctx: &mut format::Context,
@@ -139,9 +175,9 @@ where
 		args: FmtBracedArgs<A>,
 	) -> FormatOutput {
 		let mut ctx = ctx.sub_context();
-		for attr in &self.0.value.attrs {
-			if let Some(attr) = attr.try_as_attr_ref() && let Err(err) = super::update_from_attr(&attr.attr.value, &mut ctx) {
-				tracing::warn!("Malformed `#![rustidy::config(...)]` attribute: {err:?}");
+		for attr in &mut self.0.value.attrs {
+			if let Some(attr) = attr.try_as_attr_mut() {
+				super::update_from_attr(&mut attr.attr.value, &mut ctx);
 			}
 		}
 
@@ -165,9 +201,10 @@ impl<T: Format<WhitespaceConfig, A>, A> Format<WhitespaceConfig, FmtArgs<A>> for
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		args: FmtArgs<A>
+		args: FmtArgs<A>,
 	) -> FormatOutput {
 		let mut output = FormatOutput::default();
+		let column = ctx.column();
 
 		let mut is_after_newline = false;
 		let mut has_prefix_ws = true;
@@ -181,6 +218,7 @@ impl<T: Format<WhitespaceConfig, A>, A> Format<WhitespaceConfig, FmtArgs<A>> for
 				false => args.prefix_ws,
 			};
 
+			ctx.set_column(output.end_column(column));
 			ctx
 				.format(attr, prefix_ws)
 				.append_to(&mut output);
@@ -199,9 +237,11 @@ impl<T: Format<WhitespaceConfig, A>, A> Format<WhitespaceConfig, FmtArgs<A>> for
 			true => prefix_ws,
 			false => args.prefix_ws,
 		};
+		ctx.set_column(output.end_column(column));
 		ctx
 			.format_with(&mut self.inner, prefix_ws, args.inner_args)
 			.append_to(&mut output);
+		ctx.set_column(column);
 
 		output
 	}
@@ -231,7 +271,10 @@ pub const fn fmt_braced_indent() -> FmtBracedArgs<delimited::FmtArgs<WhitespaceC
 }
 
 #[must_use]
-pub const fn fmt_braced_single_or_indent(force_indent_on_multiline: bool, max_len: usize) -> FmtBracedArgs<delimited::FmtArgsSingleOrIndentIfNonBlank<FmtArgs<()>>> {
+pub const fn fmt_braced_single_or_indent(
+	force_indent_on_multiline: bool,
+	max_len: usize,
+) -> FmtBracedArgs<delimited::FmtArgsSingleOrIndentIfNonBlank<FmtArgs<()>>> {
 	FmtBracedArgs {
 		delimited_args: delimited::FmtArgsSingleOrIndentIfNonBlank {
 			force_indent_on_multiline,
@@ -31,7 +31,7 @@ pub use self::{
 // Imports
 use {
 	crate::attr::OuterAttrOrDocComment,
-	ast_literal::{IntegerLiteral, LiteralExpression, StringLiteral},
+	ast_literal::{IntegerLiteral, LiteralExpression, RawStringLiteral, StringLiteral},
 	format::{Format, Formattable},
 	parse::{
 		FromRecursiveRoot,
@@ -49,6 +49,7 @@ use {
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
+#[parse(name = "an expression")]
 pub struct Expression(pub ArenaIdx<ExpressionInner>);
 
 impl Expression {
@@ -67,11 +68,31 @@ impl Expression {
 		self.as_literal()?.try_as_string_ref()
 	}
 
+	/// Gets a raw string literal out of this expression, if it is one.
+	#[must_use]
+	pub fn as_raw_string_literal(&self) -> Option<&RawStringLiteral> {
+		self.as_literal()?.try_as_raw_string_ref()
+	}
+
 	/// Gets an integer literal out of this expression, if it is one.
 	#[must_use]
 	pub fn as_integer_literal(&self) -> Option<&IntegerLiteral> {
 		self.as_literal()?.try_as_integer_ref()
 	}
+
+	/// Returns if this expression may stay on the same line as an opening delimiter
+	/// before it, even if it spans multiple lines.
+	///
+	/// This is the case for closures and struct expressions.
+	#[must_use]
+	pub fn is_huggable(&self) -> bool {
+		let Some(expr) = self.0.try_as_without_block_ref() else {
+			return false;
+		};
+
+		let inner = &expr.0.inner;
+		inner.try_as_closure_ref().is_some() || inner.try_as_struct_ref().is_some()
+	}
 }
 
 impl FromRecursiveRoot<ExpressionInner> for Expression {
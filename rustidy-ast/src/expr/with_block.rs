@@ -13,7 +13,7 @@ use {
 	super::Expression,
 	ast_literal::LifetimeOrLabel,
 	ast_util::{Longest, Punctuated, punct},
-	format::{Format, Formattable, WhitespaceFormat},
+	format::{Format, FormatOutput, Formattable, WhitespaceConfig, WhitespaceFormat},
 	parse::{ParsableFrom, Parse, ParserTag},
 	print::Print,
 	util::Whitespace,
@@ -140,10 +140,32 @@ pub struct ConditionsExpr(
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
 pub struct LetChain(
-	#[format(args = punct::fmt(Whitespace::SINGLE, Whitespace::SINGLE))]
+	#[format(with = Self::format_conditions)]
 	pub Punctuated<LetChainCondition, ast_token::AndAnd>,
 );
 
+impl LetChain {
+	fn format_conditions(
+		conditions: &mut Punctuated<LetChainCondition, ast_token::AndAnd>,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		_args: (),
+	) -> FormatOutput {
+		let max_binary_expr_len = ctx.config().max_binary_expr_len;
+		ctx.format_single_or_break(
+			conditions,
+			|conditions, ctx| conditions.format(
+				ctx,
+				prefix_ws,
+				punct::fmt(Whitespace::SINGLE, Whitespace::SINGLE),
+			),
+			|output| output.len_non_multiline_ws() > max_binary_expr_len,
+			|conditions, ctx| conditions
+				.format(ctx, prefix_ws, punct::FmtBreakIndentRest),
+		)
+	}
+}
+
 /// `LetChainCondition`
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
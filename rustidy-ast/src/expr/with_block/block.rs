@@ -17,6 +17,7 @@ use {
 			Statement,
 			StatementInner,
 		},
+		verbatim::Verbatim,
 	},
 
 	ast_util::{AtLeast1, NotFollows, at_least},
@@ -54,12 +55,12 @@ impl Format<WhitespaceConfig, ()> for BlockExpression {
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		_args: ()
+		_args: (),
 	) -> format::FormatOutput {
 		self.format(
 			ctx,
 			prefix_ws,
-			BlockExpressionFmt { allow_singleline: true }
+			BlockExpressionFmt { allow_singleline: true },
 		)
 	}
 }
@@ -84,6 +85,10 @@ impl Parse for Statements {
 
 	#[coverage(on)]
 	fn parse_from(parser: &mut Parser) -> Result<Self, Self::Error> {
+		if parser.take_recover_statements() {
+			return Self::parse_recovering(parser);
+		}
+
 		let mut stmts = None::<AtLeast1<_>>;
 		let mut push_stmt = |stmt| match &mut stmts {
 			Some(stmts) => stmts.rest.push(stmt),
@@ -107,11 +112,13 @@ impl Parse for Statements {
 			//       we can use it as the trailing expression
 			let start_pos = parser.cur_pos();
 			if let Some(expr) = self::parse_macro_invocation_expr(parser)? {
-				match parser.peek::<Statement>()?.is_ok() || parser.peek::<ExpressionWithoutBlock>()?.is_ok() {
+				let is_stmt = parser.peek::<Statement>()?.is_ok();
+				let is_expr = parser.peek::<ExpressionWithoutBlock>()?.is_ok();
+				match is_stmt || is_expr {
 					true => parser.set_pos(start_pos),
 					false => break match stmts {
 						Some(stmts) => Self::Full(
-							StatementsFull { stmts, trailing_expr: Some(expr) }
+							StatementsFull { stmts, trailing_expr: Some(expr) },
 						),
 						None => Self::OnlyExpr(expr),
 					},
@@ -124,13 +131,13 @@ impl Parse for Statements {
 					Some(semi) => {
 						parser.set_pos(peek_expr_pos);
 						let stmt = StatementInner::Expression(
-							ExpressionStatement::WithoutBlock(ExpressionStatementWithoutBlock { expr, semi })
+							ExpressionStatement::WithoutBlock(ExpressionStatementWithoutBlock { expr, semi }),
 						);
 						push_stmt(Statement(ArenaIdx::new(stmt)));
 					},
 					None => match parser.with_tag(
 						ParserTag::SkipExpressionWithoutBlock,
-						Parser::peek::<Statement>
+						Parser::peek::<Statement>,
 					)? {
 						//       since braced statement macros don't need a semi-colon, while expression ones do.
 						//       Since both have the same length, we prefer statements to expressions if they have
@@ -143,7 +150,7 @@ impl Parse for Statements {
 							parser.set_pos(peek_expr_pos);
 							break match stmts {
 								Some(stmts) => Self::Full(
-									StatementsFull { stmts, trailing_expr: Some(expr) }
+									StatementsFull { stmts, trailing_expr: Some(expr) },
 								),
 								None => Self::OnlyExpr(expr),
 							};
@@ -152,7 +159,7 @@ impl Parse for Statements {
 				},
 				Err(_) => match parser.with_tag(
 					ParserTag::SkipExpressionWithoutBlock,
-					Parser::try_parse::<Statement>
+					Parser::try_parse::<Statement>,
 				)? {
 					Ok(stmt) => push_stmt(stmt),
 					Err(err) => match stmts {
@@ -167,7 +174,57 @@ impl Parse for Statements {
 	}
 }
 
-fn parse_macro_invocation_expr(parser: &mut Parser) -> Result<Option<ExpressionWithoutBlock>, StatementsError> {
+impl Statements {
+	/// Parses statements, skipping any that fail to parse
+	fn parse_recovering(parser: &mut Parser) -> Result<Self, StatementsError> {
+		let mut stmts = vec![];
+		let trailing_expr = loop {
+			// Note: We first try to parse all remaining statements normally, so we
+			//       only parse them one by one around the ones that fail to parse.
+			let start_pos = parser.cur_pos();
+			if let Ok(rest) = parser.parse_not_fatal::<Self>() {
+				if matches!(parser.peek::<ast_token::BracesClose>(), Ok(Ok(_))) {
+					match rest {
+						Self::OnlyExpr(expr) => break Some(expr),
+						Self::Full(rest) => {
+							stmts.extend(rest.stmts);
+							break rest.trailing_expr;
+						},
+					}
+				}
+				parser.set_pos(start_pos);
+			}
+
+			match parser.parse_not_fatal::<Statement>() {
+				Ok(stmt) => stmts.push(stmt),
+				Err(err) => match Verbatim::recover(parser, &err) {
+					Some(verbatim) => stmts.push(Statement(
+						ArenaIdx::new(StatementInner::Verbatim(verbatim)),
+					)),
+					None if stmts.is_empty() => return Err(StatementsError::Statement(err)),
+					None => break None,
+				},
+			}
+		};
+
+		let mut stmts = stmts.into_iter();
+		let stmts = match stmts.next() {
+			Some(first) => Self::Full(StatementsFull {
+				stmts: AtLeast1 { first, rest: stmts.collect() },
+				trailing_expr,
+			}),
+			None => Self::OnlyExpr(trailing_expr.expect(
+				"Should have a trailing expression without any statements",
+			)),
+		};
+
+		Ok(stmts)
+	}
+}
+
+fn parse_macro_invocation_expr(
+	parser: &mut Parser,
+) -> Result<Option<ExpressionWithoutBlock>, StatementsError> {
 	let Ok((macro_invocation, ..)) = parser
 		.try_parse::<(WithOuterAttributes<MacroInvocationSemiBraces>, NotFollows<ast_token::Dot>, NotFollows<ast_token::Question>)>()? else {
 		return Ok(None);
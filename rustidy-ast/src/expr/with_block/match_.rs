@@ -74,7 +74,7 @@ impl Parse for MatchArms {
 
 						let expr = Expression::from_recursive_root(
 							ExpressionInner::from(expr_without_block),
-							parser
+							parser,
 						);
 						(expr, Some(trailing_comma), ControlFlow::Continue(()))
 					},
@@ -104,12 +104,12 @@ impl Parse for MatchArms {
 					(expr, trailing_comma, control_flow)
 				},
 				(Err(with_block), Err(without_block)) => return Err(
-					Self::Error::Expression { with_block, without_block, }
+					Self::Error::Expression { with_block, without_block, },
 				),
 			};
 
 			arms.push(
-				MatchArmWithExpr { arm, arrow, expr, trailing_comma, }
+				MatchArmWithExpr { arm, arrow, expr, trailing_comma, },
 			);
 
 			if control_flow.is_break() {
@@ -23,9 +23,15 @@ pub struct ArrayExpression(
 
 		// Note: If the user specified columns and we can't fit everything into a single
 		//       column, then ensure we do multi-line by setting the max length to 0.
-		max_len: match ctx.config().array_expr_cols {
-			Some(cols) if let Some(ArrayElements::Punctuated(values)) = &self.0.value && values.values_len() > cols => 0,
-			_ => ctx.config().max_array_expr_len,
+		max_len: {
+			let values_len = match &self.0.value {
+				Some(ArrayElements::Punctuated(values)) => values.values_len(),
+				_ => 0,
+			};
+			match ctx.config().array_expr_cols {
+				Some(cols) if values_len > cols => 0,
+				_ => ctx.config().max_array_expr_len,
+			}
 		},
 
 		value_args_remove: FmtRemoveOrIndent::Remove,
@@ -51,18 +57,18 @@ impl ArrayElements {
 		values: &mut PunctuatedTrailing<Expression, ast_token::Comma>,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		args: FmtRemoveOrIndent
+		args: FmtRemoveOrIndent,
 	) -> FormatOutput {
 		match args {
 			FmtRemoveOrIndent::Remove => ctx.format_with(
 				values,
 				prefix_ws,
-				punct::fmt(Whitespace::SINGLE, Whitespace::REMOVE)
+				punct::fmt(Whitespace::SINGLE, Whitespace::REMOVE),
 			),
 			FmtRemoveOrIndent::Indent => ctx.format_with(
 				values,
 				prefix_ws,
-				punct::FmtIndentColumns { columns: ctx.config().array_expr_cols, }
+				punct::FmtIndentColumns { columns: ctx.config().array_expr_cols, },
 			),
 		}
 	}
@@ -25,11 +25,7 @@ pub struct CallExpression {
 	pub expr:   Expression,
 	#[format(without_tag = format::tag::InsideChain)]
 	#[format(prefix_ws = Whitespace::REMOVE)]
-	#[format(args = delimited::fmt_remove_or_indent_if_non_blank(
-		50,
-		FmtSingleOrIndent::Single,
-		FmtSingleOrIndent::Indent
-	))]
+	#[format(with = self::format_params)]
 	pub params: Parenthesized<Option<CallParams>>,
 }
 
@@ -54,11 +50,7 @@ pub struct MethodCallExpression {
 	#[format(prefix_ws = Whitespace::REMOVE)]
 	#[format(without_tag = format::tag::InsideChain)]
 	#[format(indent(if_ = args.indent))]
-	#[format(args = delimited::fmt_remove_or_indent_if_non_blank(
-		50,
-		FmtSingleOrIndent::Single,
-		FmtSingleOrIndent::Indent
-	))]
+	#[format(with = self::format_params)]
 	pub params:  Parenthesized<Option<CallParams>>,
 }
 
@@ -67,12 +59,12 @@ impl MethodCallExpression {
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		indent: bool
+		indent: bool,
 	) -> FormatOutput {
 		self.format(
 			ctx,
 			prefix_ws,
-			MethodCallExpressionFmt { indent }
+			MethodCallExpressionFmt { indent },
 		)
 	}
 
@@ -80,12 +72,12 @@ impl MethodCallExpression {
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		indent: bool
+		indent: bool,
 	) -> FormatOutput {
 		ctx.with_tag_with::<format::tag::InsideChain, _>(
 			format::tag::InsideChainData { indent },
 			|ctx| self
-				.format_inside_chain(ctx, prefix_ws, indent)
+				.format_inside_chain(ctx, prefix_ws, indent),
 		)
 	}
 }
@@ -99,16 +91,20 @@ impl Format<WhitespaceConfig, ()> for MethodCallExpression {
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		_args: ()
+		_args: (),
 	) -> FormatOutput {
 		match ctx.tag::<format::tag::InsideChain>() {
-			Some(&format::tag::InsideChainData { indent }) => self.format_inside_chain(ctx, prefix_ws, indent),
+			Some(&format::tag::InsideChainData { indent }) => {
+				Self::format_inside_chain(self, ctx, prefix_ws, indent)
+			},
 			None => {
-				let singleline_output = self.format_outside_chain(ctx, prefix_ws, false);
-				match singleline_output.len_non_multiline_ws() >= ctx.config().max_chain_len {
-					true => self.format_outside_chain(ctx, prefix_ws, true),
-					false => singleline_output,
-				}
+				let max_chain_len = ctx.config().max_chain_len;
+				ctx.format_single_or_break(
+					self,
+					|this, ctx| Self::format_outside_chain(this, ctx, prefix_ws, false),
+					|output| output.len_non_multiline_ws() >= max_chain_len,
+					|this, ctx| Self::format_outside_chain(this, ctx, prefix_ws, true),
+				)
 			},
 		}
 	}
@@ -123,3 +119,47 @@ pub struct CallParams(
 	#[format(args = punct::fmt(args.prefix_ws(), Whitespace::REMOVE))]
 	pub PunctuatedTrailing<Expression, ast_token::Comma>,
 );
+
+/// Formats the parameters of a call.
+///
+/// If the last parameter may hug the parenthesis, only the first line is
+/// checked against the maximum length, so that it may stay on the same line
+/// as the opening parenthesis.
+fn format_params(
+	params: &mut Parenthesized<Option<CallParams>>,
+	ctx: &mut format::Context,
+	prefix_ws: WhitespaceConfig,
+	_args: (),
+) -> FormatOutput {
+	let max_call_args_len = ctx.config().max_call_args_len;
+	let is_last_huggable = params
+		.value
+		.as_ref()
+		.and_then(|params| params.0.values().last())
+		.is_some_and(Expression::is_huggable);
+
+	ctx.format_single_or_break(params, |params, ctx| {
+		if params.is_suffix_on_new_line(ctx) && let Some(params) = &mut params.value {
+			params.0.trailing = None;
+		}
+
+		params.format(
+			ctx,
+			prefix_ws,
+			delimited::FmtRemoveWith(FmtSingleOrIndent::Single),
+		)
+	}, |output| match output.multiline {
+		Some(multiline) if is_last_huggable => multiline.prefix_len > max_call_args_len,
+		_ => output.len_non_multiline_ws() > max_call_args_len,
+	}, |params, ctx| {
+		if let Some(params) = &mut params.value {
+			params.0.trailing.get_or_insert_default();
+		}
+
+		params.format(
+			ctx,
+			prefix_ws,
+			delimited::fmt_indent_if_non_blank_with_value(FmtSingleOrIndent::Indent),
+		)
+	})
+}
@@ -35,7 +35,7 @@ impl FieldExpression {
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		indent: bool
+		indent: bool,
 	) -> FormatOutput {
 		self
 			.format(ctx, prefix_ws, FieldExpressionFmt { indent })
@@ -45,12 +45,12 @@ impl FieldExpression {
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		indent: bool
+		indent: bool,
 	) -> FormatOutput {
 		ctx.with_tag_with::<format::tag::InsideChain, _>(
 			format::tag::InsideChainData { indent },
 			|ctx| self
-				.format_inside_chain(ctx, prefix_ws, indent)
+				.format_inside_chain(ctx, prefix_ws, indent),
 		)
 	}
 }
@@ -64,16 +64,20 @@ impl Format<WhitespaceConfig, ()> for FieldExpression {
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		_args: ()
+		_args: (),
 	) -> FormatOutput {
 		match ctx.tag::<format::tag::InsideChain>() {
-			Some(&format::tag::InsideChainData { indent }) => self.format_inside_chain(ctx, prefix_ws, indent),
+			Some(&format::tag::InsideChainData { indent }) => {
+				Self::format_inside_chain(self, ctx, prefix_ws, indent)
+			},
 			None => {
-				let singleline_output = self.format_outside_chain(ctx, prefix_ws, false);
-				match singleline_output.len_non_multiline_ws() >= ctx.config().max_chain_len {
-					true => self.format_outside_chain(ctx, prefix_ws, true),
-					false => singleline_output,
-				}
+				let max_chain_len = ctx.config().max_chain_len;
+				ctx.format_single_or_break(
+					self,
+					|this, ctx| Self::format_outside_chain(this, ctx, prefix_ws, false),
+					|output| output.len_non_multiline_ws() >= max_chain_len,
+					|this, ctx| Self::format_outside_chain(this, ctx, prefix_ws, true),
+				)
 			},
 		}
 	}
@@ -5,7 +5,7 @@ use {
 	crate::{expr::{Expression, ExpressionInner}, ty::TypeNoBounds},
 	super::{ExpressionWithoutBlockInner, Parse},
 
-	format::{Format, Formattable, WhitespaceFormat},
+	format::{Format, FormatOutput, Formattable, WhitespaceConfig, WhitespaceFormat},
 	parse::{ParseRecursive, ParserTag},
 	print::Print,
 	util::Whitespace,
@@ -131,14 +131,43 @@ pub enum NegationExpressionToken {
 #[parse_recursive(root = ExpressionInner)]
 #[parse_recursive(into_root = OperatorExpression)]
 #[parse_recursive(kind = "fully")]
+#[format(args = BinaryExpressionFmt)]
 pub struct ArithmeticOrLogicalExpression {
+	#[format(without_tag(expr = format::tag::InsideOperatorChain, if_ = !args.lhs_in_chain))]
 	pub lhs: Expression,
-	#[format(prefix_ws = Whitespace::SINGLE)]
+	#[format(indent(if_ = args.indent))]
+	#[format(prefix_ws = match args.indent {
+		true => Whitespace::INDENT,
+		false => Whitespace::SINGLE,
+	})]
 	pub op:  ArithmeticOrLogicalExpressionOp,
+	#[format(indent(if_ = args.indent))]
+	#[format(without_tag = format::tag::InsideOperatorChain)]
 	#[format(prefix_ws = Whitespace::SINGLE)]
 	pub rhs: Expression,
 }
 
+impl BinaryExpression for ArithmeticOrLogicalExpression {
+	fn lhs(&self) -> &Expression {
+		&self.lhs
+	}
+
+	fn precedence(&self) -> usize {
+		self.op.precedence()
+	}
+}
+
+impl Format<WhitespaceConfig, ()> for ArithmeticOrLogicalExpression {
+	fn format(
+		&mut self,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		_args: (),
+	) -> FormatOutput {
+		self::format_binary(self, ctx, prefix_ws)
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
@@ -155,6 +184,21 @@ pub enum ArithmeticOrLogicalExpressionOp {
 	Shr(ast_token::Shr),
 }
 
+impl ArithmeticOrLogicalExpressionOp {
+	/// Returns the precedence of this operator
+	#[must_use]
+	pub const fn precedence(&self) -> usize {
+		match self {
+			Self::Star(_) | Self::Div(_) | Self::Percent(_) => 10,
+			Self::Plus(_) | Self::Minus(_) => 9,
+			Self::Shl(_) | Self::Shr(_) => 8,
+			Self::And(_) => 7,
+			Self::Caret(_) => 6,
+			Self::Or(_) => 5,
+		}
+	}
+}
+
 /// `ComparisonExpression`
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -162,14 +206,43 @@ pub enum ArithmeticOrLogicalExpressionOp {
 #[parse_recursive(root = ExpressionInner)]
 #[parse_recursive(into_root = OperatorExpression)]
 #[parse_recursive(kind = "fully")]
+#[format(args = BinaryExpressionFmt)]
 pub struct ComparisonExpression {
+	#[format(without_tag(expr = format::tag::InsideOperatorChain, if_ = !args.lhs_in_chain))]
 	pub lhs: Expression,
-	#[format(prefix_ws = Whitespace::SINGLE)]
+	#[format(indent(if_ = args.indent))]
+	#[format(prefix_ws = match args.indent {
+		true => Whitespace::INDENT,
+		false => Whitespace::SINGLE,
+	})]
 	pub op:  ComparisonExpressionOp,
+	#[format(indent(if_ = args.indent))]
+	#[format(without_tag = format::tag::InsideOperatorChain)]
 	#[format(prefix_ws = Whitespace::SINGLE)]
 	pub rhs: Expression,
 }
 
+impl BinaryExpression for ComparisonExpression {
+	fn lhs(&self) -> &Expression {
+		&self.lhs
+	}
+
+	fn precedence(&self) -> usize {
+		self.op.precedence()
+	}
+}
+
+impl Format<WhitespaceConfig, ()> for ComparisonExpression {
+	fn format(
+		&mut self,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		_args: (),
+	) -> FormatOutput {
+		self::format_binary(self, ctx, prefix_ws)
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
@@ -182,6 +255,14 @@ pub enum ComparisonExpressionOp {
 	Lt(ast_token::Lt),
 }
 
+impl ComparisonExpressionOp {
+	/// Returns the precedence of this operator
+	#[must_use]
+	pub const fn precedence(&self) -> usize {
+		4
+	}
+}
+
 /// `LazyBooleanExpression`
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -190,14 +271,43 @@ pub enum ComparisonExpressionOp {
 #[parse_recursive(into_root = OperatorExpression)]
 #[parse_recursive(kind = "fully")]
 #[parse_recursive(skip_if_tag = ParserTag::SkipLazyBooleanExpression)]
+#[format(args = BinaryExpressionFmt)]
 pub struct LazyBooleanExpression {
+	#[format(without_tag(expr = format::tag::InsideOperatorChain, if_ = !args.lhs_in_chain))]
 	pub lhs: Expression,
-	#[format(prefix_ws = Whitespace::SINGLE)]
+	#[format(indent(if_ = args.indent))]
+	#[format(prefix_ws = match args.indent {
+		true => Whitespace::INDENT,
+		false => Whitespace::SINGLE,
+	})]
 	pub op:  LazyBooleanExpressionOp,
+	#[format(indent(if_ = args.indent))]
+	#[format(without_tag = format::tag::InsideOperatorChain)]
 	#[format(prefix_ws = Whitespace::SINGLE)]
 	pub rhs: Expression,
 }
 
+impl BinaryExpression for LazyBooleanExpression {
+	fn lhs(&self) -> &Expression {
+		&self.lhs
+	}
+
+	fn precedence(&self) -> usize {
+		self.op.precedence()
+	}
+}
+
+impl Format<WhitespaceConfig, ()> for LazyBooleanExpression {
+	fn format(
+		&mut self,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		_args: (),
+	) -> FormatOutput {
+		self::format_binary(self, ctx, prefix_ws)
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
@@ -206,6 +316,17 @@ pub enum LazyBooleanExpressionOp {
 	And(ast_token::AndAnd),
 }
 
+impl LazyBooleanExpressionOp {
+	/// Returns the precedence of this operator
+	#[must_use]
+	pub const fn precedence(&self) -> usize {
+		match self {
+			Self::And(_) => 3,
+			Self::Or(_) => 2,
+		}
+	}
+}
+
 /// `TypeCastExpression`
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -268,3 +389,89 @@ pub enum CompoundAssignmentExpressionOp {
 	Shl(ast_token::ShlEq),
 	Shr(ast_token::ShrEq),
 }
+
+/// Binary operator expression
+trait BinaryExpression: Format<WhitespaceConfig, BinaryExpressionFmt> {
+	/// Returns the left-hand side of this expression
+	fn lhs(&self) -> &Expression;
+
+	/// Returns the precedence of this expression's operator
+	fn precedence(&self) -> usize;
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BinaryExpressionFmt {
+	/// Whether to break before the operator
+	indent:       bool,
+
+	/// Whether the left-hand side is part of the same chain
+	lhs_in_chain: bool,
+}
+
+/// Formats a binary operator expression.
+///
+/// Binary operator expressions are parsed as a chain to the left (without precedence),
+/// so when we're the last operator of a chain, we check it's length and, if it's too
+/// long, we break it before all operators with the lowest precedence in the chain.
+fn format_binary<T: BinaryExpression>(
+	expr: &mut T,
+	ctx: &mut format::Context,
+	prefix_ws: WhitespaceConfig,
+) -> FormatOutput {
+	let lhs_in_chain = self::binary_parts(expr.lhs()).is_some();
+	match ctx.tag::<format::tag::InsideOperatorChain>() {
+		Some(&format::tag::InsideOperatorChainData { break_precedence }) => {
+			let indent = break_precedence == Some(expr.precedence());
+			expr.format(
+				ctx,
+				prefix_ws,
+				BinaryExpressionFmt { indent, lhs_in_chain },
+			)
+		},
+		None => {
+			let max_binary_expr_len = ctx.config().max_binary_expr_len;
+			let precedence = self::chain_precedence(expr.precedence(), expr.lhs());
+			ctx.format_single_or_break(
+				expr,
+				|expr, ctx| self::format_binary_chain(expr, ctx, prefix_ws, None),
+				|output| output.len_non_multiline_ws() > max_binary_expr_len,
+				|expr, ctx| self::format_binary_chain(expr, ctx, prefix_ws, Some(precedence)),
+			)
+		},
+	}
+}
+
+/// Formats a binary operator expression chain
+fn format_binary_chain<T: BinaryExpression>(
+	expr: &mut T,
+	ctx: &mut format::Context,
+	prefix_ws: WhitespaceConfig,
+	break_precedence: Option<usize>,
+) -> FormatOutput {
+	ctx.with_tag_with::<format::tag::InsideOperatorChain, _>(
+		format::tag::InsideOperatorChainData { break_precedence },
+		|ctx| self::format_binary(expr, ctx, prefix_ws),
+	)
+}
+
+/// Returns the lowest precedence of a binary operator expression chain,
+/// given the precedence of it's last operator and it's left-hand side
+fn chain_precedence(mut precedence: usize, mut lhs: &Expression) -> usize {
+	while let Some((lhs_precedence, lhs_lhs)) = self::binary_parts(lhs) {
+		precedence = precedence.min(lhs_precedence);
+		lhs = lhs_lhs;
+	}
+
+	precedence
+}
+
+/// Returns the precedence and left-hand side of a binary operator expression
+fn binary_parts(expr: &Expression) -> Option<(usize, &Expression)> {
+	let expr = expr.0.try_as_without_block_ref()?;
+	match expr.0.inner.try_as_operator_ref()? {
+		OperatorExpression::ArithmeticOrLogical(expr) => Some((expr.precedence(), &expr.lhs)),
+		OperatorExpression::Comparison(expr) => Some((expr.precedence(), &expr.lhs)),
+		OperatorExpression::LazyBoolean(expr) => Some((expr.precedence(), &expr.lhs)),
+		_ => None,
+	}
+}
@@ -24,7 +24,7 @@ pub struct StructExpression {
 	#[format(args = delimited::fmt_single_or_indent_if_non_blank(
 		50,
 		StructExprFieldsFmt { field_prefix_ws: Whitespace::SINGLE },
-		StructExprFieldsFmt { field_prefix_ws: Whitespace::INDENT }
+		StructExprFieldsFmt { field_prefix_ws: Whitespace::INDENT },
 	))]
 	pub inner: Braced<Option<StructExpressionInner>>,
 }
@@ -49,7 +49,7 @@ pub struct StructExprFields {
 		args.field_prefix_ws,
 		Whitespace::REMOVE,
 		args,
-		()
+		(),
 	))]
 	pub fields: Punctuated<StructExprField, ast_token::Comma>,
 	#[format(prefix_ws = Whitespace::REMOVE)]
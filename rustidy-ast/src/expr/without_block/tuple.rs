@@ -15,7 +15,10 @@ use {
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
-pub struct TupleExpression(#[format(args = delimited::FmtRemove)] Parenthesized<Option<TupleElements>>);
+pub struct TupleExpression(
+	#[format(args = delimited::FmtRemove)]
+	Parenthesized<Option<TupleElements>>,
+);
 
 /// `TupleElements`
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -42,14 +42,16 @@ use {
 	super::{
 		attr::{OuterAttrOrDocComment, WithOuterAttributes},
 		util::{Braced, Parenthesized},
+		verbatim::{self, Verbatim},
 		vis::Visibility,
 	},
 	self::macro_rules::{MacroMatcherMatches, MacroRule},
 	ast_literal::Identifier,
 	ast_util::{AtLeast1, PunctuatedTrailing, delimited, punct},
+	core::iter,
 	format::{Format, Formattable, WhitespaceFormat},
 	itertools::Itertools,
-	parse::Parse,
+	parse::{Parse, Parser},
 	print::Print,
 	util::{ArenaIdx, Whitespace, decl_arena},
 };
@@ -57,6 +59,7 @@ use {
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
+#[parse(try_with = Self::try_parse_recovering)]
 #[format(before_with = Self::merge_use)]
 pub struct Items(
 	#[format(args = ast_util::at_least::fmt_prefix_ws(Whitespace::INDENT))]
@@ -64,26 +67,50 @@ pub struct Items(
 );
 
 impl Items {
+	/// Parses all items, skipping any that fail to parse, if recovering
+	#[expect(clippy::unnecessary_wraps, reason = "It's required by `#[parse(try_with)]`")]
+	fn try_parse_recovering(parser: &mut Parser) -> Result<Option<Self>, ItemsError> {
+		if !parser.is_recovering() {
+			return Ok(None);
+		}
+
+		let Some(first) = Item::parse_or_recover(parser) else {
+			return Ok(None);
+		};
+		let rest = iter::from_fn(|| Item::parse_or_recover(parser))
+			.collect();
+
+		Ok(Some(Self(AtLeast1 { first, rest })))
+	}
+
 	pub fn merge_use(&mut self, ctx: &mut format::Context) {
 		replace_with::replace_with_or_abort(&mut self.0, |items| {
 			let mut items = items
 				.into_iter()
 				.peekable()
 				.batching(|items| {
-					let item = items.next()?;
+					let mut item = items.next()?;
+
+					// Note: Merging changes multiple lines, so we can only do it if
+					//       they're all being formatted.
+					let range = item.input_range(ctx, true);
+					if !ctx.is_within_ranges(range) {
+						return Some(item);
+					}
+
 					let item = match item.try_into_use_decl() {
 						Ok((attrs, vis, mut first_use_decl)) => {
 							while let Some(use_decl) = items.next_if_map(
 								|item| item
-									.try_into_just_use_decl(ctx, vis.as_ref())
+									.try_into_just_use_decl(ctx, vis.as_ref()),
 							) {
 								first_use_decl.merge(use_decl);
 							}
 
 							Item(ArenaIdx::new(
 								WithOuterAttributes { attrs, inner: ItemInner::Vis(
-									VisItem { vis, inner: VisItemInner::Use(first_use_decl), }
-								), }
+									VisItem { vis, inner: VisItemInner::Use(first_use_decl), },
+								), },
 							))
 						},
 						Err(item) => item,
@@ -111,10 +138,25 @@ pub struct Item(
 );
 
 impl Item {
+	/// Parses an item, or a verbatim item if it fails to parse
+	fn parse_or_recover(parser: &mut Parser) -> Option<Self> {
+		verbatim::parse_or_recover(
+			parser,
+			|verbatim| Self(ArenaIdx::new(WithOuterAttributes {
+				attrs: vec![],
+				inner: ItemInner::Verbatim(verbatim)
+			})),
+		)
+	}
+
 	#[expect(clippy::result_large_err, reason = "TODO")]
-	fn try_into_use_decl(self) -> Result<(Vec<OuterAttrOrDocComment>, Option<Visibility>, UseDeclaration), Self> {
+	fn try_into_use_decl(
+		self,
+	) -> Result<(Vec<OuterAttrOrDocComment>, Option<Visibility>, UseDeclaration), Self> {
 		self.0.try_take_map(|item| match item.inner {
-			ItemInner::Vis(VisItem { vis, inner: VisItemInner::Use(use_decl), }) => Ok((item.attrs, vis, use_decl)),
+			ItemInner::Vis(VisItem { vis, inner: VisItemInner::Use(use_decl), }) => {
+				Ok((item.attrs, vis, use_decl))
+			},
 			_ => Err(item),
 		}).map_err(Self)
 	}
@@ -134,9 +176,18 @@ impl Item {
 			if !item.attrs.is_empty() {
 				return Err(item);
 			}
+			let range = item.input_range(ctx, true);
+			if !ctx.is_within_ranges(range) {
+				return Err(item);
+			}
+
+			if let ItemInner::Vis(vis_item) = &item.inner
+				&& vis_item.vis.as_ref() != expected_vis {
+				return Err(item);
+			}
 
 			match item.inner {
-				ItemInner::Vis(VisItem { vis, inner: VisItemInner::Use(use_decl), }) if vis.as_ref() == expected_vis => Ok(use_decl),
+				ItemInner::Vis(VisItem { inner: VisItemInner::Use(use_decl), .. }) => Ok(use_decl),
 				_ => Err(item),
 			}
 		}).map_err(Self)
@@ -150,6 +201,7 @@ impl Item {
 pub enum ItemInner {
 	Vis(VisItem),
 	Macro(MacroItem),
+	Verbatim(Verbatim),
 }
 
 decl_arena! { WithOuterAttributes<ItemInner> }
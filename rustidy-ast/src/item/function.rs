@@ -11,10 +11,10 @@ use {
 	},
 	ast_literal::{Identifier, Lifetime, LiteralExpression, RawStringLiteral, StringLiteral},
 	ast_util::{Delimited, Follows, PunctuatedTrailing, delimited, punct},
-	format::{Format, Formattable, WhitespaceFormat},
-	parse::{Parse, ParsePeeked},
+	format::{Format, FormatOutput, Formattable, WhitespaceConfig, WhitespaceFormat},
+	parse::{Parse, ParsePeeked, Parser},
 	print::Print,
-	util::Whitespace,
+	util::{WhereStyle, Whitespace},
 };
 
 /// `Function`
@@ -32,11 +32,7 @@ pub struct Function {
 	#[format(prefix_ws = Whitespace::REMOVE)]
 	pub generics:   Option<GenericParams>,
 	#[format(prefix_ws = Whitespace::REMOVE)]
-	#[format(args = delimited::fmt_remove_or_indent_if_non_blank(
-		60,
-		FmtSingleOrIndent::Single,
-		FmtSingleOrIndent::Indent
-	))]
+	#[format(with_self = Self::format_params)]
 	pub params:     Parenthesized<Option<FunctionParameters>>,
 	#[format(prefix_ws = Whitespace::SINGLE)]
 	pub ret:        Option<FunctionReturnType>,
@@ -44,18 +40,66 @@ pub struct Function {
 	pub where_:     Option<WhereClause>,
 	#[format(prefix_ws = match self.body.is_semi() {
 		true => Whitespace::REMOVE,
-		false => match self.where_.is_some() {
-			true => Whitespace::INDENT,
-			false => Whitespace::SINGLE,
-		}
+		false => WhereClause::suffix_ws(&mut self.where_, ctx),
 	})]
 	pub body:       FunctionBody,
 }
 
+impl Function {
+	fn format_params(
+		&mut self,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		_args: (),
+	) -> FormatOutput {
+		// Note: The return type stays on the same line as the closing parenthesis,
+		//       so we need to include it when checking the maximum width.
+		let ret_len = ctx
+			.with_tag::<format::tag::IgnoreMaxWidth, _>(
+				|ctx| ctx
+					.format(&mut self.ret, Whitespace::SINGLE),
+			)
+			.len;
+		let max_fn_params_len = ctx.config().max_fn_params_len;
+		let max_width = ctx.config().max_width;
+		let column = ctx.column();
+
+		ctx.format_single_or_break(&mut self.params, |params, ctx| {
+			// Note: If the parameters were one per line, the trailing comma was
+			//       only there for that, so we remove it.
+			if params.is_suffix_on_new_line(ctx) && let Some(params) = &mut params.value {
+				*params.trailing_comma_mut() = None;
+			}
+
+			params.format(
+				ctx,
+				prefix_ws,
+				delimited::FmtRemoveWith(FmtSingleOrIndent::Single),
+			)
+		}, |output| {
+			let exceeds_max_width = output.end_column(column) + ret_len > max_width;
+			exceeds_max_width || output.len_non_multiline_ws() > max_fn_params_len
+		}, |params, ctx| {
+			if let Some(params) = &mut params.value && params.allows_trailing_comma() {
+				params
+					.trailing_comma_mut()
+					.get_or_insert_default();
+			}
+
+			params.format(
+				ctx,
+				prefix_ws,
+				delimited::fmt_indent_if_non_blank_with_value(FmtSingleOrIndent::Indent),
+			)
+		})
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(strum::EnumIs)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
+#[parse(try_with = Self::try_parse_recovering)]
 pub enum FunctionBody {
 	// TODO: Should we allow singleline function bodies?
 	#[format(args = BlockExpressionFmt { allow_singleline: false })]
@@ -63,6 +107,22 @@ pub enum FunctionBody {
 	Semi(ast_token::Semi),
 }
 
+impl FunctionBody {
+	/// Parses a body with statements that skip any that fail to parse, if recovering
+	fn try_parse_recovering(parser: &mut Parser) -> Result<Option<Self>, FunctionBodyError> {
+		if !parser.is_recovering() {
+			return Ok(None);
+		}
+
+		// Note: We only recover within function bodies, since other blocks may
+		//       be parsed speculatively, where failing to parse is expected.
+		let body = parser
+			.with_recover_statements(Parser::try_parse::<BlockExpression>)
+			.map_err(FunctionBodyError::Expr)?;
+		Ok(body.ok().map(Self::Expr))
+	}
+}
+
 /// `FunctionQualifiers`
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -72,7 +132,9 @@ pub struct FunctionQualifiers {
 	pub const_:  Option<ast_token::Const>,
 	#[format(prefix_ws(if_ = self.const_.is_some(), expr = Whitespace::SINGLE))]
 	pub async_:  Option<ast_token::Async>,
-	#[format(prefix_ws(if_ = self.const_.is_some() || self.async_.is_some(), expr = Whitespace::SINGLE))]
+	#[format(
+		prefix_ws(if_ = self.const_.is_some() || self.async_.is_some(), expr = Whitespace::SINGLE)
+	)]
 	pub safety:  Option<ItemSafety>,
 	#[format(prefix_ws(
 		if_ = self.const_.is_some() || self.async_.is_some() || self.safety.is_some(),
@@ -85,7 +147,8 @@ impl FunctionQualifiers {
 	/// Returns if any qualifiers exist
 	#[must_use]
 	pub const fn has_any(&self) -> bool {
-		self.const_.is_some() || self.async_.is_some() || self.safety.is_some() || self.extern_.is_some()
+		let has_modifiers = self.const_.is_some() || self.async_.is_some();
+		has_modifiers || self.safety.is_some() || self.extern_.is_some()
 	}
 }
 
@@ -129,6 +192,31 @@ pub enum FunctionParameters {
 	OnlySelf(FunctionParametersOnlySelf),
 }
 
+impl FunctionParameters {
+	/// Returns the trailing comma of these parameters
+	pub const fn trailing_comma_mut(&mut self) -> &mut Option<ast_token::Comma> {
+		match self {
+			Self::Full(params) => &mut params.rest.trailing,
+			Self::OnlySelf(params) => &mut params.trailing_comma,
+		}
+	}
+
+	/// Returns if these parameters may have a trailing comma.
+	///
+	/// C-variadic parameters (`...`) must be the last parameter, without a trailing comma.
+	#[must_use]
+	pub fn allows_trailing_comma(&self) -> bool {
+		match self {
+			Self::Full(params) => !params
+				.rest
+				.values()
+				.last()
+				.is_some_and(|param| param.0.inner.is_c_variadic()),
+			Self::OnlySelf(_) => true,
+		}
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
@@ -177,6 +265,7 @@ pub struct FunctionParam(
 );
 
 #[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(strum::EnumIs)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
 pub enum FunctionParamInner {
@@ -278,16 +367,54 @@ pub struct FunctionReturnType {
 #[derive(Parse, Formattable, Format, Print)]
 #[parse(name = "generic parameters")]
 pub struct GenericParams(
-	#[format(args = delimited::FmtRemove)]
+	#[format(with = Self::format_params)]
 	pub Delimited<Option<GenericParamsInner>, ast_token::Lt, ast_token::Gt>,
 );
 
+impl GenericParams {
+	fn format_params(
+		params: &mut Delimited<Option<GenericParamsInner>, ast_token::Lt, ast_token::Gt>,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		_args: (),
+	) -> FormatOutput {
+		let max_generic_params_len = ctx.config().max_generic_params_len;
+		ctx.format_single_or_break(params, |params, ctx| {
+			if params.is_suffix_on_new_line(ctx) && let Some(params) = &mut params.value {
+				params.0.trailing = None;
+			}
+
+			params.format(
+				ctx,
+				prefix_ws,
+				delimited::FmtRemoveWith(FmtSingleOrIndent::Single),
+			)
+		}, |output| {
+			// Note: If any parameter is multi-line (e.g. due to long bounds), we
+			//       put each one on it's own line.
+			output.multiline.is_some()
+				|| output.len_non_multiline_ws() > max_generic_params_len
+		}, |params, ctx| {
+			if let Some(params) = &mut params.value {
+				params.0.trailing.get_or_insert_default();
+			}
+
+			params.format(
+				ctx,
+				prefix_ws,
+				delimited::fmt_indent_if_non_blank_with_value(FmtSingleOrIndent::Indent),
+			)
+		})
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
 #[parse(name = "generic parameters")]
+#[format(args(ty = "FmtSingleOrIndent"))]
 pub struct GenericParamsInner(
-	#[format(args = punct::fmt(Whitespace::SINGLE, Whitespace::REMOVE))]
+	#[format(args = punct::fmt(args.prefix_ws(), Whitespace::REMOVE))]
 	pub PunctuatedTrailing<GenericParam, ast_token::Comma>,
 );
 
@@ -374,10 +501,32 @@ pub struct TypeParamEqType {
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
 pub struct TypeParamBounds(
-	#[format(args = punct::fmt(Whitespace::SINGLE, Whitespace::SINGLE))]
+	#[format(with = Self::format_bounds)]
 	pub PunctuatedTrailing<TypeParamBound, ast_token::Plus>,
 );
 
+impl TypeParamBounds {
+	fn format_bounds(
+		bounds: &mut PunctuatedTrailing<TypeParamBound, ast_token::Plus>,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		_args: (),
+	) -> FormatOutput {
+		let max_bounds_len = ctx.config().max_bounds_len;
+		ctx.format_single_or_break(
+			bounds,
+			|bounds, ctx| bounds.format(
+				ctx,
+				prefix_ws,
+				punct::fmt(Whitespace::SINGLE, Whitespace::SINGLE),
+			),
+			|output| output.len_non_multiline_ws() > max_bounds_len,
+			|bounds, ctx| bounds
+				.format(ctx, prefix_ws, punct::FmtBreakIndentRest),
+		)
+	}
+}
+
 /// `TypeParamBound`
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -422,16 +571,75 @@ pub enum TraitBoundInnerPrefix {
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
+#[format(args(ty = "FmtSingleOrIndent"))]
 pub struct WhereClause {
 	pub where_: ast_token::Where,
 	// TODO: The reference says that this can't have a trailing comma,
 	//       but the compiler accepts it, so we do to.
-	#[format(prefix_ws = Whitespace::INDENT)]
-	#[format(indent)]
-	#[format(args = punct::fmt(Whitespace::INDENT, Whitespace::REMOVE))]
+	#[format(prefix_ws = args.prefix_ws())]
+	#[format(indent(if_ = matches!(args, FmtSingleOrIndent::Indent)))]
+	#[format(args = punct::fmt(args.prefix_ws(), Whitespace::REMOVE))]
 	pub items:  Option<PunctuatedTrailing<WhereClauseItem, ast_token::Comma>>,
 }
 
+impl WhereClause {
+	/// Returns the prefix whitespace of whatever follows an optional `where` clause.
+	///
+	/// If the clause was formatted vertically, it goes on a new line, otherwise
+	/// it stays on the same line.
+	pub fn suffix_ws(where_: &mut Option<Self>, ctx: &mut format::Context) -> WhitespaceConfig {
+		let is_vertical = where_
+			.as_mut()
+			.and_then(|where_| where_
+				.where_
+				.prefix_ws_has_newlines(ctx))
+			.unwrap_or(false);
+		match is_vertical {
+			true => Whitespace::INDENT,
+			false => Whitespace::SINGLE,
+		}
+	}
+
+	fn format_single(&mut self, ctx: &mut format::Context) -> FormatOutput {
+		if let Some(items) = &mut self.items {
+			items.trailing = None;
+		}
+
+		self.format(
+			ctx,
+			Whitespace::SINGLE,
+			FmtSingleOrIndent::Single,
+		)
+	}
+}
+
+/// Formats a `where` clause.
+///
+/// The prefix whitespace is only used when the clause is formatted vertically,
+/// otherwise it stays on the same line.
+impl Format<WhitespaceConfig, ()> for WhereClause {
+	fn format(
+		&mut self,
+		ctx: &mut format::Context,
+		prefix_ws: WhitespaceConfig,
+		_args: (),
+	) -> FormatOutput {
+		let max_where_clause_len = ctx.config().max_where_clause_len;
+		match ctx.config().where_style {
+			WhereStyle::Vertical => self
+				.format(ctx, prefix_ws, FmtSingleOrIndent::Indent),
+			WhereStyle::Auto => ctx.format_single_or_break(
+				self,
+				Self::format_single,
+				|output| output.multiline.is_some()
+					|| output.len_non_multiline_ws() > max_where_clause_len,
+				|this, ctx| this
+					.format(ctx, prefix_ws, FmtSingleOrIndent::Indent),
+			),
+		}
+	}
+}
+
 /// `WhereClauseItem`
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -6,7 +6,7 @@ use {
 	super::{function::{GenericParams, WhereClause}, trait_::AssociatedItem},
 
 	format::{Format, Formattable, WhitespaceFormat},
-	parse::Parse,
+	parse::{Parse, Parser},
 	print::Print,
 	util::Whitespace,
 };
@@ -72,7 +72,19 @@ pub struct TraitImpl {
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
+#[parse(try_with = Self::try_parse_recovering)]
 pub struct ImplBody(
 	#[format(args = format::vec::args_prefix_ws(Whitespace::INDENT))]
 	pub Vec<AssociatedItem>,
 );
+
+impl ImplBody {
+	/// Parses all associated items, skipping any that fail to parse, if recovering
+	#[expect(clippy::unnecessary_wraps, reason = "It's required by `#[parse(try_with)]`")]
+	fn try_parse_recovering(parser: &mut Parser) -> Result<Option<Self>, ImplBodyError> {
+		Ok(
+			AssociatedItem::try_parse_all_recovering(parser)
+				.map(Self),
+		)
+	}
+}
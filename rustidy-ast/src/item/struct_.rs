@@ -61,16 +61,18 @@ pub enum StructStructInner {
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
 pub struct StructFields(#[format(args = {
+	let tab_width = ctx.config().tab_width;
 	let max_ident_len = self.0
 		.values()
-		.map(|field| field.0.inner.ident.non_ws_len())
+		.map(|field| &field.0.inner.ident)
+		.map(|ident| ident.non_ws_width(tab_width))
 		.max()
 		.expect("At least one element exists");
 	punct::fmt_with(
 		Whitespace::INDENT,
 		Whitespace::REMOVE,
 		StructFieldInnerArgs { max_ident_len },
-		()
+		(),
 	)
 })] PunctuatedTrailing<StructField, ast_token::Comma>);
 
@@ -95,7 +97,7 @@ pub struct StructFieldInner {
 	#[format(prefix_ws = Whitespace::REMOVE)]
 	pub colon: ast_token::Colon,
 	#[format(prefix_ws = {
-		let ident_len = self.ident.non_ws_len();
+		let ident_len = self.ident.non_ws_width(ctx.config().tab_width);
 		let ty_prefix_ws_len = 1 + args.max_ident_len - ident_len;
 		Whitespace::spaces(ty_prefix_ws_len)
 	})]
@@ -144,32 +146,30 @@ impl TupleStruct {
 		fields: &mut Parenthesized<Option<TupleFields>>,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		_args: ()
+		_args: (),
 	) -> FormatOutput {
-		if let Some(fields) = &mut fields.value {
-			fields.0.trailing = None;
-		}
-		let output = fields
-			.format(ctx, prefix_ws, delimited::FmtRemoveWith(
-				TupleFieldsFmt { field_prefix_ws: Whitespace::SINGLE }
-			));
-
-		match output.len_non_multiline_ws() <= ctx.config().max_inline_tuple_struct_len {
-			true => output,
-			false => {
-				if let Some(fields) = &mut fields.value && fields.0.trailing.is_none() {
-					fields.0.trailing = Some(ast_token::Comma::new());
-				}
-
-				fields.format(
-					ctx,
-					prefix_ws,
-					delimited::fmt_indent_if_non_blank_with_value(
-						TupleFieldsFmt { field_prefix_ws: Whitespace::INDENT }
-					)
-				)
-			},
-		}
+		let max_inline_tuple_struct_len = ctx.config().max_inline_tuple_struct_len;
+		ctx.format_single_or_break(fields, |fields, ctx| {
+			if let Some(fields) = &mut fields.value {
+				fields.0.trailing = None;
+			}
+
+			fields.format(ctx, prefix_ws, delimited::FmtRemoveWith(
+				TupleFieldsFmt { field_prefix_ws: Whitespace::SINGLE },
+			))
+		}, |output| output.len_non_multiline_ws() > max_inline_tuple_struct_len, |fields, ctx| {
+			if let Some(fields) = &mut fields.value && fields.0.trailing.is_none() {
+				fields.0.trailing = Some(ast_token::Comma::new());
+			}
+
+			fields.format(
+				ctx,
+				prefix_ws,
+				delimited::fmt_indent_if_non_blank_with_value(
+					TupleFieldsFmt { field_prefix_ws: Whitespace::INDENT },
+				),
+			)
+		})
 	}
 }
 
@@ -183,7 +183,7 @@ pub struct TupleFields(
 		args.field_prefix_ws,
 		Whitespace::REMOVE,
 		args,
-		()
+		(),
 	))]
 	pub PunctuatedTrailing<TupleField, ast_token::Comma>,
 );
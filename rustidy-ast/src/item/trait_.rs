@@ -2,7 +2,10 @@
 
 // Imports
 use {
-	crate::attr::{self, BracedWithInnerAttributes, WithOuterAttributes},
+	crate::{
+		attr::{self, BracedWithInnerAttributes, WithOuterAttributes},
+		verbatim::{self, Verbatim},
+	},
 	super::{
 		ConstantItem,
 		Function,
@@ -12,8 +15,9 @@ use {
 		function::{GenericParams, TypeParamBounds, WhereClause},
 	},
 	ast_literal::Identifier,
+	core::iter,
 	format::{Format, Formattable, WhitespaceFormat},
-	parse::Parse,
+	parse::{Parse, Parser},
 	print::Print,
 	util::Whitespace,
 };
@@ -67,11 +71,23 @@ pub struct TraitBodyEq {
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
+#[parse(try_with = Self::try_parse_recovering)]
 pub struct TraitBodyFull(
 	#[format(args = format::vec::args_prefix_ws(Whitespace::INDENT))]
 	pub Vec<AssociatedItem>,
 );
 
+impl TraitBodyFull {
+	/// Parses all associated items, skipping any that fail to parse, if recovering
+	#[expect(clippy::unnecessary_wraps, reason = "It's required by `#[parse(try_with)]`")]
+	fn try_parse_recovering(parser: &mut Parser) -> Result<Option<Self>, TraitBodyFullError> {
+		Ok(
+			AssociatedItem::try_parse_all_recovering(parser)
+				.map(Self),
+		)
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
@@ -90,12 +106,32 @@ pub struct AssociatedItem(
 	pub WithOuterAttributes<AssociatedItemInner>,
 );
 
+impl AssociatedItem {
+	/// Parses all associated items, skipping any that fail to parse, if recovering
+	pub(super) fn try_parse_all_recovering(parser: &mut Parser) -> Option<Vec<Self>> {
+		if !parser.is_recovering() {
+			return None;
+		}
+
+		let items = iter::from_fn(|| verbatim::parse_or_recover(
+			parser,
+			|verbatim| Self(WithOuterAttributes {
+				attrs: vec![],
+				inner: AssociatedItemInner::Verbatim(verbatim)
+			}),
+		))
+			.collect();
+		Some(items)
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Parse, Formattable, Format, Print)]
 pub enum AssociatedItemInner {
 	Macro(MacroInvocationSemi),
 	Vis(AssociatedItemVis),
+	Verbatim(Verbatim),
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
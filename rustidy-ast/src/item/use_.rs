@@ -253,7 +253,7 @@ impl UseTreeGroup {
 					},
 					UseTree::Simple(tree) => SortOrder::WithPath(SimplePathSortOrder(&tree.path)),
 				}
-			}
+			},
 		);
 	}
 
@@ -294,7 +294,7 @@ impl UseTreeGroup {
 									.ws
 									.join_prefix(group.tree.prefix.ws);
 								sub_trees.push(
-									PunctuatedRest { punct: comma, value: trees.punctuated.first, }
+									PunctuatedRest { punct: comma, value: trees.punctuated.first, },
 								);
 								for rest in trees.punctuated.rest {
 									sub_trees.push(rest);
@@ -351,13 +351,15 @@ impl UseTreeGroup {
 		prefix_ws: WhitespaceConfig,
 		_args: (),
 	) -> FormatOutput {
-		let compact_output = Self::format_tree_compact(tree, ctx, prefix_ws);
-
 		// Note: We don't use `len_non_multiline_ws` because we never want to emit
 		//       something like `{a, b::{\n...\n}, c, d}`, and if any newlines are
 		//       found we'd instead want to make it multi-line.
-		match compact_output.len_without_prefix_ws() > ctx.config().max_use_tree_len {
-			true => {
+		let max_use_tree_len = ctx.config().max_use_tree_len;
+		ctx.format_single_or_break(
+			tree,
+			|tree, ctx| Self::format_tree_compact(tree, ctx, prefix_ws),
+			|output| output.len_without_prefix_ws() > max_use_tree_len,
+			|tree, ctx| {
 				if let Some(punct) = &mut tree.value && punct.trailing.is_none() {
 					punct.trailing = Some(ast_token::Comma::new());
 				}
@@ -370,11 +372,10 @@ impl UseTreeGroup {
 						punct_prefix_ws: Whitespace::REMOVE,
 						value_args: (),
 						punct_args: (),
-					}, (),),
+					}, ()),
 				)
 			},
-			false => compact_output,
-		}
+		)
 	}
 }
 
@@ -41,6 +41,7 @@ pub mod shebang;
 pub mod stmt;
 pub mod ty;
 pub mod util;
+pub mod verbatim;
 pub mod vis;
 
 // Imports
@@ -70,12 +71,12 @@ impl Format<(), ()> for Crate {
 		&mut self,
 		ctx: &mut format::Context,
 		_prefix_ws: (),
-		_args: ()
+		_args: (),
 	) -> FormatOutput {
 		let mut ctx = ctx.sub_context();
-		for attr in &self.inner_attrs {
-			if let Some(attr) = attr.try_as_attr_ref() && let Err(err) = attr::update_from_attr(&attr.attr.value, &mut ctx) {
-				tracing::warn!("Malformed `#![rustidy::config(...)]` attribute: {err:?}");
+		for attr in &mut self.inner_attrs {
+			if let Some(attr) = attr.try_as_attr_mut() {
+				attr::update_from_attr(&mut attr.attr.value, &mut ctx);
 			}
 		}
 
@@ -115,7 +116,7 @@ impl Format<(), ()> for Crate {
 
 		ctx.format(
 			&mut self.suffix_ws,
-			Whitespace::indent(output.is_empty)
+			Whitespace::indent(output.is_empty),
 		).append_to(&mut output);
 
 		output
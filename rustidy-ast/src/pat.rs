@@ -146,7 +146,7 @@ pub struct StructPattern {
 	#[format(args = delimited::fmt_single_or_indent_if_non_blank(
 		50,
 		FmtSingleOrIndent::Single,
-		FmtSingleOrIndent::Indent
+		FmtSingleOrIndent::Indent,
 	))]
 	pub items: Braced<Option<StructPatternElements>>,
 }
@@ -330,7 +330,10 @@ pub struct LiteralPattern {
 }
 
 impl ParsePeeked<ByteLiteral> for LiteralPattern {
-	fn parse_from_with_peeked(_parser: &mut parse::Parser, parsed: ByteLiteral) -> Result<Self, Self::Error> {
+	fn parse_from_with_peeked(
+		_parser: &mut parse::Parser,
+		parsed: ByteLiteral,
+	) -> Result<Self, Self::Error> {
 		Ok(Self {
 			minus: None,
 			literal: LiteralExpression::Byte(parsed),
@@ -339,7 +342,10 @@ impl ParsePeeked<ByteLiteral> for LiteralPattern {
 }
 
 impl ParsePeeked<ByteStringLiteral> for LiteralPattern {
-	fn parse_from_with_peeked(_parser: &mut parse::Parser, parsed: ByteStringLiteral) -> Result<Self, Self::Error> {
+	fn parse_from_with_peeked(
+		_parser: &mut parse::Parser,
+		parsed: ByteStringLiteral,
+	) -> Result<Self, Self::Error> {
 		Ok(Self {
 			minus: None,
 			literal: LiteralExpression::ByteString(parsed),
@@ -9,6 +9,7 @@ use {
 		item::Item,
 		pat::PatternNoTopAlt,
 		ty::Type,
+		verbatim::Verbatim,
 	},
 
 	format::{Format, Formattable, WhitespaceFormat},
@@ -34,6 +35,7 @@ pub enum StatementInner {
 	Let(LetStatement),
 	Expression(ExpressionStatement),
 	Item(Item),
+	Verbatim(Verbatim),
 }
 
 /// `LetStatement`
@@ -94,7 +96,7 @@ impl Parse for LetStatementEq {
 			Ok(else_) => {
 				let else_expr = parser.parse()?;
 				Ok(Self::Else(
-					LetStatementEqElse { eq, expr, else_, else_expr, }
+					LetStatementEqElse { eq, expr, else_, else_expr, },
 				))
 			},
 			Err(_) => Ok(Self::Normal(LetStatementEqNormal { eq, expr })),
@@ -107,6 +109,7 @@ pub enum LetStatementEqError {
 	Eq(ParserError<ast_token::Eq>),
 
 	#[parse_error(transparent)]
+	#[parse_error(fatal)]
 	Expr(ParserError<Expression>),
 
 	#[parse_error(transparent)]
@@ -27,7 +27,7 @@ pub struct BareFunctionType {
 	#[format(prefix_ws(
 		expr = Whitespace::SINGLE,
 		if_ = self.for_lifetimes.is_some() || self.qualifiers.as_ref().is_some_and(
-			|qualifiers| qualifiers.unsafe_.is_some() || qualifiers.extern_.is_some()
+			|qualifiers| qualifiers.unsafe_.is_some() || qualifiers.extern_.is_some(),
 		)
 	))]
 	pub fn_:           ast_token::Fn,
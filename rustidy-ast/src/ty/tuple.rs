@@ -32,29 +32,37 @@ impl Format<WhitespaceConfig, ()> for TupleTypeInner {
 		&mut self,
 		ctx: &mut format::Context,
 		prefix_ws: WhitespaceConfig,
-		_args: ()
+		_args: (),
 	) -> FormatOutput {
-		let [(first_ty, first_comma), tys @ ..] = &mut *self.tys else { return ctx.format(&mut self.end, prefix_ws) };
+		let [(first_ty, first_comma), tys @ ..] = &mut *self.tys else {
+			return ctx.format(&mut self.end, prefix_ws)
+		};
 
 		let mut output = FormatOutput::default();
+		let column = ctx.column();
 
 		ctx
 			.format(first_ty, prefix_ws)
 			.append_to(&mut output);
+		ctx.set_column(output.end_column(column));
 		ctx
 			.format(first_comma, Whitespace::REMOVE)
 			.append_to(&mut output);
 		for (ty, comma) in tys {
+			ctx.set_column(output.end_column(column));
 			ctx
 				.format(ty, Whitespace::SINGLE)
 				.append_to(&mut output);
+			ctx.set_column(output.end_column(column));
 			ctx
 				.format(comma, Whitespace::REMOVE)
 				.append_to(&mut output);
 		}
+		ctx.set_column(output.end_column(column));
 		ctx
 			.format(&mut self.end, Whitespace::SINGLE)
 			.append_to(&mut output);
+		ctx.set_column(column);
 
 		output
 	}
@@ -0,0 +1,231 @@
+//! Verbatim
+
+// Imports
+use {
+	format::{Format, Formattable},
+	parse::{Parse, ParseError, Parser},
+	print::Print,
+	util::{AstRange, AstStr, Whitespace},
+};
+
+/// Verbatim
+///
+/// Input that failed to parse and is kept exactly as it was.
+/// Only created by the parser when it's recovering from errors (see
+/// [`Parser::set_recovering`]).
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Formattable, Format, Print)]
+pub struct Verbatim {
+	pub ws:   Whitespace,
+	#[format(str)]
+	pub text: AstStr,
+}
+
+impl Verbatim {
+	/// Recovers from `err` by skipping until the end of the current statement.
+	///
+	/// Returns `None` if there's nothing left to skip, such as at the end
+	/// of a block or of the input.
+	pub fn recover<E: ParseError>(parser: &mut Parser, err: &E) -> Option<Self> {
+		Self::recover_with(parser, err, RegionKind::Statement)
+	}
+
+	/// Recovers from `err` by skipping until the end of the current item.
+	///
+	/// Unlike statements, items always end after their body, so anything after
+	/// it on the same line isn't skipped.
+	///
+	/// Returns `None` if there's nothing left to skip, such as at the end
+	/// of a block or of the input.
+	pub fn recover_item<E: ParseError>(parser: &mut Parser, err: &E) -> Option<Self> {
+		Self::recover_with(parser, err, RegionKind::Item)
+	}
+
+	fn recover_with<E: ParseError>(parser: &mut Parser, err: &E, kind: RegionKind) -> Option<Self> {
+		let start_pos = parser.cur_pos();
+		let Ok(ws) = parser.parse_not_fatal::<Whitespace>() else {
+			return None;
+		};
+
+		let text_start_pos = parser.cur_pos();
+		let (text, ()) = parser
+			.update_with(|s| self::skip_region(s, kind));
+		if text.is_empty() {
+			parser.set_pos(start_pos);
+			return None;
+		}
+
+		let range = AstRange::new(text_start_pos, parser.cur_pos());
+		parser.add_recovered_error(err, range);
+
+		Some(Self { ws, text })
+	}
+}
+
+impl Parse for Verbatim {
+	type Error = ();
+
+	fn parse_from(_parser: &mut Parser) -> Result<Self, Self::Error> {
+		// Note: We're only created through `Self::recover`, so we never
+		//       want to be parsed directly.
+		Err(())
+	}
+}
+
+/// Parses an item `T`, or a verbatim region, if it fails to parse.
+///
+/// Returns `None` if neither could be parsed.
+pub fn parse_or_recover<T: Parse>(
+	parser: &mut Parser,
+	from_verbatim: impl FnOnce(Verbatim) -> T,
+) -> Option<T> {
+	match parser.parse_not_fatal::<T>() {
+		Ok(value) => Some(value),
+		Err(err) => Verbatim::recover_item(parser, &err)
+			.map(from_verbatim),
+	}
+}
+
+/// Kind of region to skip
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum RegionKind {
+	Item,
+	Statement,
+}
+
+/// Skips a region until the end of the current item or statement.
+///
+/// The region ends after a `;` outside of any delimiters, after a `}` that
+/// closes all delimiters (for statements, only at the end of a line), or
+/// before an unmatched `}`.
+fn skip_region(s: &mut &str, kind: RegionKind) {
+	let start = *s;
+	let mut depth = 0_usize;
+	while let Some(ch) = s.chars().next() {
+		match ch {
+			'/' if s.starts_with("//") => self::skip_line_comment(s),
+			'/' if s.starts_with("/*") => self::skip_block_comment(s),
+			'"' => self::skip_string(s),
+			'\'' => self::skip_char_or_lifetime(s),
+			'(' | '[' | '{' => {
+				depth += 1;
+				*s = &s[1..];
+			},
+			')' | ']' => {
+				depth = depth.saturating_sub(1);
+				*s = &s[1..];
+			},
+			'}' => {
+				let Some(new_depth) = depth.checked_sub(1) else {
+					break;
+				};
+				depth = new_depth;
+				*s = &s[1..];
+
+				if depth == 0 {
+					*s = s.trim_prefix(';');
+					if kind == RegionKind::Item {
+						break;
+					}
+
+					// Note: We keep going on things like `} else {` or `}.await`.
+					let rest = s.trim_start_matches([' ', '\t']);
+					if rest.is_empty() || rest.starts_with(['\n', '\r']) || rest.starts_with("//") {
+						break;
+					}
+				}
+			},
+			';' if depth == 0 => {
+				*s = &s[1..];
+				break;
+			},
+			_ if ch == '_' || ch.is_alphanumeric() => self::skip_ident_or_raw_string(s),
+			_ => *s = &s[ch.len_utf8()..],
+		}
+	}
+
+	// Note: Any trailing whitespace belongs to whatever comes after us.
+	let len = start.len() - s.len();
+	let len = start[..len].trim_end().len();
+	*s = &start[len..];
+}
+
+/// Skips a line comment
+fn skip_line_comment(s: &mut &str) {
+	let len = s.find('\n').unwrap_or(s.len());
+	*s = &s[len..];
+}
+
+/// Skips a (possibly nested) block comment
+fn skip_block_comment(s: &mut &str) {
+	let mut depth = 0_usize;
+	while let Some(ch) = s.chars().next() {
+		if let Some(rest) = s.strip_prefix("/*") {
+			depth += 1;
+			*s = rest;
+		} else if let Some(rest) = s.strip_prefix("*/") {
+			depth -= 1;
+			*s = rest;
+			if depth == 0 {
+				break;
+			}
+		} else {
+			*s = &s[ch.len_utf8()..];
+		}
+	}
+}
+
+/// Skips a string
+fn skip_string(s: &mut &str) {
+	let mut chars = s.char_indices().skip(1);
+	let len = loop {
+		match chars.next() {
+			Some((idx, '"')) => break idx + 1,
+			Some((_, '\\')) => _ = chars.next(),
+			Some(_) => (),
+			None => break s.len(),
+		}
+	};
+
+	*s = &s[len..];
+}
+
+/// Skips a character or a lifetime
+fn skip_char_or_lifetime(s: &mut &str) {
+	// Note: `'a'` and `'\n'` are characters, while `'a` is a lifetime.
+	let mut chars = s[1..].chars();
+	match (chars.next(), chars.next()) {
+		(Some('\\'), Some(escaped)) => {
+			let rest = &s[2 + escaped.len_utf8()..];
+			let len = rest.find('\'').map_or(rest.len(), |idx| idx + 1);
+			*s = &rest[len..];
+		},
+		(Some(ch), Some('\'')) => *s = &s[1 + ch.len_utf8() + 1..],
+		_ => *s = &s[1..],
+	}
+}
+
+/// Skips an identifier, or a raw string if it's a raw string prefix
+fn skip_ident_or_raw_string(s: &mut &str) {
+	let len = s
+		.find(|ch: char| ch != '_' && !ch.is_alphanumeric())
+		.unwrap_or(s.len());
+	let (ident, rest) = s.split_at(len);
+	*s = rest;
+
+	if !matches!(ident, "r" | "br" | "cr") {
+		return;
+	}
+
+	let hashes = rest.len() - rest.trim_start_matches('#').len();
+	let Some(body) = rest[hashes..].strip_prefix('"') else {
+		return;
+	};
+
+	let terminator = format!("\"{}", "#".repeat(hashes));
+	let len = body
+		.find(&terminator)
+		.map_or(body.len(), |idx| idx + terminator.len());
+	*s = &body[len..];
+}
@@ -0,0 +1,119 @@
+//! Comment directives
+
+// Imports
+use util::{AstStr, whitespace::{Comment, Whitespace}};
+
+/// Comment directive.
+///
+/// Directives are comments such as `// rustidy::skip`, which work
+/// like the equivalent attribute on the node after them.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Directive {
+	/// `rustidy::skip-start`
+	SkipStart,
+
+	/// `rustidy::skip-end`
+	SkipEnd,
+
+	/// Any other directive, which should be handled as an attribute
+	Attr {
+		/// Directive, without the comment delimiters
+		attr: String,
+
+		/// Byte position of `attr` in the input, if known
+		pos:  Option<usize>,
+	},
+}
+
+impl Directive {
+	/// Parses a directive from a comment
+	#[must_use]
+	pub fn from_comment(comment: &Comment) -> Option<Self> {
+		let (comment, prefix, suffix) = match comment {
+			Comment::Line(comment) => (&comment.0, "//", ""),
+			Comment::Block(comment) => (&comment.0, "/*", "*/"),
+		};
+
+		let comment_str = comment.str();
+		let attr = comment_str
+			.strip_prefix(prefix)?
+			.trim_end()
+			.strip_suffix(suffix)?
+			.trim();
+		if !attr.starts_with("rustidy::") {
+			return None;
+		}
+
+		let directive = match attr {
+			"rustidy::skip-start" => Self::SkipStart,
+			"rustidy::skip-end" => Self::SkipEnd,
+			_ => {
+				let offset = comment_str
+					.substr_range(attr)
+					.expect("Should be a substring")
+					.start;
+				let pos = comment
+					.input()
+					.map(|input| input.range().start + offset);
+				Self::Attr { attr: attr.to_owned(), pos }
+			},
+		};
+
+		Some(directive)
+	}
+
+	/// Returns all directives within a whitespace.
+	///
+	/// Only comments after a newline (or at the start of the input) are directives,
+	/// so that a comment after a node doesn't apply to the next one.
+	#[must_use]
+	pub fn from_whitespace(ws: &Whitespace) -> Vec<Self> {
+		let mut is_after_newline = self::starts_after_newline(ws);
+
+		let mut directives = vec![];
+		for (comment, pure) in &ws.0.rest {
+			if is_after_newline && let Some(directive) = Self::from_comment(comment) {
+				directives.push(directive);
+			}
+
+			let comment = match comment {
+				Comment::Line(comment) => &comment.0,
+				Comment::Block(comment) => &comment.0,
+			};
+			is_after_newline |= self::has_input_newlines(comment)
+				|| self::has_input_newlines(&pure.0);
+		}
+
+		directives
+	}
+
+	/// Returns if a whitespace starts with a directive-like comment
+	/// on the same line as the node before it.
+	///
+	/// These comments aren't directives, and must be kept on that line,
+	/// else they'd become directives the next time they're parsed.
+	#[must_use]
+	pub fn is_trailing(ws: &Whitespace) -> bool {
+		let Some((comment, _)) = ws.0.rest.first() else { return false };
+		!self::starts_after_newline(ws) && Self::from_comment(comment).is_some()
+	}
+}
+
+/// Returns if a whitespace starts after a newline (or at the start of the input)
+fn starts_after_newline(ws: &Whitespace) -> bool {
+	let first = &ws.0.first.0;
+	self::has_input_newlines(first) || first
+		.input()
+		.is_some_and(|input| input.range().start == 0)
+}
+
+/// Returns if a string had newlines in the input.
+///
+/// Whitespace may be formatted multiple times, so we must check the input, since
+/// formatting might have already moved a comment to its own line.
+fn has_input_newlines(s: &AstStr) -> bool {
+	match s.input() {
+		Some(input) => input.contains('\n'),
+		None => s.has_newlines(),
+	}
+}
@@ -4,6 +4,7 @@
 #![feature(
 	decl_macro,
 	never_type,
+	substr_range,
 	coverage_attribute,
 	macro_metavar_expr_concat,
 	trait_alias,
@@ -11,16 +12,20 @@
 )]
 
 // Modules
+pub mod directive;
 pub mod tag;
 pub mod vec;
 pub mod whitespace;
 pub mod output;
+pub mod warning;
 
 // Exports
 pub use {
 	self::{
+		directive::Directive,
 		output::{FormatMultilineOutput, FormatOutput},
 		tag::{FormatTag, FormatTags},
+		warning::FormatWarning,
 		whitespace::{WhitespaceFormat, WhitespaceFormatKind},
 	},
 	macros::{Format, Formattable},
@@ -30,9 +35,9 @@ pub use {
 use {
 	crate as format,
 	arcstr::ArcStr,
-	core::{marker::PhantomData, ops::ControlFlow},
-	std::borrow::Cow,
-	util::{ArenaData, ArenaIdx, AstStr, Config, Oob, Whitespace},
+	core::{marker::PhantomData, ops::{ControlFlow, Range}},
+	std::{borrow::Cow, mem, sync::Arc},
+	util::{ArenaData, ArenaIdx, AstStr, Config, LineIndex, Oob, Whitespace},
 };
 
 /// Formattable types
@@ -70,7 +75,7 @@ pub trait Formattable {
 			ws.join_prefix(
 				join_ws
 					.take()
-					.expect("`with_prefix_ws` called multiple times")
+					.expect("`with_prefix_ws` called multiple times"),
 			);
 		});
 
@@ -95,6 +100,27 @@ pub trait Formattable {
 
 	/// Returns the formatting output for this type, without formatting it.
 	fn format_output(&mut self, ctx: &mut Context) -> FormatOutput;
+
+	/// Returns the input range of this type.
+	///
+	/// Returns `None` if no part of this type came from the input.
+	fn input_range(&mut self, ctx: &mut Context, exclude_prefix_ws: bool) -> Option<Range<usize>> {
+		let mut range = None::<Range<usize>>;
+		let _: ControlFlow<!, bool> = self
+			.with_strings(ctx, exclude_prefix_ws, &mut |s, _ctx| {
+				if let Some(input) = s.input() {
+					let input = input.range();
+					range = Some(match range.take() {
+						Some(range) => range.start.min(input.start)..range.end.max(input.end),
+						None => input,
+					});
+				}
+
+				ControlFlow::Continue(())
+			});
+
+		range
+	}
 }
 
 /// Type formatting
@@ -107,7 +133,7 @@ pub trait Format<PrefixWs, Args>: Formattable {
 		&mut self,
 		ctx: &mut Context,
 		prefix_ws: PrefixWs,
-		args: Args
+		args: Args,
 	) -> FormatOutput;
 }
 
@@ -140,7 +166,7 @@ impl<T: Format<PrefixWs, Args>, PrefixWs, Args> Format<PrefixWs, Args> for &'_ m
 		&mut self,
 		ctx: &mut Context,
 		prefix_ws: PrefixWs,
-		args: Args
+		args: Args,
 	) -> FormatOutput {
 		(**self).format(ctx, prefix_ws, args)
 	}
@@ -175,7 +201,7 @@ impl<T: Format<PrefixWs, Args>, PrefixWs, Args> Format<PrefixWs, Args> for Box<T
 		&mut self,
 		ctx: &mut Context,
 		prefix_ws: PrefixWs,
-		args: Args
+		args: Args,
 	) -> FormatOutput {
 		(**self).format(ctx, prefix_ws, args)
 	}
@@ -218,7 +244,7 @@ impl<T: Format<PrefixWs, Args>, PrefixWs, Args> Format<PrefixWs, Args> for Optio
 		&mut self,
 		ctx: &mut Context,
 		prefix_ws: PrefixWs,
-		args: Args
+		args: Args,
 	) -> FormatOutput {
 		match self {
 			Some(value) => value.format(ctx, prefix_ws, args),
@@ -255,7 +281,7 @@ impl<PrefixWs, Args> Format<PrefixWs, Args> for ! {
 		&mut self,
 		_ctx: &mut Context,
 		_prefix_ws: PrefixWs,
-		_args: Args
+		_args: Args,
 	) -> FormatOutput {
 		*self
 	}
@@ -394,14 +420,15 @@ impl Formattable for AstStr {
 		ControlFlow::Continue(self.is_empty())
 	}
 
-	fn format_output(&mut self, _ctx: &mut Context) -> FormatOutput {
+	fn format_output(&mut self, ctx: &mut Context) -> FormatOutput {
 		// TODO: Optimize these by not iterating over the string multiple times.
+		let tab_width = ctx.config().tab_width;
 		FormatOutput {
 			prefix_ws_len: None,
-			len: self.len(),
+			len: self.width(tab_width),
 			is_empty: self.is_empty(),
 			is_blank: self.is_blank(),
-			multiline: FormatMultilineOutput::from_ast_str_repr(self.repr())
+			multiline: FormatMultilineOutput::from_ast_str_repr(self.repr(), tab_width)
 		}
 	}
 }
@@ -435,7 +462,7 @@ impl<T: ArenaData + Format<PrefixWs, Args>, PrefixWs, Args> Format<PrefixWs, Arg
 		&mut self,
 		ctx: &mut Context,
 		prefix_ws: PrefixWs,
-		args: Args
+		args: Args,
 	) -> FormatOutput {
 		(**self).format(ctx, prefix_ws, args)
 	}
@@ -443,47 +470,78 @@ impl<T: ArenaData + Format<PrefixWs, Args>, PrefixWs, Args> Format<PrefixWs, Arg
 
 /// Format context
 pub struct Context<'a> {
-	input:        ArcStr,
+	line_index:   Arc<LineIndex>,
 	config:       Cow<'a, Config>,
 	indent_depth: usize,
+	column:       usize,
 	tags:         Oob<'a, FormatTags>,
+	ranges:       Option<&'a [Range<usize>]>,
+	warnings:     Oob<'a, Vec<FormatWarning>>,
 }
 
 impl<'a> Context<'a> {
 	/// Creates a new context
 	#[must_use]
 	pub fn new(input: impl Into<ArcStr>, config: &'a Config) -> Self {
+		Self::from_line_index(Arc::new(LineIndex::new(input)), config)
+	}
+
+	/// Creates a new context from the line index of it's input
+	#[must_use]
+	pub const fn from_line_index(line_index: Arc<LineIndex>, config: &'a Config) -> Self {
 		Self {
-			input: input.into(),
+			line_index,
 			config: Cow::Borrowed(config),
 			indent_depth: 0,
+			column: 0,
 			tags: Oob::Owned(FormatTags::new()),
+			ranges: None,
+			warnings: Oob::Owned(vec![]),
 		}
 	}
 
 	/// Formats a value
 	pub fn format<T, PrefixWs>(&mut self, value: &mut T, prefix_ws: PrefixWs) -> FormatOutput
 	where
-		T: Format<PrefixWs, ()>
+		T: Format<PrefixWs, ()>,
 	{
 		self.format_with(value, prefix_ws, ())
 	}
 
 	/// Formats a value with arguments
-	pub fn format_with<T, PrefixWs, A>(&mut self, value: &mut T, prefix_ws: PrefixWs, args: A) -> FormatOutput
+	pub fn format_with<T, PrefixWs, A>(
+		&mut self,
+		value: &mut T,
+		prefix_ws: PrefixWs,
+		args: A,
+	) -> FormatOutput
 	where
-		T: Format<PrefixWs, A>
+		T: Format<PrefixWs, A>,
 	{
-		match self.config().skip {
-			true => value.format_output(self),
-			false => value.format(self, prefix_ws, args),
+		if self.config().skip {
+			return value.format_output(self);
+		}
+
+		if self.ranges.is_some() {
+			let range = value.input_range(self, false);
+			if !self.is_in_ranges(range) {
+				return value.format_output(self);
+			}
 		}
+
+		value.format(self, prefix_ws, args)
 	}
 
 	/// Returns the input
 	#[must_use]
-	pub const fn input(&self) -> &ArcStr {
-		&self.input
+	pub fn input(&self) -> &ArcStr {
+		self.line_index.input()
+	}
+
+	/// Returns the line index of the input
+	#[must_use]
+	pub const fn line_index(&self) -> &Arc<LineIndex> {
+		&self.line_index
 	}
 
 	/// Returns the config
@@ -525,7 +583,7 @@ impl<'a> Context<'a> {
 	pub fn without_indent_if<O>(
 		&mut self,
 		pred: bool,
-		f: impl for<'b> FnOnce(&'b mut Self) -> O
+		f: impl for<'b> FnOnce(&'b mut Self) -> O,
 	) -> O {
 		self.with_indent_offset_if(-1, pred, f)
 	}
@@ -534,7 +592,7 @@ impl<'a> Context<'a> {
 	pub fn with_indent_offset<O>(
 		&mut self,
 		offset: i16,
-		f: impl for<'b> FnOnce(&'b mut Self) -> O
+		f: impl for<'b> FnOnce(&'b mut Self) -> O,
 	) -> O {
 		let prev_depth = self.indent_depth;
 		self.indent_depth = prev_depth
@@ -557,6 +615,119 @@ impl<'a> Context<'a> {
 		}
 	}
 
+	/// Returns the column that the value being formatted starts at.
+	///
+	/// This is the column before it's prefix whitespace.
+	#[must_use]
+	pub const fn column(&self) -> usize {
+		self.column
+	}
+
+	/// Sets the column that the next value being formatted starts at.
+	///
+	/// Types formatting multiple values in sequence should set this before
+	/// each of them, and restore it afterwards.
+	pub const fn set_column(&mut self, column: usize) {
+		self.column = column;
+	}
+
+	/// Returns if `output`, starting at the current column, exceeds
+	/// the maximum line width.
+	///
+	/// Only the first and last lines of `output` are checked.
+	#[must_use]
+	pub fn exceeds_max_width(&self, output: &FormatOutput) -> bool {
+		if self.has_tag::<tag::IgnoreMaxWidth>() {
+			return false;
+		}
+
+		let max_width = self.config().max_width;
+		match output.multiline {
+			Some(multiline) => {
+				// Note: If we don't add anything to the current line, we can't make it any longer.
+				let prefix_end = self.column + multiline.prefix_len;
+				let prefix_exceeds = multiline.prefix_len != 0 && prefix_end > max_width;
+				prefix_exceeds || multiline.suffix_len > max_width
+			},
+			None => self.column + output.len > max_width,
+		}
+	}
+
+	/// Formats a value with `format_single`, unless it exceeds the maximum
+	/// line width or `should_break` returns true, in which case it's formatted
+	/// with `format_break` instead.
+	///
+	/// The maximum width isn't checked for any values inside of `format_single`,
+	/// since they should only be broken if we are not.
+	pub fn format_single_or_break<T: ?Sized>(
+		&mut self,
+		value: &mut T,
+		mut format_single: impl FnMut(&mut T,&mut Self) -> FormatOutput,
+		should_break: impl FnOnce(&FormatOutput) -> bool,
+		format_break: impl FnOnce(&mut T,&mut Self) -> FormatOutput,
+	) -> FormatOutput {
+		let is_ignoring_max_width = self.has_tag::<tag::IgnoreMaxWidth>();
+		let output = self
+			.with_tag::<tag::IgnoreMaxWidth, _>(|ctx| format_single(value, ctx));
+		if should_break(&output) || self.exceeds_max_width(&output) {
+			return format_break(value, self);
+		}
+
+		// Note: If we're multi-line, any lines other than the first and last
+		//       weren't checked, so we need to format again to check them.
+		match !is_ignoring_max_width && output.multiline.is_some() {
+			true => format_single(value, self),
+			false => output,
+		}
+	}
+
+	/// Sets the input ranges to format.
+	///
+	/// Values and whitespace that don't overlap these ranges will be left untouched.
+	pub const fn set_ranges(&mut self, ranges: &'a [Range<usize>]) {
+		self.ranges = Some(ranges);
+	}
+
+	/// Returns if an input range overlaps the formatting ranges.
+	///
+	/// Empty ranges overlap if their position is within a formatting range.
+	/// Ranges without an input (e.g. created during formatting) are always formatted.
+	#[must_use]
+	pub fn is_in_ranges(&self, range: Option<Range<usize>>) -> bool {
+		let (Some(ranges), Some(range)) = (self.ranges, range) else {
+			return true;
+		};
+
+		let range_end = range.end.max(range.start + 1);
+		ranges.iter().any(
+			|fmt_range| range.start < fmt_range.end && fmt_range.start < range_end,
+		)
+	}
+
+	/// Returns if an input range is entirely within the formatting ranges.
+	///
+	/// Used for changes other than whitespace, which can't be partially applied.
+	/// Ranges without an input (e.g. created during formatting) are always within them.
+	#[must_use]
+	pub fn is_within_ranges(&self, range: Option<Range<usize>>) -> bool {
+		let (Some(ranges), Some(range)) = (self.ranges, range) else {
+			return true;
+		};
+
+		// Note: The range may span multiple adjacent formatting ranges.
+		let mut cur_pos = range.start;
+		while cur_pos < range.end {
+			match ranges
+				.iter()
+				.find(|fmt_range| fmt_range.contains(&cur_pos)) {
+				Some(fmt_range) => cur_pos = fmt_range.end,
+				None => return false,
+			}
+		}
+
+		true
+	}
+
 	#[doc(hidden)]
 	pub const fn set_indent_depth(&mut self, indent_depth: usize) {
 		self.indent_depth = indent_depth;
@@ -567,13 +738,31 @@ impl<'a> Context<'a> {
 	/// Sub contexts have their own configuration
 	pub fn sub_context(&mut self) -> Context<'_> {
 		Context {
-			input: ArcStr::clone(&self.input),
+			line_index: Arc::clone(&self.line_index),
 			config: Cow::Borrowed(&self.config),
 			indent_depth: self.indent_depth,
+			column: self.column,
 			tags: Oob::Borrowed(&mut self.tags),
+			ranges: self.ranges,
+			warnings: Oob::Borrowed(&mut self.warnings),
 		}
 	}
 
+	/// Adds a warning.
+	///
+	/// Since values may be formatted more than once, duplicate
+	/// warnings are ignored.
+	pub fn warn(&mut self, warning: FormatWarning) {
+		if !self.warnings.contains(&warning) {
+			self.warnings.push(warning);
+		}
+	}
+
+	/// Takes all warnings emitted so far
+	pub fn take_warnings(&mut self) -> Vec<FormatWarning> {
+		mem::take(&mut self.warnings)
+	}
+
 	/// Adds a tag.
 	///
 	/// Returns the previous tag data, if any
@@ -620,7 +809,11 @@ impl<'a> Context<'a> {
 	}
 
 	/// Runs `f` with a tag and data, removing it after
-	pub fn with_tag_with<Tag: FormatTag, O>(&mut self, data: Tag::Data, f: impl FnOnce(&mut Self) -> O) -> O {
+	pub fn with_tag_with<Tag: FormatTag, O>(
+		&mut self,
+		data: Tag::Data,
+		f: impl FnOnce(&mut Self) -> O,
+	) -> O {
 		let prev_data = self.add_tag_with::<Tag>(data);
 		let output = f(self);
 		self.set_tag::<Tag>(prev_data);
@@ -629,7 +822,11 @@ impl<'a> Context<'a> {
 	}
 
 	/// Runs `f` with a tag if `pred` is true, removing it after
-	pub fn with_tag_if<Tag: FormatTag<Data = ()>, O>(&mut self, pred: bool, f: impl FnOnce(&mut Self) -> O) -> O {
+	pub fn with_tag_if<Tag: FormatTag<Data = ()>, O>(
+		&mut self,
+		pred: bool,
+		f: impl FnOnce(&mut Self) -> O,
+	) -> O {
 		self.with_tag_with_if::<Tag, _>(pred, (), f)
 	}
 
@@ -638,7 +835,7 @@ impl<'a> Context<'a> {
 		&mut self,
 		pred: bool,
 		data: Tag::Data,
-		f: impl FnOnce(&mut Self) -> O
+		f: impl FnOnce(&mut Self) -> O,
 	) -> O {
 		match pred {
 			true => self.with_tag_with::<Tag, _>(data, f),
@@ -656,7 +853,11 @@ impl<'a> Context<'a> {
 	}
 
 	/// Runs `f` without a tag if `pred` is true, adding it after if it existed
-	pub fn without_tag_if<Tag: FormatTag, O>(&mut self, pred: bool, f: impl FnOnce(&mut Self) -> O) -> O {
+	pub fn without_tag_if<Tag: FormatTag, O>(
+		&mut self,
+		pred: bool,
+		f: impl FnOnce(&mut Self) -> O,
+	) -> O {
 		match pred {
 			true => self.without_tag::<Tag, _>(f),
 			false => f(self),
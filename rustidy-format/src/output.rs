@@ -10,7 +10,7 @@ pub struct FormatOutput {
 	/// Prefix whitespace length, if any
 	pub prefix_ws_len: Option<usize>,
 
-	/// Total length of this type
+	/// Total length of this type, in display columns
 	pub len:           usize,
 
 	/// Whether the type was empty
@@ -46,6 +46,15 @@ impl FormatOutput {
 		}
 	}
 
+	/// Returns the column this type ends at, given the column it starts at
+	#[must_use]
+	pub const fn end_column(&self, column: usize) -> usize {
+		match self.multiline {
+			Some(multiline) => multiline.suffix_len,
+			None => column + self.len,
+		}
+	}
+
 	/// Joins two format outputs.
 	///
 	/// You must ensure that `rhs` directly follows `lhs`.
@@ -107,25 +116,24 @@ pub struct FormatMultilineOutput {
 	/// Prefix whitespace length (before the first newline)
 	pub prefix_ws_len: Option<usize>,
 
-	/// Prefix length (before the first newline)
+	/// Prefix length (before the first newline), in display columns
 	pub prefix_len:    usize,
 
-	/// Suffix length (after the last newline)
+	/// Suffix length (after the last newline), in display columns
 	pub suffix_len:    usize,
 }
 
 impl FormatMultilineOutput {
 	/// Gets the multi-line output of a string
 	#[must_use]
-	#[expect(clippy::should_implement_trait, reason = "The trait semantics are different")]
-	pub fn from_str(s: &str) -> Option<Self> {
+	pub fn from_str(s: &str, tab_width: usize) -> Option<Self> {
 		let (prefix, _) = s.split_once('\n')?;
 		let (_, suffix) = s.rsplit_once('\n')?;
 
 		Some(Self {
 			prefix_ws_len: None,
-			prefix_len: prefix.len(),
-			suffix_len: suffix.len(),
+			prefix_len: util::str_width(prefix, tab_width),
+			suffix_len: util::str_width(suffix, tab_width),
 		})
 	}
 
@@ -135,17 +143,17 @@ impl FormatMultilineOutput {
 		lhs: Option<Self>,
 		rhs: Option<Self>,
 		lhs_len: usize,
-		rhs_len: usize
+		rhs_len: usize,
 	) -> Option<Self> {
 		match (lhs, rhs) {
 			(Some(lhs), Some(rhs)) => Some(Self {
 				prefix_ws_len: self::join_prefix_ws(
 					lhs.prefix_ws_len,
 					rhs.prefix_ws_len,
-					lhs.prefix_len
+					lhs.prefix_len,
 				),
 				prefix_len: lhs.prefix_len,
-				suffix_len: rhs.suffix_len
+				suffix_len: rhs.suffix_len,
 			}),
 			(Some(lhs), None) => Some(Self {
 				prefix_ws_len: lhs.prefix_ws_len,
@@ -158,7 +166,7 @@ impl FormatMultilineOutput {
 					false => None,
 				},
 				prefix_len: rhs.prefix_len + lhs_len,
-				suffix_len: rhs.suffix_len
+				suffix_len: rhs.suffix_len,
 			}),
 			(None, None) => None,
 		}
@@ -166,10 +174,10 @@ impl FormatMultilineOutput {
 
 	/// Gets the multi-line output of an ast string repr
 	#[must_use]
-	pub fn from_ast_str_repr(repr: &AstStrRepr) -> Option<Self> {
+	pub fn from_ast_str_repr(repr: &AstStrRepr, tab_width: usize) -> Option<Self> {
 		match *repr {
-			AstStrRepr::String(ref s) => Self::from_str(s),
-			AstStrRepr::Static(s) => Self::from_str(s),
+			AstStrRepr::String(ref s) => Self::from_str(s, tab_width),
+			AstStrRepr::Static(s) => Self::from_str(s, tab_width),
 			AstStrRepr::Char(ch) => match ch == '\n' {
 				true => Some(Self {
 					prefix_ws_len: None,
@@ -184,14 +192,14 @@ impl FormatMultilineOutput {
 				_ => Some(Self {
 					prefix_ws_len: None,
 					prefix_len: 0,
-					suffix_len: depth * indent.len(),
-				})
+					suffix_len: depth * util::str_width(indent, tab_width),
+				}),
 			},
 			AstStrRepr::Join { ref lhs, ref rhs } => Self::join(
-				Self::from_ast_str_repr(lhs.repr()),
-				Self::from_ast_str_repr(rhs.repr()),
-				lhs.len(),
-				rhs.len(),
+				Self::from_ast_str_repr(lhs.repr(), tab_width),
+				Self::from_ast_str_repr(rhs.repr(), tab_width),
+				lhs.width(tab_width),
+				rhs.width(tab_width),
 			),
 		}
 	}
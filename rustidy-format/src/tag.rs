@@ -105,6 +105,9 @@ decl_tags! {
 	/// Inside chain
 	InsideChain: InsideChainData,
 
+	/// Inside binary operator chain
+	InsideOperatorChain: InsideOperatorChainData,
+
 	/// After newline
 	// Note: This attribute only works because every time
 	//       we apply it, there's always whitespace directly
@@ -114,9 +117,22 @@ decl_tags! {
 	//       during formatting, we no longer necessarily have
 	//       the input ranges.
 	AfterNewline: (),
+
+	/// Inside a `rustidy::skip-start` / `rustidy::skip-end` region
+	SkipRegion: (),
+
+	/// Formatting a single-line candidate, so the maximum width
+	/// shouldn't be checked yet.
+	IgnoreMaxWidth: (),
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct InsideChainData {
 	pub indent: bool
 }
+
+#[derive(Clone, Copy, Debug)]
+pub struct InsideOperatorChainData {
+	/// Precedence of the operators to break before, if any
+	pub break_precedence: Option<usize>,
+}
@@ -59,21 +59,23 @@ where
 		&mut self,
 		ctx: &mut Context,
 		prefix_ws: PrefixWs,
-		args: Args<PrefixWs, A>
+		args: Args<PrefixWs, A>,
 	) -> FormatOutput {
 		// Note: Due to the way we're parsed, the first element will never be non-empty,
 		//       but it's possible for the caller to create this value during formatting
 		//       and have that not be true, so we always check.
 		let mut output = FormatOutput::default();
 		let mut prefix_ws = Some(prefix_ws);
+		let column = ctx.column();
 		for value in self {
+			ctx.set_column(output.end_column(column));
 			let value_output = match &prefix_ws {
 				Some(prefix_ws) => ctx
 					.format_with(value, prefix_ws.clone(), args.args.clone()),
 				None => ctx.format_with(
 					value,
 					args.rest_prefix_ws.clone(),
-					args.args.clone()
+					args.args.clone(),
 				),
 			};
 			value_output.append_to(&mut output);
@@ -82,6 +84,7 @@ where
 				prefix_ws = None;
 			}
 		}
+		ctx.set_column(column);
 
 		output
 	}
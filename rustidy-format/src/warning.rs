@@ -0,0 +1,9 @@
+//! Formatting warnings
+
+/// Formatting warning
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct FormatWarning {
+	/// Byte position of the warning in the input, if known
+	pub pos:     Option<usize>,
+	pub message: String,
+}
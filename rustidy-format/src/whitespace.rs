@@ -2,8 +2,9 @@
 
 // Imports
 use {
-	crate::{Format, FormatOutput, Formattable, WhitespaceConfig, tag},
-	core::ops::ControlFlow,
+	crate::{Directive, Format, FormatOutput, Formattable, WhitespaceConfig, tag},
+	arcstr::Substr,
+	core::ops::{ControlFlow, Range},
 	itertools::Itertools,
 	std::sync::Arc,
 	util::{AstStr, ast_str::AstStrRepr, whitespace::{Comment, Whitespace}},
@@ -12,17 +13,17 @@ use {
 #[extend::ext(name = WhitespaceFormat)]
 pub impl Whitespace {
 	const INDENT: WhitespaceConfig = WhitespaceConfig { format: Some(
-		WhitespaceFormatKind::Indent { use_prev: false, remove_if_pure: false, }
+		WhitespaceFormatKind::Indent { use_prev: false, remove_if_pure: false, },
 	), };
 	const PRESERVE: WhitespaceConfig = WhitespaceConfig { format: None };
 	const INDENT_CLOSE: WhitespaceConfig = WhitespaceConfig { format: Some(
-		WhitespaceFormatKind::Indent { use_prev: true, remove_if_pure: false, }
+		WhitespaceFormatKind::Indent { use_prev: true, remove_if_pure: false, },
 	), };
 	const INDENT_CLOSE_REMOVE_IF_PURE: WhitespaceConfig = WhitespaceConfig { format: Some(
-		WhitespaceFormatKind::Indent { use_prev: true, remove_if_pure: true, }
+		WhitespaceFormatKind::Indent { use_prev: true, remove_if_pure: true, },
 	), };
 	const INDENT_REMOVE_IF_PURE: WhitespaceConfig = WhitespaceConfig { format: Some(
-		WhitespaceFormatKind::Indent { use_prev: false, remove_if_pure: true, }
+		WhitespaceFormatKind::Indent { use_prev: false, remove_if_pure: true, },
 	), };
 	const REMOVE: WhitespaceConfig = WhitespaceConfig { format: Some(WhitespaceFormatKind::Remove), };
 	const SINGLE: WhitespaceConfig = WhitespaceConfig {
@@ -39,13 +40,13 @@ pub impl Whitespace {
 
 	fn indent(remove_if_pure: bool) -> WhitespaceConfig {
 		WhitespaceConfig { format: Some(
-			WhitespaceFormatKind::Indent { use_prev: false, remove_if_pure }
+			WhitespaceFormatKind::Indent { use_prev: false, remove_if_pure },
 		), }
 	}
 
 	fn prev_indent(remove_if_pure: bool) -> WhitespaceConfig {
 		WhitespaceConfig { format: Some(
-			WhitespaceFormatKind::Indent { use_prev: true, remove_if_pure }
+			WhitespaceFormatKind::Indent { use_prev: true, remove_if_pure },
 		), }
 	}
 
@@ -65,10 +66,34 @@ pub impl Whitespace {
 			|(comment, ws)| ws.0.has_newlines() || match comment {
 				Comment::Block(comment) => comment.0.has_newlines(),
 				Comment::Line(comment) => comment.0.has_newlines(),
-			}
+			},
 		)
 	}
 
+	/// Returns the input range of this whitespace.
+	///
+	/// Returns `None` if no part of this whitespace came from the input.
+	fn input_range(&mut self) -> Option<Range<usize>> {
+		let mut range = self.0.first.0.input().map(Substr::range);
+		for (comment, ws) in &self.0.rest {
+			let comment = match comment {
+				Comment::Line(comment) => &comment.0,
+				Comment::Block(comment) => &comment.0,
+			};
+
+			let inputs = [comment.input(), ws.0.input()];
+			for input in inputs.into_iter().flatten() {
+				let input = input.range();
+				range = Some(match range {
+					Some(range) => range.start.min(input.start)..range.end.max(input.end),
+					None => input,
+				});
+			}
+		}
+
+		range
+	}
+
 	/// Joins `other` to this whitespace as a suffix
 	fn join_suffix(&mut self, other: Self) {
 		let lhs = &mut *self.0;
@@ -81,7 +106,7 @@ pub impl Whitespace {
 
 		replace_with::replace_with_or_abort(
 			&mut lhs_last.0,
-			|lhs_last| AstStr::join(lhs_last, rhs.first.0)
+			|lhs_last| AstStr::join(lhs_last, rhs.first.0),
 		);
 		lhs.rest.append(&mut rhs.rest);
 	}
@@ -159,7 +184,7 @@ impl Format<(), ()> for Whitespace {
 		&mut self,
 		_ctx: &mut crate::Context,
 		_prefix_ws: (),
-		_args: ()
+		_args: (),
 	) -> FormatOutput {
 		if !self.is_empty() {
 			tracing::warn!("Whitespace was not empty");
@@ -173,8 +198,15 @@ impl Format<WhitespaceConfig, ()> for Whitespace {
 		&mut self,
 		ctx: &mut crate::Context,
 		prefix_ws: WhitespaceConfig,
-		_args: ()
+		_args: (),
 	) -> FormatOutput {
+		// Note: Skip regions may end right before a closing delimiter, so
+		//       we must check every whitespace for the end of one.
+		if Directive::from_whitespace(self)
+			.contains(&Directive::SkipEnd) {
+			ctx.remove_tag::<tag::SkipRegion>();
+		}
+
 		if let Some(format) = prefix_ws.format {
 			self::format(self, ctx, format);
 		}
@@ -209,7 +241,7 @@ impl WhitespaceFormatKind {
 	fn indent_str_nl(
 		ctx: &mut crate::Context,
 		cur_str: &AstStr,
-		after_newline: bool
+		after_newline: bool,
 	) -> AstStrRepr {
 		let min_newlines = ctx.config().min_empty_lines;
 		let max_newlines = ctx.config().max_empty_lines;
@@ -238,7 +270,7 @@ impl WhitespaceFormatKind {
 		ctx: &mut crate::Context,
 		cur_str: &AstStr,
 		is_last: bool,
-		after_newline: bool
+		after_newline: bool,
 	) -> AstStrRepr {
 		match self {
 			Self::Remove => "".into(),
@@ -248,7 +280,7 @@ impl WhitespaceFormatKind {
 				false => ctx.with_indent_offset_if(
 					-1,
 					use_prev && is_last,
-					|ctx| Self::indent_str_nl(ctx, cur_str, after_newline)
+					|ctx| Self::indent_str_nl(ctx, cur_str, after_newline),
 				),
 			},
 		}
@@ -259,7 +291,7 @@ impl WhitespaceFormatKind {
 		self,
 		ctx: &mut crate::Context,
 		cur_str: &AstStr,
-		is_last: bool
+		is_last: bool,
 	) -> AstStrRepr {
 		match self {
 			Self::Remove | Self::Spaces { .. } => "".into(),
@@ -267,7 +299,7 @@ impl WhitespaceFormatKind {
 				true => ctx.with_indent_offset_if(
 					-1,
 					use_prev,
-					|ctx| Self::indent_str_nl(ctx, cur_str, true)
+					|ctx| Self::indent_str_nl(ctx, cur_str, true),
 				),
 				false => Self::indent_str_nl(ctx, cur_str, true),
 			},
@@ -279,7 +311,7 @@ impl WhitespaceFormatKind {
 		self,
 		ctx: &mut crate::Context,
 		cur_str: &AstStr,
-		is_last: bool
+		is_last: bool,
 	) -> AstStrRepr {
 		match self {
 			Self::Remove => "".into(),
@@ -288,7 +320,7 @@ impl WhitespaceFormatKind {
 				true => ctx.with_indent_offset_if(
 					-1,
 					use_prev,
-					|ctx| Self::indent_str_nl(ctx, cur_str, false)
+					|ctx| Self::indent_str_nl(ctx, cur_str, false),
 				),
 				false => Self::indent_str_nl(ctx, cur_str, false),
 			},
@@ -300,19 +332,27 @@ impl WhitespaceFormatKind {
 pub fn format(
 	ws: &mut Whitespace,
 	ctx: &mut crate::Context,
-	kind: WhitespaceFormatKind
+	kind: WhitespaceFormatKind,
 ) {
 	// Note: If we're whitespace after a line doc comment, then we have a newline
 	//       prior to us that we need to take into account.
 	// TODO: We should do this even when we're preserving the whitespace
 	let after_newline = ctx.remove_tag::<tag::AfterNewline>().is_some();
 
-	let prefix_str = kind.prefix_str(
-		ctx,
-		&ws.0.first.0,
-		ws.0.rest.is_empty(),
-		after_newline
-	);
+	if !ctx
+		.is_in_ranges(WhitespaceFormat::input_range(ws)) {
+		return;
+	}
+
+	let prefix_str = match kind.is_indent() && !after_newline && Directive::is_trailing(ws) {
+		true => AstStrRepr::Spaces { len: 1 },
+		false => kind.prefix_str(
+			ctx,
+			&ws.0.first.0,
+			ws.0.rest.is_empty(),
+			after_newline,
+		),
+	};
 	ws.0.first.0.replace(prefix_str);
 
 	for (pos, (comment, ws)) in ws.0.rest.iter_mut().with_position() {
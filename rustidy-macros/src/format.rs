@@ -12,7 +12,7 @@ use {
 #[derive(Debug, darling::FromMeta)]
 // TODO: Something better than this...
 #[darling(from_expr = |expr| Ok(
-	Self { ty: parse_quote! { #expr }, generics: vec![] }
+	Self { ty: parse_quote! { #expr }, generics: vec![] },
 ))]
 struct ArgsTy {
 	ty:       syn::Type,
@@ -207,20 +207,28 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 		None => {
 			let generics = attrs.generics.clone();
 			match &attrs.data {
-				darling::ast::Data::Enum(variants) => util::with_enum_bounds(generics, variants, |variant, field| {
-					let ty = &field.ty;
-					match variant.args.is_some() {
-						true => parse_quote! { #ty: format::Formattable },
-						false => parse_quote! { #ty: format::Format<#prefix_ws_ty, ()> },
-					}
-				}),
-				darling::ast::Data::Struct(fields) => util::with_struct_bounds(generics, &fields.fields, |field| {
-					let ty = &field.ty;
-					match field.args.is_some() {
-						true => parse_quote! { #ty: format::Formattable },
-						false => parse_quote! { #ty: format::Format<#prefix_ws_ty, ()> },
-					}
-				}),
+				darling::ast::Data::Enum(variants) => util::with_enum_bounds(
+					generics,
+					variants,
+					|variant, field| {
+						let ty = &field.ty;
+						match variant.args.is_some() {
+							true => parse_quote! { #ty: format::Formattable },
+							false => parse_quote! { #ty: format::Format<#prefix_ws_ty, ()> },
+						}
+					},
+				),
+				darling::ast::Data::Struct(fields) => util::with_struct_bounds(
+					generics,
+					&fields.fields,
+					|field| {
+						let ty = &field.ty;
+						match field.args.is_some() {
+							true => parse_quote! { #ty: format::Formattable },
+							false => parse_quote! { #ty: format::Format<#prefix_ws_ty, ()> },
+						}
+					},
+				),
 			}
 		},
 	};
@@ -259,7 +267,7 @@ fn derive_enum(variants: &[VariantAttrs]) -> Result<syn::Expr, AppError> {
 
 			let prefix_ws = variant.prefix_ws.as_ref().map(
 				|prefix_ws| prefix_ws
-					.eval(Some(parse_quote! { prefix_ws }))
+					.eval(Some(parse_quote! { prefix_ws })),
 			);
 
 			let format = parse_quote! { ctx.format_with(value, prefix_ws, args) };
@@ -275,7 +283,7 @@ fn derive_enum(variants: &[VariantAttrs]) -> Result<syn::Expr, AppError> {
 				&variant.with_tag,
 				&variant.without_tag,
 				Args::Set(variant.args.clone()),
-				&variant.indent
+				&variant.indent,
 			)?;
 
 			Ok(parse_quote! {
@@ -285,36 +293,51 @@ fn derive_enum(variants: &[VariantAttrs]) -> Result<syn::Expr, AppError> {
 		.collect::<Result<Vec<syn::Arm>, AppError>>()?;
 
 	Ok(
-		parse_quote! { match *self { #( #format_variants )* } }
+		parse_quote! { match *self { #( #format_variants )* } },
 	)
 }
 
-fn derive_struct(attrs: &Attrs, fields: &darling::ast::Fields<FieldAttrs>) -> Result<syn::Expr, AppError> {
+fn derive_struct(
+	attrs: &Attrs,
+	fields: &darling::ast::Fields<FieldAttrs>,
+) -> Result<syn::Expr, AppError> {
 	let format_fields = fields
 		.iter()
 		.enumerate()
 		.map(
-			|(field_idx, field)| self::derive_struct_field(attrs, field_idx, field)
+			|(field_idx, field)| self::derive_struct_field(attrs, field_idx, field),
 		)
 		.collect::<Result<Vec<_>, _>>()?;
 
-	let assert_prefix_ws: Option<syn::Expr> = (!attrs.no_prefix_ws).then(|| parse_quote! {
+	let assert_prefix_ws: Option<syn::Expr> = match attrs.no_prefix_ws {
+		true => None,
+		false => Some(parse_quote! {
 			if !output.is_empty && !output.has_prefix_ws() {
 				tracing::warn!("Non-empty type did not use prefix whitespace: {}", std::any::type_name::<Self>())
 			}
-		});
+		}),
+	};
 
 	Ok(parse_quote! {{
 		let mut output = format::FormatOutput::default();
 		let mut has_prefix_ws = true;
-		#( #format_fields; )*
+		let column = ctx.column();
+		#(
+			ctx.set_column(output.end_column(column));
+			#format_fields;
+		)*
+		ctx.set_column(column);
 
 		#assert_prefix_ws;
 		output
 	}})
 }
 
-fn derive_struct_field(attrs: &Attrs, field_idx: usize, field: &FieldAttrs) -> Result<syn::Expr, AppError> {
+fn derive_struct_field(
+	attrs: &Attrs,
+	field_idx: usize,
+	field: &FieldAttrs,
+) -> Result<syn::Expr, AppError> {
 	let field_ident = util::field_member_access(field_idx, field);
 
 	let prefix_ws = match &field.prefix_ws {
@@ -386,7 +409,11 @@ enum Args {
 	Set(Option<syn::Expr>),
 }
 
-#[expect(clippy::ref_option, clippy::needless_pass_by_value, reason = "This signature is more ergonomic")]
+#[expect(
+	clippy::ref_option,
+	clippy::needless_pass_by_value,
+	reason = "This signature is more ergonomic"
+)]
 #[expect(clippy::too_many_arguments, reason = "TODO")]
 fn derive_format(
 	value: syn::Expr,
@@ -440,7 +467,7 @@ fn derive_format(
 		|format, WithExprIf { expr: tag, if_ }| match if_ {
 			Some(cond) => parse_quote! { ctx.without_tag_if::<#tag, _>(#cond, |ctx| #format) },
 			None => parse_quote! { ctx.without_tag::<#tag, _>(|ctx| #format) },
-		}
+		},
 	);
 
 	let format = with_tag.iter().fold(
@@ -448,7 +475,7 @@ fn derive_format(
 		|format, WithExprIf { expr: tag, if_ }| match if_ {
 			Some(cond) => parse_quote! { ctx.with_tag_if::<#tag, _>(#cond, |ctx| #format) },
 			None => parse_quote! { ctx.with_tag::<#tag, _>(|ctx| #format) },
-		}
+		},
 	);
 
 	let format = match indent {
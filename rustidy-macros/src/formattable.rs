@@ -65,7 +65,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 
 	let impl_generics = util::with_bounds(
 		&attrs,
-		|ty| parse_quote! { #ty: format::Formattable }
+		|ty| parse_quote! { #ty: format::Formattable },
 	);
 	let (impl_generics, ty_generics, impl_where_clause) = impl_generics.split_for_impl();
 	let output = quote! {
@@ -120,12 +120,14 @@ fn derive_enum(variants: &[VariantAttrs]) -> Impls<syn::Expr, syn::Expr, syn::Ex
 	Impls { with_strings, with_prefix_ws, format_output, }
 }
 
-fn derive_struct(fields: &darling::ast::Fields<FieldAttrs>) -> Impls<syn::Expr, syn::Expr, syn::Expr> {
+fn derive_struct(
+	fields: &darling::ast::Fields<FieldAttrs>,
+) -> Impls<syn::Expr, syn::Expr, syn::Expr> {
 	let Impls { with_strings, with_prefix_ws, format_output, } = fields
 		.iter()
 		.enumerate()
 		.map(
-			|(field_idx, field)| self::derive_struct_field(field_idx, field)
+			|(field_idx, field)| self::derive_struct_field(field_idx, field),
 		)
 		.collect::<Impls<Vec<_>, Vec<_>, Vec<_>>>();
 
@@ -150,7 +152,10 @@ fn derive_struct(fields: &darling::ast::Fields<FieldAttrs>) -> Impls<syn::Expr,
 	Impls { with_strings, with_prefix_ws, format_output }
 }
 
-fn derive_struct_field(field_idx: usize, field: &FieldAttrs) -> Impls<syn::Expr, syn::Expr, syn::Expr> {
+fn derive_struct_field(
+	field_idx: usize,
+	field: &FieldAttrs,
+) -> Impls<syn::Expr, syn::Expr, syn::Expr> {
 	let field_ident = util::field_member_access(field_idx, field);
 
 	let with_strings = parse_quote! {{
@@ -61,10 +61,11 @@ pub fn derive_print(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 fn try_derive(
 	input: proc_macro::TokenStream,
-	f: impl FnOnce(proc_macro::TokenStream) -> Result<proc_macro::TokenStream, AppError> + UnwindSafe,
+	f: impl FnOnce(proc_macro::TokenStream) -> Result<proc_macro::TokenStream, AppError>
+		+ UnwindSafe,
 ) -> proc_macro::TokenStream {
 	std::panic::catch_unwind(move || f(input)).map_err(
-		|payload| app_error!("Derive macro panicked: {payload:?}")
+		|payload| app_error!("Derive macro panicked: {payload:?}"),
 	).flatten().unwrap_or_else(|err| {
 		let err = err.to_string();
 		quote::quote! {
@@ -223,7 +223,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 						variant.peek.iter().enumerate().map(|(idx, ty)| {
 							let err_variant = syn::Ident::new(
 								&format!("{}Peek{idx}", variant.ident),
-								variant.ident.span()
+								variant.ident.span(),
 							);
 							Peek { variant, variant_ty, peek_ty: ty, err_variant, }
 						})
@@ -289,7 +289,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 						let on_err = match variant.not_fatal {
 							true => None,
 							false => Some(
-								quote! { .map_err(#error_ident::#variant_ident)? }
+								quote! { .map_err(#error_ident::#variant_ident)? },
 							),
 						};
 
@@ -311,7 +311,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 						|(variant, error_ident)| match variant.box_error {
 							true => quote! { #error_ident: Box::new(#error_ident), },
 							false => quote! { #error_ident, },
-						}
+						},
 					)
 					.collect::<Vec<_>>();
 
@@ -436,7 +436,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 						let tag = field.skip_if_tag.as_ref()?;
 						let error_ident = syn::Ident::new(
 							&format!("tag_exists_{field_ident}"),
-							field_ident.span()
+							field_ident.span(),
 						);
 
 						Some((tag, error_ident))
@@ -535,7 +535,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 						let is_cur_fatal = field.fatal;
 						if *is_fatal && is_cur_fatal {
 							return Some(Err(
-								app_error!("Cannot specify `#[parser(fatal)]` more than once")
+								app_error!("Cannot specify `#[parser(fatal)]` more than once"),
 							));
 						}
 						*is_fatal |= is_cur_fatal;
@@ -545,28 +545,26 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 					.collect::<Result<Vec<_>, AppError>>()?;
 
 				let error_enum_variants = itertools::izip!(&fields.fields, &error_names, &field_tys, &fatal_fields)
-					.filter_map(
-						|(field, error_name, field_ty, is_fatal)| {
-							let Some(error_name) = error_name else { return None };
+					.filter_map(|(field, error_name, field_ty, is_fatal)| {
+						let Some(error_name) = error_name else { return None };
 
-							let fatal = match is_fatal {
-								true => quote! { #[parse_error(fatal)] },
-								false => quote! {},
-							};
+						let fatal = match is_fatal {
+							true => quote! { #[parse_error(fatal)] },
+							false => quote! {},
+						};
 
-							let ty = quote! { parse::ParserError<#field_ty> };
-							let ty = match field.box_error {
-								true => quote! { Box<#ty> },
-								false => ty,
-							};
+						let ty = quote! { parse::ParserError<#field_ty> };
+						let ty = match field.box_error {
+							true => quote! { Box<#ty> },
+							false => ty,
+						};
 
-							Some(quote! {
+						Some(quote! {
 							#[parse_error(transparent)]
 							#fatal
 							#error_name(#ty),
 						})
-						}
-					)
+					})
 					.collect::<Vec<_>>();
 
 				// TODO: Figure out why using just `#error_generics` doesn't work here
@@ -86,7 +86,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 				bail!("Cannot set `#[parse_error(transparent)]` on enums or unions")
 			};
 			let field = fields.fields.iter().exactly_one().context(
-				"`#[parse_error(transparent)]` is only supported for single-field structs"
+				"`#[parse_error(transparent)]` is only supported for single-field structs",
 			)?;
 
 			let field_ident = util::field_member_access(0, field);
@@ -100,7 +100,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 
 	let mut impl_generics = attrs.generics.clone();
 	let impl_where_clause = impl_generics.make_where_clause();
-	let (is_fatal, pos, to_app_error) = match &attrs.data {
+	let (is_fatal, pos, to_app_error, to_tree) = match &attrs.data {
 		darling::ast::Data::Enum(variants) => {
 			// If we have generics, add bounds
 			if !attrs.generics.params.is_empty() {
@@ -131,7 +131,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 								.iter()
 								.map(|variant_field| {
 									variant_field.ident.as_ref().context(
-										"`#[parse_error(multiple)]` is only supported on named variants"
+										"`#[parse_error(multiple)]` is only supported on named variants",
 									)
 								})
 								.collect::<Result<Vec<_>, _>>()?;
@@ -170,7 +170,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 										.enumerate()
 										.exactly_one()
 										.context(
-											"Exactly 1 field must exist on `#[parse_error(transparent)]` variants"
+											"Exactly 1 field must exist on `#[parse_error(transparent)]` variants",
 										)?;
 									Some(field)
 								},
@@ -181,7 +181,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 									.filter(|(_, variant_field)| { variant_field.source })
 									.at_most_one()
 									.context(
-										"At most 1 field may have `#[parse_error(source)]`"
+										"At most 1 field may have `#[parse_error(source)]`",
 									)?,
 							};
 
@@ -223,7 +223,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 				#( #pos_variants )*
 			} };
 
-			let to_app_error_variants = itertools::izip!(variants)
+			let (to_app_error_variants, to_tree_variants) = itertools::izip!(variants)
 				.map(|variant| {
 					let variant_ident = &variant.ident;
 
@@ -237,32 +237,37 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 								Some(ident) => ident.clone(),
 								None => syn::Ident::new(
 									&format!("_{variant_field_idx}"),
-									Span::mixed_site()
+									Span::mixed_site(),
 								),
-							}
+							},
 						)
 						.collect::<Vec<_>>();
 
-					let output = match &*field_idents {
+					let (output, tree_output) = match &*field_idents {
 						[] => {
 							ensure!(!variant.transparent, "Empty variants may not be transparent");
 							let Fmt { parts } = variant.fmt.as_ref().context(
 								"Expected either `#[parse_error(transparent)]` or `#[parse_error(fmt = \"...\")]`",
 							)?;
 
-							quote! {
+							let output = quote! {
 								match format_args!(#( #parts, )*).as_str() {
 									Some(fmt) => app_error::AppError::msg(fmt),
 									None => app_error::AppError::fmt(format!(#( #parts, )*)),
 								}
-							}
-						},
-						[field_ident] => {
-							quote! { parse::ParseError::to_app_error(#field_ident, parser) }
+							};
+							let tree_output = quote! {
+								parse::ParseErrorTree::Message(format!(#( #parts, )*))
+							};
+
+							(output, tree_output)
 						},
-						_ => quote! { app_error::AppError::from_multiple([
-							#( parse::ParseError::to_app_error(#field_idents, parser), )*
-						]) },
+						[field_ident] => (quote! { parse::ParseError::to_app_error(#field_ident, parser) }, quote! { parse::ParseError::to_tree(#field_ident) },),
+						_ => (quote! { app_error::AppError::from_multiple([
+								#( parse::ParseError::to_app_error(#field_idents, parser), )*
+							]) }, quote! { parse::ParseErrorTree::Multiple(vec![
+								#( parse::ParseError::to_tree(#field_idents), )*
+							]) },),
 					};
 
 					let pat = match variant.fields.style {
@@ -274,9 +279,11 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 						darling::ast::Style::Struct => quote! { { #( ref #field_idents, )* } },
 					};
 
-					Ok(quote! { Self::#variant_ident #pat => #output, })
+					Ok::<_, AppError>(
+						(quote! { Self::#variant_ident #pat => #output, }, quote! { Self::#variant_ident #pat => #tree_output, },),
+					)
 				})
-				.collect::<Result<Vec<_>, AppError>>()?;
+				.try_unzip::<Vec<_>, Vec<_>>()?;
 
 			let to_app_error = quote! {
 				match *self {
@@ -284,7 +291,13 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 				}
 			};
 
-			(is_fatal, pos, to_app_error)
+			let to_tree = quote! {
+				match *self {
+					#( #to_tree_variants )*
+				}
+			};
+
+			(is_fatal, pos, to_app_error, to_tree)
 		},
 
 		darling::ast::Data::Struct(fields) => {
@@ -299,7 +312,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 				None => quote! { None },
 			};
 
-			let to_app_error = match &transparent_field_access {
+			let (to_app_error, to_tree) = match &transparent_field_access {
 				Some((field, field_access)) => {
 					// With transparent fields, we need a type bound if we're generic
 					if !attrs.generics.params.is_empty() {
@@ -309,11 +322,11 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 							.push(parse_quote! { #ty: parse::ParseError });
 					}
 
-					quote! { #field_access.to_app_error(parser) }
+					(quote! { #field_access.to_app_error(parser) }, quote! { #field_access.to_tree() },)
 				},
 				None => {
 					let Fmt { parts } = item_error_fmt.as_ref().context(
-						"Expected either `#[parse_error(transparent)]` or `#[parse_error(fmt = \"...\")]`"
+						"Expected either `#[parse_error(transparent)]` or `#[parse_error(fmt = \"...\")]`",
 					)?;
 
 					let field_idents = fields
@@ -321,23 +334,30 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 						.iter()
 						.enumerate()
 						.map(
-							|(field_idx, field)| util::field_member_access(field_idx, field)
+							|(field_idx, field)| util::field_member_access(field_idx, field),
 						)
 						.collect::<Vec<_>>();
 
 
-					quote! {
+					let to_app_error = quote! {
 						let Self { #( #field_idents, )* } = self;
 
 						match format_args!(#( #parts, )*).as_str() {
 							Some(fmt) => app_error::AppError::msg(fmt),
 							None => app_error::AppError::fmt(format!(#( #parts, )*)),
 						}
-					}
+					};
+					let to_tree = quote! {
+						let Self { #( #field_idents, )* } = self;
+
+						parse::ParseErrorTree::Message(format!(#( #parts, )*))
+					};
+
+					(to_app_error, to_tree)
 				},
 			};
 
-			(is_fatal, pos, to_app_error)
+			(is_fatal, pos, to_app_error, to_tree)
 		},
 	};
 
@@ -356,6 +376,10 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 			fn to_app_error(&self, parser: &parse::Parser) -> app_error::AppError {
 				#to_app_error
 			}
+
+			fn to_tree(&self) -> parse::ParseErrorTree {
+				#to_tree
+			}
 		}
 	};
 
@@ -100,12 +100,12 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 	let ident_base = syn::Ident::new(&format!("{item_ident}Base"), item_ident.span());
 	let ident_prefix = syn::Ident::new(
 		&format!("{item_ident}Prefix"),
-		item_ident.span()
+		item_ident.span(),
 	);
 	let ident_infix = syn::Ident::new(&format!("{item_ident}Infix"), item_ident.span());
 	let ident_suffix = syn::Ident::new(
 		&format!("{item_ident}Suffix"),
-		item_ident.span()
+		item_ident.span(),
 	);
 
 	let root_ty = &attrs.root;
@@ -158,7 +158,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 				.map(|variant| try {
 					let field = self::get_variant_as_unnamed_single(variant)
 						.context(
-							"Enum variants must be tuple variants with a single field"
+							"Enum variants must be tuple variants with a single field",
 						)?;
 
 					let ty = &field.ty;
@@ -170,7 +170,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 			for variant in &mut suffix_variants {
 				let field = self::get_variant_as_unnamed_single_mut(variant)
 					.context(
-						"Enum variants must be tuple variants with a single field"
+						"Enum variants must be tuple variants with a single field",
 					)?;
 				let ty = &field.ty;
 				field.ty = parse_quote! { <#ty as parse::ParsableRecursive<#root_ty>>::Suffix };
@@ -201,7 +201,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 				.map(|variant| try {
 					let field = self::get_variant_as_unnamed_single(variant)
 						.context(
-							"Enum variants must be tuple variants with a single field"
+							"Enum variants must be tuple variants with a single field",
 						)?;
 
 					let ty = &field.ty;
@@ -213,7 +213,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 			for variant in &mut prefix_variants {
 				let field = self::get_variant_as_unnamed_single_mut(variant)
 					.context(
-						"Enum variants must be tuple variants with a single field"
+						"Enum variants must be tuple variants with a single field",
 					)?;
 				let ty = &field.ty;
 				field.ty = parse_quote! { <#ty as parse::ParsableRecursive<#root_ty>>::Prefix };
@@ -243,7 +243,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 				.map(|variant| try {
 					let field = self::get_variant_as_unnamed_single(variant)
 						.context(
-							"Enum variants must be tuple variants with a single field"
+							"Enum variants must be tuple variants with a single field",
 						)?;
 
 					let ty = &field.ty;
@@ -255,7 +255,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 			for variant in &mut infix_variants {
 				let field = self::get_variant_as_unnamed_single_mut(variant)
 					.context(
-						"Enum variants must be tuple variants with a single field"
+						"Enum variants must be tuple variants with a single field",
 					)?;
 				let ty = &field.ty;
 				field.ty = parse_quote! { <#ty as parse::ParsableRecursive<#root_ty>>::Infix };
@@ -286,7 +286,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 				.map(|variant| try {
 					let field = self::get_variant_as_unnamed_single(variant)
 						.context(
-							"Enum variants must be tuple variants with a single field"
+							"Enum variants must be tuple variants with a single field",
 						)?;
 
 					let ty = &field.ty;
@@ -298,7 +298,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 			for variant in &mut base_variants {
 				let field = self::get_variant_as_unnamed_single_mut(variant)
 					.context(
-						"Enum variants must be tuple variants with a single field"
+						"Enum variants must be tuple variants with a single field",
 					)?;
 				let ty = &field.ty;
 				field.ty = parse_quote! { <#ty as parse::ParsableRecursive<#root_ty>>::Base };
@@ -306,7 +306,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 
 			for variant in variants {
 				if recursive_variants.iter().any(
-					|existing_variant| existing_variant.ident == variant.ident
+					|existing_variant| existing_variant.ident == variant.ident,
 				) {
 					continue;
 				}
@@ -380,7 +380,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 									|field| field
 										.ident
 										.as_ref()
-										.context("Should have an ident")
+										.context("Should have an ident"),
 								)
 								.collect::<Result<Vec<_>, _>>()?;
 
@@ -446,7 +446,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 									|field| field
 										.ident
 										.as_ref()
-										.context("Should have an ident")
+										.context("Should have an ident"),
 								)
 								.collect::<Result<Vec<_>, _>>()?;
 
@@ -524,7 +524,7 @@ pub fn derive(input: proc_macro::TokenStream) -> Result<proc_macro::TokenStream,
 									|field| field
 										.ident
 										.as_ref()
-										.context("Should have an ident")
+										.context("Should have an ident"),
 								)
 								.collect::<Result<Vec<_>, _>>()?;
 
@@ -610,7 +610,7 @@ fn emit_transparent(
 	let root_ty = &attrs.root;
 
 	let field = fields.fields.iter().exactly_one().context(
-		"`#[parse_recursive(transparent)]` expects a single field"
+		"`#[parse_recursive(transparent)]` expects a single field",
 	)?;
 	let field_ty = &field.ty;
 
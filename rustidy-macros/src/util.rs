@@ -64,7 +64,7 @@ pub fn with_struct_bounds<F>(
 /// If the item is not generic, no bounds are added
 pub fn with_bounds<A, V, VF, F>(
 	attrs: &A,
-	create_bound: impl Fn(&syn::Type) -> syn::WherePredicate
+	create_bound: impl Fn(&syn::Type) -> syn::WherePredicate,
 ) -> syn::Generics
 where
 	A: AsRef<syn::Generics> + AsRef<darling::ast::Data<V, F>>,
@@ -78,12 +78,12 @@ where
 		darling::ast::Data::Enum(variants) => self::with_enum_bounds(
 			generics,
 			variants,
-			|_, field| create_bound(field.as_ref())
+			|_, field| create_bound(field.as_ref()),
 		),
 		darling::ast::Data::Struct(fields) => self::with_struct_bounds(
 			generics,
 			&fields.fields,
-			|field| create_bound(field.as_ref())
+			|field| create_bound(field.as_ref()),
 		),
 	}
 }
@@ -94,7 +94,10 @@ pub fn field_member_access<F: AsRef<Option<syn::Ident>>>(field_idx: usize, field
 	match field.as_ref() {
 		Some(ident) => syn::Member::Named(ident.clone()),
 		None => syn::Member::Unnamed(syn::Index {
-			#[expect(clippy::cast_possible_truncation, reason = "There shouldn't be more than 2^32 fields in a struct")]
+			#[expect(
+				clippy::cast_possible_truncation,
+				reason = "There shouldn't be more than 2^32 fields in a struct"
+			)]
 			index: field_idx as u32,
 			span: Span::call_site(),
 		}),
@@ -145,7 +148,7 @@ impl darling::FromMeta for Fmt {
 					syn::Meta::NameValue(_) => todo!("Expected a literal or path"),
 				},
 				darling::ast::NestedMeta::Lit(lit) => Ok(syn::Expr::Lit(
-					syn::ExprLit { attrs: vec![], lit: lit.clone(), }
+					syn::ExprLit { attrs: vec![], lit: lit.clone(), },
 				)),
 			})
 			.collect::<Result<Vec<_>, darling::Error>>()?;
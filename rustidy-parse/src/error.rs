@@ -20,6 +20,31 @@ pub trait ParseError {
 
 	/// Converts this error type to an `AppError`
 	fn to_app_error(&self, parser: &Parser) -> AppError;
+
+	/// Converts this error type to a tree of errors
+	fn to_tree(&self) -> ParseErrorTree;
+}
+
+/// Parse error tree
+#[derive(Clone, Debug)]
+pub enum ParseErrorTree {
+	/// Error message
+	Message(String),
+
+	/// Error while parsing a type
+	Parse {
+		/// Name of the type, if any
+		name:   Option<String>,
+
+		/// Range parsed before the error
+		range:  AstRange,
+
+		/// Error
+		source: Box<Self>,
+	},
+
+	/// Errors of multiple alternatives
+	Multiple(Vec<Self>),
 }
 
 impl ParseError for ! {
@@ -34,6 +59,10 @@ impl ParseError for ! {
 	fn to_app_error(&self, _parser: &Parser) -> AppError {
 		*self
 	}
+
+	fn to_tree(&self) -> ParseErrorTree {
+		*self
+	}
 }
 
 impl<E: ParseError> ParseError for Box<E> {
@@ -48,6 +77,10 @@ impl<E: ParseError> ParseError for Box<E> {
 	fn to_app_error(&self, parser: &Parser) -> AppError {
 		(**self).to_app_error(parser)
 	}
+
+	fn to_tree(&self) -> ParseErrorTree {
+		(**self).to_tree()
+	}
 }
 
 impl ParseError for () {
@@ -62,6 +95,10 @@ impl ParseError for () {
 	fn to_app_error(&self, _parser: &Parser) -> AppError {
 		AppError::from_multiple([])
 	}
+
+	fn to_tree(&self) -> ParseErrorTree {
+		ParseErrorTree::Multiple(vec![])
+	}
 }
 
 
@@ -129,11 +166,35 @@ impl<T: Parse> ParseError for ParserError<T> {
 		let err = self.source.to_app_error(parser).flatten();
 		match self::name_of::<T>() {
 			Some(name) => err.with_context(
-				|| format!("Expected {name} at {}", parser.loc(self.range.start))
+				|| format!("Expected {name} at {}", parser.loc(self.range.start)),
 			),
 			None => err,
 		}
 	}
+
+	fn to_tree(&self) -> ParseErrorTree {
+		ParseErrorTree::Parse {
+			name: self::name_of::<T>(),
+			range: self.range,
+			source: Box::new(self.source.to_tree()),
+		}
+	}
+}
+
+/// Recovered error
+#[derive(Clone, Debug)]
+pub struct RecoveredError {
+	/// Position of the error
+	pub pos:   AstPos,
+
+	/// Range of the input skipped because of the error
+	pub range: AstRange,
+
+	/// Error
+	pub err:   AppError,
+
+	/// Error tree
+	pub tree:  ParseErrorTree,
 }
 
 /// Gets the name of a parsable type
@@ -143,7 +204,7 @@ fn name_of<T: Parse>() -> Option<String> {
 	#[cfg(feature = "parse-debug-name")]
 	let name = Some(
 		name
-			.unwrap_or_else(|| std::any::type_name::<T>().to_owned())
+			.unwrap_or_else(|| std::any::type_name::<T>().to_owned()),
 	);
 
 	name
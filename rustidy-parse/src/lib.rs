@@ -28,7 +28,7 @@ mod whitespace;
 // Exports
 pub use {
 	self::{
-		error::{ParseError, ParserError},
+		error::{ParseError, ParseErrorTree, ParserError, RecoveredError},
 		recursive::{
 			FromRecursiveRoot,
 			IntoRecursiveRoot,
@@ -47,8 +47,8 @@ use {
 	app_error::AppError,
 	arcstr::ArcStr,
 	core::{marker::PhantomData, ops::{Residual, Try}},
-	std::fmt,
-	util::{ArenaData, ArenaIdx, AstPos, AstRange, AstStr},
+	std::{fmt, sync::Arc},
+	util::{ArenaData, ArenaIdx, AstPos, AstRange, AstStr, ColumnUnit, LineIndex},
 };
 #[cfg(feature = "flamegraph-traces")]
 use {
@@ -95,6 +95,10 @@ impl ParseError for NeverError {
 	fn to_app_error(&self, _parser: &Parser) -> AppError {
 		AppError::from_multiple([])
 	}
+
+	fn to_tree(&self) -> ParseErrorTree {
+		ParseErrorTree::Multiple(vec![])
+	}
 }
 
 impl Parse for ! {
@@ -222,6 +226,14 @@ macro tuple_impl(
 				)*
 			}
 		}
+
+		fn to_tree(&self) -> ParseErrorTree {
+			match *self {
+				$(
+					Self::$T(ref err, ..) => err.to_tree(),
+				)*
+			}
+		}
 	}
 }
 
@@ -248,37 +260,59 @@ impl<T: ArenaData + Parse> Parse for ArenaIdx<T> {
 #[derive(Debug)]
 pub struct Parser {
 	/// Input
-	input:           ArcStr,
+	input:              ArcStr,
+
+	/// Line index of the input
+	line_index:         Arc<LineIndex>,
 
 	/// Current position
-	cur_pos:         AstPos,
+	cur_pos:            AstPos,
 
 	/// Tags
 	// Note: Always sorted by ast position.
-	tags:            Vec<(AstPos, ParserTag)>,
+	tags:               Vec<(AstPos, ParserTag)>,
 
 	/// Tags offset
-	tags_offset:     usize,
+	tags_offset:        usize,
+
+	/// Whether we're recovering from errors
+	recovering:         bool,
+
+	/// Whether the next statements should recover from errors
+	recover_statements: bool,
+
+	/// Errors recovered from
+	recovered_errors:   Vec<RecoveredError>,
 
 	#[cfg(feature = "flamegraph-traces")]
-	stack:           Vec<&'static str>,
+	stack:              Vec<&'static str>,
 
 	#[cfg(feature = "flamegraph-traces")]
-	trace_max_depth: usize,
+	trace_max_depth:    usize,
 
 	#[cfg(feature = "flamegraph-traces")]
-	trace_file:      BufWriter<GzEncoder<fs::File>>,
+	trace_file:         BufWriter<GzEncoder<fs::File>>,
 }
 
 impl Parser {
 	/// Creates a new parser
 	#[must_use]
 	pub fn new(input: impl Into<ArcStr>) -> Self {
+		Self::from_line_index(Arc::new(LineIndex::new(input)))
+	}
+
+	/// Creates a new parser from the line index of it's input
+	#[must_use]
+	pub fn from_line_index(line_index: Arc<LineIndex>) -> Self {
 		Self {
-			input: input.into(),
+			input: ArcStr::clone(line_index.input()),
+			line_index,
 			cur_pos: AstPos::from_usize(0),
 			tags: vec![],
 			tags_offset: 0,
+			recovering: false,
+			recover_statements: false,
+			recovered_errors: vec![],
 			#[cfg(feature = "flamegraph-traces")]
 			stack: {
 				let mut stack = Vec::with_capacity(128);
@@ -304,7 +338,7 @@ impl Parser {
 				let default = "output.gz";
 
 				self::open_flamegraph_trace_file(var, default).unwrap_or_else(
-					|err| panic!("Unable to create {var:?}: {err:?}")
+					|err| panic!("Unable to create {var:?}: {err:?}"),
 				)
 			},
 		}
@@ -316,6 +350,12 @@ impl Parser {
 		&self.input
 	}
 
+	/// Returns the line index of the input
+	#[must_use]
+	pub const fn line_index(&self) -> &Arc<LineIndex> {
+		&self.line_index
+	}
+
 	/// Returns the remaining string for the parser
 	#[must_use]
 	pub fn remaining(&self) -> &str {
@@ -370,17 +410,8 @@ impl Parser {
 	/// Gets the position (0-indexed) of the parser at a position
 	#[must_use]
 	pub fn loc(&self, pos: AstPos) -> ParserLoc {
-		let line = self
-			.input[..pos.0]
-			.chars()
-			.filter(|&ch| ch == '\n')
-			.count();
-		let column = match self.input[..pos.0].rfind('\n') {
-			Some(newline_pos) => pos.0 - newline_pos - 1,
-			None => pos.0,
-		};
-
-		ParserLoc { line, column }
+		let loc = self.line_index.line_col(pos, ColumnUnit::Bytes);
+		ParserLoc { line: loc.line, column: loc.column }
 	}
 
 	/// Gets the current position (0-indexed) of the parser
@@ -420,7 +451,10 @@ impl Parser {
 	/// # Failure
 	/// If `f` returns unsuccessfully, an error will be returned
 	/// with the latest change to the string as it's position.
-	pub fn try_update_with<F, T>(&mut self, f: F) -> <T::Residual as Residual<(AstStr, T::Output)>>::TryType
+	pub fn try_update_with<F, T>(
+		&mut self,
+		f: F,
+	) -> <T::Residual as Residual<(AstStr, T::Output)>>::TryType
 	where
 		F: FnOnce(&mut &str) -> T,
 		T: Try<Residual: Residual<(AstStr, T::Output)>>,
@@ -441,7 +475,7 @@ impl Parser {
 		let value = res?;
 
 		<_>::from_output(
-			(AstStr::from_input(self.input.substr(output_range)), value)
+			(AstStr::from_input(self.input.substr(output_range)), value),
 		)
 	}
 
@@ -460,7 +494,7 @@ impl Parser {
 
 		let start_pos = self.cur_pos;
 		let res = T::parse_from(self).map_err(
-			|source| ParserError::new(source, AstRange::new(start_pos, self.cur_pos))
+			|source| ParserError::new(source, AstRange::new(start_pos, self.cur_pos)),
 		);
 
 		#[cfg(feature = "flamegraph-traces")]
@@ -485,20 +519,27 @@ impl Parser {
 	pub fn parse_with_peeked<T: ParsePeeked<U>, U>(&mut self, parsed: U) -> Result<T, ParserError<T>> {
 		let start_pos = self.cur_pos;
 		T::parse_from_with_peeked(self, parsed).map_err(
-			|source| ParserError::new(source, AstRange::new(start_pos, self.cur_pos))
+			|source| ParserError::new(source, AstRange::new(start_pos, self.cur_pos)),
 		)
 	}
 
 	/// Tries to parses `T` from this parser using `parser` for parsing.
 	///
 	/// On error, nothing is modified.
-	pub fn try_parse_with<T, E: ParseError>(&mut self, parser: impl FnOnce(&mut Self) -> Result<T, E>,) -> Result<Result<T, E>, E> {
+	pub fn try_parse_with<T, E: ParseError>(
+		&mut self,
+		parser: impl FnOnce(&mut Self) -> Result<T, E>,
+	) -> Result<Result<T, E>, E> {
 		let prev_pos = self.cur_pos;
+		let prev_recovered_errors_len = self.recovered_errors.len();
 		match parser(self) {
 			Ok(value) => Ok(Ok(value)),
 			Err(err) if err.is_fatal() => Err(err),
 			Err(err) => {
 				self.cur_pos = prev_pos;
+				self
+					.recovered_errors
+					.truncate(prev_recovered_errors_len);
 				Ok(Err(err))
 			},
 		}
@@ -513,12 +554,19 @@ impl Parser {
 
 	/// Parses a `T` from this parser using `parser` for parsing, ignoring any errors that occur (including fatal)
 	// TODO: Give this a better name.
-	pub fn parse_not_fatal_with<T, E>(&mut self, parser: impl FnOnce(&mut Self) -> Result<T, E>,) -> Result<T, E> {
+	pub fn parse_not_fatal_with<T, E>(
+		&mut self,
+		parser: impl FnOnce(&mut Self) -> Result<T, E>,
+	) -> Result<T, E> {
 		let prev_pos = self.cur_pos;
+		let prev_recovered_errors_len = self.recovered_errors.len();
 		match parser(self) {
 			Ok(value) => Ok(value),
 			Err(err) => {
 				self.cur_pos = prev_pos;
+				self
+					.recovered_errors
+					.truncate(prev_recovered_errors_len);
 				Err(err)
 			},
 		}
@@ -533,12 +581,21 @@ impl Parser {
 	/// Peeks a `T` from this parser using `parser` for parsing.
 	///
 	/// Parser is only advanced is a fatal error occurs
-	pub fn peek_with<T, E: ParseError>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T, E>,) -> Result<Result<(T, AstPos), E>, E> {
+	pub fn peek_with<T, E: ParseError>(
+		&mut self,
+		parse: impl FnOnce(&mut Self) -> Result<T, E>,
+	) -> Result<Result<(T, AstPos), E>, E> {
 		let start_pos = self.cur_pos;
+		let prev_recovered_errors_len = self.recovered_errors.len();
 		let output = match parse(self) {
 			Ok(value) => Ok(value),
 			Err(err) if err.is_fatal() => return Err(err),
-			Err(err) => Err(err),
+			Err(err) => {
+				self
+					.recovered_errors
+					.truncate(prev_recovered_errors_len);
+				Err(err)
+			},
 		};
 
 		let end_pos = self.cur_pos;
@@ -578,7 +635,7 @@ impl Parser {
 	pub fn with_tags<O>(
 		&mut self,
 		tags: impl IntoIterator<Item = ParserTag>,
-		f: impl FnOnce(&mut Self) -> O
+		f: impl FnOnce(&mut Self) -> O,
 	) -> O {
 		let tags_len = self.tags.len();
 
@@ -595,7 +652,7 @@ impl Parser {
 	pub fn with_tag<O>(
 		&mut self,
 		tag: impl Into<ParserTag>,
-		f: impl FnOnce(&mut Self) -> O
+		f: impl FnOnce(&mut Self) -> O,
 	) -> O {
 		self.with_tags([tag.into()], f)
 	}
@@ -609,13 +666,63 @@ impl Parser {
 
 		output
 	}
+
+	/// Returns if this parser is recovering from errors.
+	///
+	/// See [`Self::set_recovering`] for more details.
+	#[must_use]
+	pub const fn is_recovering(&self) -> bool {
+		self.recovering
+	}
+
+	/// Sets whether this parser is recovering from errors.
+	///
+	/// When recovering, items and statements that fail to parse are skipped
+	/// instead of failing the whole parse, and their errors are recorded
+	/// (see [`Self::take_recovered_errors`]).
+	pub const fn set_recovering(&mut self, recovering: bool) {
+		self.recovering = recovering;
+	}
+
+	/// Calls `f` with the next statements recovering from errors
+	pub fn with_recover_statements<O>(&mut self, f: impl FnOnce(&mut Self) -> O) -> O {
+		let prev_recover_statements = self.recover_statements;
+		self.recover_statements = true;
+		let output = f(self);
+		self.recover_statements = prev_recover_statements;
+
+		output
+	}
+
+	/// Returns if the next statements should recover from errors.
+	///
+	/// Any statements parsed afterwards will no longer recover from errors.
+	pub const fn take_recover_statements(&mut self) -> bool {
+		core::mem::replace(&mut self.recover_statements, false)
+	}
+
+	/// Records an error that was recovered from by skipping `range`
+	pub fn add_recovered_error<E: ParseError>(&mut self, err: &E, range: AstRange) {
+		let err = RecoveredError {
+			pos: err.pos().unwrap_or(range.start),
+			range,
+			err: err.to_app_error(self),
+			tree: err.to_tree(),
+		};
+		self.recovered_errors.push(err);
+	}
+
+	/// Takes all errors recovered from so far
+	pub fn take_recovered_errors(&mut self) -> Vec<RecoveredError> {
+		core::mem::take(&mut self.recovered_errors)
+	}
 }
 
 /// Parser location (0-indexed).
 ///
 /// # Display
 /// The display impl transforms this into a 1-indexed format of `{line}:{column}`
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 #[derive(derive_more::Display)]
 #[display("{}:{}", line + 1, column + 1)]
 pub struct ParserLoc {
@@ -623,6 +730,26 @@ pub struct ParserLoc {
 	pub column: usize,
 }
 
+impl ParserLoc {
+	/// Gets the location of a byte position within `input`.
+	///
+	/// This goes through all of `input`, so if you need the location
+	/// of multiple positions, use a [`LineIndex`] instead.
+	#[must_use]
+	pub fn from_pos(input: &str, pos: usize) -> Self {
+		let line = input[..pos]
+			.chars()
+			.filter(|&ch| ch == '\n')
+			.count();
+		let column = match input[..pos].rfind('\n') {
+			Some(newline_pos) => pos - newline_pos - 1,
+			None => pos,
+		};
+
+		Self { line, column }
+	}
+}
+
 /// Types that may be parsed using a peek into itself
 pub trait ParsePeeked<T>: Parse {
 	fn parse_from_with_peeked(parser: &mut Parser, parsed: T) -> Result<Self, Self::Error>;
@@ -679,7 +806,10 @@ fn get_flamegraph_trace_max_depth(var: &str, default: usize) -> Result<usize, Ap
 }
 
 #[cfg(feature = "flamegraph-traces")]
-fn open_flamegraph_trace_file(var: &str, default: &str) -> Result<BufWriter<GzEncoder<fs::File>>, AppError> {
+fn open_flamegraph_trace_file(
+	var: &str,
+	default: &str,
+) -> Result<BufWriter<GzEncoder<fs::File>>, AppError> {
 	let res = env::var(var);
 	let path = match &res {
 		Ok(path) => path,
@@ -199,13 +199,18 @@ struct RecursiveWrapperInnerPart<R: ParsableRecursive<R>> {
 	suffixes: Vec<R::Suffix>,
 }
 
-fn parse<R: ParsableRecursive<R>>(parser: &mut Parser) -> Result<RecursiveWrapperInner<R>, RecursiveWrapperError<R>> {
+fn parse<R: ParsableRecursive<R>>(
+	parser: &mut Parser,
+) -> Result<RecursiveWrapperInner<R>, RecursiveWrapperError<R>> {
 	// Note: We want to ensure that any tags that are active at the beginning
 	//       stay active throughout the whole parsing, so we manually set them
 	//       on each parse.
 	// TODO: This is not a very good solution.
 	#[expect(clippy::type_complexity, reason = "TODO")]
-	fn peek<T: Parse>(parser: &mut Parser, tags: &[ParserTag],) -> Result<Result<(T, AstPos), ParserError<T>>, ParserError<T>> {
+	fn peek<T: Parse>(
+		parser: &mut Parser,
+		tags: &[ParserTag],
+	) -> Result<Result<(T, AstPos), ParserError<T>>, ParserError<T>> {
 		parser
 			.with_tags(tags.iter().copied(), Parser::peek::<T>)
 	}
@@ -259,7 +264,7 @@ fn parse<R: ParsableRecursive<R>>(parser: &mut Parser) -> Result<RecursiveWrappe
 
 					// Otherwise, we're fully empty, so return an error
 					None => return Err(
-						RecursiveWrapperError::PrefixOrBase { prefix, base }
+						RecursiveWrapperError::PrefixOrBase { prefix, base },
 					),
 				},
 			}
@@ -288,7 +293,10 @@ fn parse<R: ParsableRecursive<R>>(parser: &mut Parser) -> Result<RecursiveWrappe
 					//       `SkipOptionalTrailingBlockExpression` tag and skip themselves or something
 					//       similar that doesn't involve us doing anything.
 					if tags
-						.contains(&ParserTag::SkipOptionalTrailingBlockExpression) && peek::<ParseBracesOpen>(parser, &tags)
+						.contains(&ParserTag::SkipOptionalTrailingBlockExpression) && peek::<ParseBracesOpen>(
+						parser,
+						&tags,
+					)
 						.map_err(RecursiveWrapperError::BracesOpen)?
 						.is_ok() {
 						parser.set_pos(suffix_pos);
@@ -77,7 +77,7 @@ impl Parse for Comment {
 		};
 
 		Err(
-			CommentError::None { block: block_err, line: line_err, }
+			CommentError::None { block: block_err, line: line_err, },
 		)
 	}
 }
@@ -103,7 +103,8 @@ impl Parse for BlockComment {
 
 	fn parse_from(parser: &mut Parser) -> Result<Self, Self::Error> {
 		parser.try_update_with(|s| {
-			let is_doc_comment = (s.starts_with("/**") && !s.starts_with("/***") && !s.starts_with("/**/")) || s.starts_with("/*!");
+			let is_doc_comment = (s.starts_with("/**") && !s.starts_with("/***") && !s.starts_with("/**/"))
+				|| s.starts_with("/*!");
 
 			match s.strip_prefix("/*") {
 				Some(rest) if !is_doc_comment => {
@@ -21,7 +21,12 @@ pub const CAPACITY: usize = 4096;
 type Slot<T> = UnsafeCell<MaybeUninit<T>>;
 type Row<T> = [Slot<T>; CAPACITY];
 
-/// Arena for `T`'s Data
+/// Arena for `T`'s Data.
+///
+/// Each thread has it's own arena for each type, which is why
+/// neither the arena nor it's indices may be sent to other threads.
+/// To work with multiple asts in parallel, each one must be created
+/// and dropped within the same thread.
 #[derive(Debug)]
 pub struct Arena<T> {
 	/// Tracks all the initialized fields.
@@ -287,6 +292,8 @@ pub macro decl_arena(
 		}
 	}
 
+	// Note: Since `#[thread_local]` statics are never dropped, all rows
+	//       allocated by a thread are leaked when it exits.
 	#[thread_local]
 	static ARENA: UnsafeCell<Arena<$Ty>> = UnsafeCell::new(Arena::new());
 }
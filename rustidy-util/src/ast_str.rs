@@ -3,7 +3,7 @@
 // Imports
 use {
 	crate::{ArenaData, ArenaIdx, StrChunk},
-	arcstr::Substr,
+	arcstr::{ArcStr, Substr},
 	std::{borrow::Cow, sync::Arc},
 };
 
@@ -17,7 +17,7 @@ impl AstStr {
 	/// Creates a new ast string without any associated input range
 	pub fn new(repr: impl Into<AstStrRepr>) -> Self {
 		Self(
-			ArenaIdx::new(Inner { repr: repr.into(), input: None, })
+			ArenaIdx::new(Inner { repr: repr.into(), input: None, }),
 		)
 	}
 
@@ -75,6 +75,14 @@ impl AstStr {
 		self.repr().len()
 	}
 
+	/// Returns the display width of this string.
+	///
+	/// See [`str_width`](crate::str_width) for details.
+	#[must_use]
+	pub fn width(&self, tab_width: usize) -> usize {
+		self.repr().width(tab_width)
+	}
+
 	/// Returns if this string is empty
 	#[must_use]
 	pub fn is_empty(&self) -> bool {
@@ -123,13 +131,19 @@ impl AstStr {
 	}
 }
 
-// TODO: Make serialization not lossy?
+// Note: By default, only the string itself is serialized. Within a
+//       [`Lossless`](crate::Lossless), the input range and representation
+//       are kept as well.
 impl serde::Serialize for AstStr {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
-		S: serde::Serializer
+		S: serde::Serializer,
 	{
-		self.str().serialize(serializer)
+		match crate::lossless::input() {
+			Some(input) => LosslessInner::new(self, &input)
+				.serialize(serializer),
+			None => self.str().serialize(serializer),
+		}
 	}
 }
 
@@ -138,11 +152,133 @@ impl<'de> serde::Deserialize<'de> for AstStr {
 	where
 		D: serde::Deserializer<'de>,
 	{
-		let s = String::deserialize(deserializer)?;
-		Ok(Self::new(AstStrRepr::String(s.into())))
+		match crate::lossless::input() {
+			Some(input) => LosslessInner::deserialize(deserializer)?
+				.into_ast_str(&input)
+				.map_err(serde::de::Error::custom),
+			None => {
+				let s = String::deserialize(deserializer)?;
+				Ok(Self::new(AstStrRepr::String(s.into())))
+			},
+		}
 	}
 }
 
+/// Lossless ast string
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "AstStr")]
+struct LosslessInner<'a> {
+	repr:  LosslessRepr<'a>,
+	input: Option<LosslessSubstr<'a>>,
+}
+
+impl<'a> LosslessInner<'a> {
+	fn new(s: &'a AstStr, input: &ArcStr) -> Self {
+		// Note: Static strings can't be deserialized, so we store them as any other string.
+		let repr = match *s.repr() {
+			AstStrRepr::String(ref s) => LosslessRepr::String(LosslessSubstr::new(s, input)),
+			AstStrRepr::Static(s) => LosslessRepr::String(LosslessSubstr::Str(s.into())),
+			AstStrRepr::Char(ch) => LosslessRepr::Char(ch),
+			AstStrRepr::Spaces { len } => LosslessRepr::Spaces { len },
+			AstStrRepr::Indentation { ref indent, newlines, depth } => LosslessRepr::Indentation {
+				indent: Arc::clone(indent),
+				newlines,
+				depth,
+			},
+			AstStrRepr::Join { ref lhs, ref rhs } => LosslessRepr::Join {
+				lhs: Cow::Borrowed(lhs),
+				rhs: Cow::Borrowed(rhs),
+			},
+		};
+		let input = s.input().map(|s| LosslessSubstr::new(s, input));
+
+		Self { repr, input }
+	}
+
+	fn into_ast_str(self, input: &ArcStr) -> Result<AstStr, String> {
+		let repr = match self.repr {
+			LosslessRepr::String(s) => AstStrRepr::String(s.into_substr(input)?),
+			LosslessRepr::Char(ch) => AstStrRepr::Char(ch),
+			LosslessRepr::Spaces { len } => AstStrRepr::Spaces { len },
+			LosslessRepr::Indentation { indent, newlines, depth } => AstStrRepr::Indentation {
+				indent,
+				newlines,
+				depth
+			},
+			LosslessRepr::Join { lhs, rhs } => AstStrRepr::Join {
+				lhs: lhs.into_owned(),
+				rhs: rhs.into_owned(),
+			},
+		};
+		let input = self
+			.input
+			.map(|s| s.into_substr(input))
+			.transpose()?;
+
+		Ok(AstStr(ArenaIdx::new(Inner { repr, input })))
+	}
+}
+
+/// Lossless ast string representation
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "AstStrRepr")]
+enum LosslessRepr<'a> {
+	String(LosslessSubstr<'a>),
+	Char(char),
+	Spaces {
+		len: u16,
+	},
+	Indentation {
+		indent:   Arc<str>,
+		newlines: usize,
+		depth:    usize,
+	},
+	Join {
+		lhs: Cow<'a, AstStr>,
+		rhs: Cow<'a, AstStr>,
+	},
+}
+
+/// Lossless substring
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum LosslessSubstr<'a> {
+	/// Range of the input
+	Input {
+		start: usize,
+		end:   usize,
+	},
+
+	/// Any other string
+	Str(Cow<'a, str>),
+}
+
+impl<'a> LosslessSubstr<'a> {
+	fn new(s: &'a Substr, input: &ArcStr) -> Self {
+		match ArcStr::ptr_eq(s.parent(), input) {
+			true => {
+				let range = s.range();
+				Self::Input { start: range.start, end: range.end }
+			},
+			false => Self::Str(Cow::Borrowed(s)),
+		}
+	}
+
+	fn into_substr(self, input: &ArcStr) -> Result<Substr, String> {
+		match self {
+			Self::Input { start, end } => {
+				if input.get(start..end).is_none() {
+					return Err(
+						format!("Input range {start}..{end} is not valid for an input of length {}", input.len()),
+					);
+				}
+
+				Ok(input.substr(start..end))
+			},
+			Self::Str(s) => Ok(s.into_owned().into()),
+		}
+	}
+}
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(ArenaData)]
@@ -204,6 +340,21 @@ impl AstStrRepr {
 		}
 	}
 
+	/// Returns the display width of this representation
+	#[must_use]
+	pub fn width(&self, tab_width: usize) -> usize {
+		match *self {
+			Self::String(ref s) => crate::str_width(s, tab_width),
+			Self::Static(s) => crate::str_width(s, tab_width),
+			Self::Char(ch) => crate::char_width(ch, tab_width),
+			Self::Spaces { len } => usize::from(len),
+			Self::Indentation { ref indent, newlines, depth, } => {
+				newlines + depth * crate::str_width(indent, tab_width)
+			},
+			Self::Join { ref lhs, ref rhs } => lhs.width(tab_width) + rhs.width(tab_width),
+		}
+	}
+
 	/// Returns if this representation is empty
 	#[must_use]
 	pub fn is_empty(&self) -> bool {
@@ -278,11 +429,15 @@ impl AstStrRepr {
 				other.next().is_none()
 			},
 
-			Self::Spaces { len } => other.len() == usize::from(len) && other.chars().all(|ch| ch == ' '),
+			Self::Spaces { len } => {
+				other.len() == usize::from(len) && other.chars().all(|ch| ch == ' ')
+			},
 
-			Self::Indentation { ref indent, newlines, depth } => other.len() == newlines + depth && other[..newlines].chars().all(|ch| ch == '\n') && other[newlines..]
-				.chunk(indent.len())
-				.all(|other_indent| other_indent == other),
+			Self::Indentation { ref indent, newlines, depth } => other.len() == newlines + depth
+				&& other[..newlines].chars().all(|ch| ch == '\n')
+				&& other[newlines..]
+					.chunk(indent.len())
+					.all(|other_indent| other_indent == other),
 
 			Self::Join { ref lhs, ref rhs } => {
 				let Some((lhs_other, rhs_other)) = other.split_at_checked(lhs.len()) else {
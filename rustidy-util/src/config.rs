@@ -4,30 +4,177 @@
 use std::sync::Arc;
 
 decl_config! {
-	Config;
+	Config, PartialConfig, CONFIG_FIELDS;
 
+	/// Indentation string
 	indent                     : Arc<str>      = Arc::from("\t"),
+
+	/// Whether to skip formatting.
+	///
+	/// Usually set with `#[rustidy::skip]`.
 	skip                       : bool          = false,
+
+	/// Minimum number of empty lines between items and statements
 	min_empty_lines            : usize         = 0,
+
+	/// Maximum number of empty lines between items and statements
 	max_empty_lines            : usize         = 2,
+
+	/// Maximum width of a line.
+	///
+	/// Constructs that can be split into multiple lines are split if they'd exceed it.
+	max_width                  : usize         = 100,
+
+	/// Width of a tab character, in columns.
+	///
+	/// Used when measuring the length of lines that contain tabs, such as tab indentation.
+	///
+	/// Defaults to `1`, as tabs were always counted as a single column, so that the
+	/// existing formatting doesn't change.
+	tab_width                  : usize         = 1,
+
+	/// Maximum length of a `use` tree before it's split into multiple lines
 	max_use_tree_len           : usize         = 75,
+
+	/// Number of columns to split array expressions into, if they're multi-line
 	array_expr_cols            : Option<usize> = None,
+
+	/// Maximum length of an array expression before it's split into multiple lines
 	max_array_expr_len         : usize         = 80,
+
+	/// Maximum length of a method call or field access chain before it's
+	/// split into multiple lines
 	max_chain_len              : usize         = 50,
+
+	/// Maximum length of a tuple struct before it's split into multiple lines
 	max_inline_tuple_struct_len: usize         = 80,
+
+	/// Maximum length of a function's parameters before they're split into
+	/// multiple lines
+	max_fn_params_len          : usize         = 60,
+
+	/// Maximum length of a function or method call's arguments before they're
+	/// split into multiple lines
+	max_call_args_len          : usize         = 50,
+
+	/// Maximum length of a binary operator expression chain or a `let` chain
+	/// before it's split into multiple lines
+	max_binary_expr_len        : usize         = 80,
+
+	/// Maximum length of a generic parameter list before it's split into
+	/// multiple lines
+	max_generic_params_len     : usize         = 60,
+
+	/// Maximum length of a `+`-separated list of bounds before it's split into
+	/// multiple lines
+	max_bounds_len             : usize         = 60,
+
+	/// Style of `where` clauses.
+	///
+	/// `"vertical"` always places each bound on it's own line, while `"auto"` only
+	/// does so if the clause is longer than `max_where_clause_len` or doesn't fit in a line.
+	where_style                : WhereStyle    = WhereStyle::Vertical,
+
+	/// Maximum length of a `where` clause before it's split into multiple lines,
+	/// when `where_style` is `"auto"`
+	max_where_clause_len       : usize         = 40,
+}
+
+/// Configuration field
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigField {
+	pub name: &'static str,
+
+	/// Documentation lines
+	pub docs: &'static [&'static str],
+	pub ty:   ConfigType,
+}
+
+/// Configuration value type
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ConfigType {
+	String,
+	Bool,
+	Integer,
+
+	/// One of a set of strings
+	Enum(&'static [&'static str]),
+}
+
+/// Configuration value
+pub trait ConfigValue {
+	const TYPE: ConfigType;
+}
+
+impl ConfigValue for Arc<str> {
+	const TYPE: ConfigType = ConfigType::String;
+}
+
+impl ConfigValue for bool {
+	const TYPE: ConfigType = ConfigType::Bool;
+}
+
+impl ConfigValue for usize {
+	const TYPE: ConfigType = ConfigType::Integer;
+}
+
+impl<T: ConfigValue> ConfigValue for Option<T> {
+	const TYPE: ConfigType = T::TYPE;
+}
+
+/// `where` clause style
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(strum::EnumString, strum::VariantNames)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WhereStyle {
+	/// Each bound is always on a separate line
+	Vertical,
+
+	/// Each bound is on a separate line only if the clause is too long
+	Auto,
+}
+
+impl ConfigValue for WhereStyle {
+	const TYPE: ConfigType = ConfigType::Enum(<Self as strum::VariantNames>::VARIANTS);
+}
+
+/// Configuration that can be applied on top of another
+// Note: This is a trait because inherent methods declared
+//       within `decl_config` wouldn't be visible outside of it.
+pub trait ApplyConfig {
+	/// Applies this configuration on top of `config`
+	fn apply(&self, config: &mut Config);
 }
 
 macro decl_config(
-	$Config:ident; $($field:ident : $T:ty = $default:expr),* $(,)?
+	$Config:ident, $PartialConfig:ident, $FIELDS:ident;
+	$(
+		$( #[doc = $doc:literal] )*
+		$field:ident : $T:ty = $default:expr
+	),* $(,)?
 ) {
 	/// Formatter configuration
 	#[derive(Clone, Debug)]
 	pub struct $Config {
 		$(
+			$( #[doc = $doc] )*
 			pub $field: $T,
 		)*
 	}
 
+	/// All configuration fields
+	pub const $FIELDS: &[ConfigField] = &[
+		$(
+			ConfigField {
+				name: stringify!($field),
+				docs: &[ $( $doc ),* ],
+				ty: <$T as ConfigValue>::TYPE,
+			},
+		)*
+	];
+
 	impl Default for $Config {
 		fn default() -> Self {
 			Self {
@@ -65,21 +212,46 @@ macro decl_config(
 		where
 			D: serde::Deserializer<'de>
 		{
-			#[derive(serde::Deserialize)]
-			struct ${ concat($Config, Repr) } {
-				$(
-					$field: Option<$T>,
-				)*
-			}
+			let partial = $PartialConfig::deserialize(deserializer)?;
+
+			let mut config = Self::default();
+			partial.apply(&mut config);
+
+			Ok(config)
+		}
+	}
 
-			let repr = ${ concat($Config, Repr) }::deserialize(deserializer)?;
+	/// Partial formatter configuration.
+	///
+	/// Unset fields leave the configuration they're applied to unchanged.
+	#[derive(Clone, Default, Debug)]
+	#[derive(serde::Serialize, serde::Deserialize)]
+	#[serde(deny_unknown_fields)]
+	pub struct $PartialConfig {
+		$(
+			$( #[doc = $doc] )*
+			#[serde(skip_serializing_if = "Option::is_none")]
+			pub $field: Option<$T>,
+		)*
+	}
 
-			Ok(Self {
+	impl From<&$Config> for $PartialConfig {
+		fn from(config: &$Config) -> Self {
+			Self {
 				$(
-					$field: repr.$field.unwrap_or_else(|| $default),
+					$field: Some(config.$field.clone()),
 				)*
-			})
+			}
+		}
+	}
 
+	impl ApplyConfig for $PartialConfig {
+		fn apply(&self, config: &mut $Config) {
+			$(
+				if let Some(value) = &self.$field {
+					config.$field = value.clone();
+				}
+			)*
 		}
 	}
 }
@@ -18,6 +18,8 @@ pub mod ast_pos;
 pub mod ast_range;
 pub mod ast_str;
 pub mod config;
+pub mod line_index;
+pub mod lossless;
 pub mod oob;
 pub mod whitespace;
 
@@ -27,13 +29,23 @@ pub use self::{
 	ast_pos::AstPos,
 	ast_range::AstRange,
 	ast_str::AstStr,
-	config::Config,
+	config::{
+		ApplyConfig,
+		CONFIG_FIELDS,
+		Config,
+		ConfigField,
+		ConfigType,
+		PartialConfig,
+		WhereStyle,
+	},
+	line_index::{ColumnUnit, LineCol, LineIndex},
+	lossless::Lossless,
 	oob::Oob,
 	whitespace::Whitespace,
 };
 
 // Imports
-use core::iter;
+use {core::iter, unicode_width::UnicodeWidthChar};
 
 /// Returns if a string is blank
 #[must_use]
@@ -47,6 +59,76 @@ pub fn str_count_newlines(s: &str) -> usize {
 	s.chars().filter(|&ch| ch == '\n').count()
 }
 
+/// Returns the display width of a string.
+///
+/// Tabs are `tab_width` columns wide, and newlines are counted as a single column.
+#[must_use]
+pub fn str_width(s: &str, tab_width: usize) -> usize {
+	s
+		.chars()
+		.map(|ch| self::char_width(ch, tab_width))
+		.sum()
+}
+
+/// Returns the display width of a character.
+///
+/// See [`str_width`] for details.
+#[must_use]
+pub fn char_width(ch: char, tab_width: usize) -> usize {
+	match ch {
+		'\t' => tab_width,
+		'\n' => 1,
+		_ => ch.width().unwrap_or(0),
+	}
+}
+
+/// Returns the candidate closest to `s`.
+///
+/// Only candidates close enough to be a likely typo of `s` are considered.
+#[must_use]
+pub fn closest_str<'a>(s: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+	let max_distance = s.len().max(3) / 3;
+	candidates
+		.into_iter()
+		.map(|candidate| {
+			let distance = self::edit_distance(s, candidate);
+			(candidate, distance)
+		})
+		.filter(|&(_, distance)| distance <= max_distance)
+		.min_by_key(|&(_, distance)| distance)
+		.map(|(candidate, _)| candidate)
+}
+
+/// Returns a `, did you mean ...?` suggestion for a misspelled name, if any.
+///
+/// See [`closest_str`] for details.
+#[must_use]
+pub fn did_you_mean<'a>(s: &str, candidates: impl IntoIterator<Item = &'a str>) -> String {
+	match self::closest_str(s, candidates) {
+		Some(closest) => format!(", did you mean `{closest}`?"),
+		None => String::new(),
+	}
+}
+
+/// Returns the levenshtein distance between two strings
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+	let rhs = rhs.chars().collect::<Vec<_>>();
+	let mut prev_row = (0..=rhs.len()).collect::<Vec<_>>();
+	for (lhs_idx, lhs_ch) in lhs.chars().enumerate() {
+		let mut row = vec![lhs_idx + 1];
+		for (rhs_idx, &rhs_ch) in rhs.iter().enumerate() {
+			let cost = usize::from(lhs_ch != rhs_ch);
+			let distance = (prev_row[rhs_idx] + cost)
+				.min(prev_row[rhs_idx + 1] + 1)
+				.min(row[rhs_idx] + 1);
+			row.push(distance);
+		}
+		prev_row = row;
+	}
+
+	prev_row[rhs.len()]
+}
+
 #[extend::ext(name = StrPopFirst)]
 pub impl &str {
 	fn pop_first(&mut self) -> Option<char> {
@@ -0,0 +1,167 @@
+//! Line index
+
+// Imports
+use {crate::AstPos, arcstr::ArcStr};
+
+/// Line index.
+///
+/// Stores the start of every line of an input, so that positions
+/// can be converted to lines and columns (and back) without having to
+/// go through the whole input each time.
+#[derive(Clone, Debug)]
+pub struct LineIndex {
+	input:       ArcStr,
+	line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+	/// Creates a line index of `input`
+	#[must_use]
+	pub fn new(input: impl Into<ArcStr>) -> Self {
+		let input = input.into();
+		let line_starts = core::iter::once(0).chain(
+			input
+				.match_indices('\n')
+				.map(|(idx, _)| idx + 1),
+		).collect();
+
+		Self { input, line_starts }
+	}
+
+	/// Returns the input
+	#[must_use]
+	pub const fn input(&self) -> &ArcStr {
+		&self.input
+	}
+
+	/// Returns the number of lines.
+	///
+	/// An input ending in a newline has an empty last line.
+	#[must_use]
+	pub const fn line_count(&self) -> usize {
+		self.line_starts.len()
+	}
+
+	/// Returns the start of a line (0-indexed)
+	#[must_use]
+	pub fn line_start(&self, line: usize) -> Option<AstPos> {
+		self.line_starts.get(line).copied().map(AstPos)
+	}
+
+	/// Returns the end of a line (0-indexed), not including the newline
+	#[must_use]
+	pub fn line_end(&self, line: usize) -> Option<AstPos> {
+		let start = *self.line_starts.get(line)?;
+		let end = match self.line_starts.get(line + 1) {
+			Some(&next_start) => next_start - 1,
+			None => self.input.len(),
+		};
+		let end = match self.input[start..end].ends_with('\r') {
+			true => end - 1,
+			false => end,
+		};
+
+		Some(AstPos(end))
+	}
+
+	/// Returns a line (0-indexed), not including the newline
+	#[must_use]
+	pub fn line(&self, line: usize) -> Option<&str> {
+		let start = self.line_start(line)?;
+		let end = self.line_end(line)?;
+		Some(&self.input[start.0..end.0])
+	}
+
+	/// Returns the line (0-indexed) of a position.
+	///
+	/// Positions past the end of the input are on the last line.
+	#[must_use]
+	pub fn line_of(&self, pos: AstPos) -> usize {
+		self
+			.line_starts
+			.partition_point(|&start| start <= pos.0) - 1
+	}
+
+	/// Converts a position into a line and column.
+	///
+	/// Positions past the end of the input are clamped to it, and positions
+	/// in the middle of a character only count the characters before them.
+	#[must_use]
+	pub fn line_col(&self, pos: AstPos, unit: ColumnUnit) -> LineCol {
+		let pos = pos.0.min(self.input.len());
+		let line = self.line_of(AstPos(pos));
+		let line_start = self.line_starts[line];
+		let column = match unit {
+			ColumnUnit::Bytes => pos - line_start,
+			ColumnUnit::Chars | ColumnUnit::Utf16 => {
+				let pos = self.input.floor_char_boundary(pos);
+				unit.len(&self.input[line_start..pos])
+			},
+		};
+
+		LineCol { line, column }
+	}
+
+	/// Converts a line and column into a position.
+	///
+	/// Returns `None` if the line doesn't exist. Columns past the end of the line
+	/// are clamped to it, and columns in the middle of a character are rounded
+	/// down to the start of that character.
+	#[must_use]
+	pub fn pos(&self, line_col: LineCol, unit: ColumnUnit) -> Option<AstPos> {
+		let line_start = self.line_start(line_col.line)?;
+		let line = self.line(line_col.line)?;
+		let offset = match unit {
+			ColumnUnit::Bytes => line.floor_char_boundary(line_col.column),
+			ColumnUnit::Chars | ColumnUnit::Utf16 => {
+				let mut column = 0;
+				line.char_indices().find(|&(_, ch)| {
+					column += unit.char_len(ch);
+					column > line_col.column
+				}).map_or(line.len(), |(idx, _)| idx)
+			},
+		};
+
+		Some(AstPos(line_start.0 + offset))
+	}
+}
+
+/// Column unit
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ColumnUnit {
+	/// Bytes
+	Bytes,
+
+	/// Characters
+	Chars,
+
+	/// UTF-16 code units
+	Utf16,
+}
+
+impl ColumnUnit {
+	/// Returns the length of a string in this unit
+	fn len(self, s: &str) -> usize {
+		match self {
+			Self::Bytes => s.len(),
+			Self::Chars => s.chars().count(),
+			Self::Utf16 => s.chars().map(char::len_utf16).sum(),
+		}
+	}
+
+	/// Returns the length of a character in this unit
+	const fn char_len(self, ch: char) -> usize {
+		match self {
+			Self::Bytes => ch.len_utf8(),
+			Self::Chars => 1,
+			Self::Utf16 => ch.len_utf16(),
+		}
+	}
+}
+
+/// Line and column (0-indexed)
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Debug)]
+pub struct LineCol {
+	pub line:   usize,
+	pub column: usize,
+}
@@ -0,0 +1,158 @@
+//! Lossless serialization
+
+// Imports
+use {
+	arcstr::ArcStr,
+	core::{cell::RefCell, fmt, marker::PhantomData},
+	serde::{de, ser::SerializeStruct},
+};
+
+/// Ast serialized losslessly, alongside it's input.
+///
+/// By default, ast strings are serialized as just their contents, which loses their
+/// input range and representation. While (de)serializing this, they keep both instead,
+/// so that the deserialized ast prints and formats exactly like the original.
+///
+/// Input ranges are stored as offsets into `input`, so the input is only serialized once.
+/// When deserializing, the `input` field must come before the `ast` field.
+#[derive(Clone, Debug)]
+pub struct Lossless<T> {
+	pub input: ArcStr,
+	pub ast:   T,
+}
+
+impl<T> Lossless<T> {
+	/// Creates a lossless ast
+	pub fn new(input: impl Into<ArcStr>, ast: T) -> Self {
+		Self { input: input.into(), ast }
+	}
+}
+
+impl<T: serde::Serialize> serde::Serialize for Lossless<T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let _guard = InputGuard::new(ArcStr::clone(&self.input));
+
+		let mut serializer = serializer.serialize_struct("Lossless", 2)?;
+		serializer
+			.serialize_field("input", self.input.as_str())?;
+		serializer.serialize_field("ast", &self.ast)?;
+		serializer.end()
+	}
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Lossless<T> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		deserializer.deserialize_struct(
+			"Lossless",
+			&["input", "ast"],
+			Visitor(PhantomData),
+		)
+	}
+}
+
+/// Field of [`Lossless`]
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum Field {
+	Input,
+	Ast,
+}
+
+/// Visitor for [`Lossless`]
+struct Visitor<T>(PhantomData<T>);
+
+impl<'de, T: serde::Deserialize<'de>> de::Visitor<'de> for Visitor<T> {
+	type Value = Lossless<T>;
+
+	fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("a lossless ast")
+	}
+
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	where
+		A: de::SeqAccess<'de>,
+	{
+		let input = seq
+			.next_element::<String>()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let input = ArcStr::from(input);
+
+		let _guard = InputGuard::new(ArcStr::clone(&input));
+		let ast = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+		Ok(Lossless { input, ast })
+	}
+
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where
+		A: de::MapAccess<'de>,
+	{
+		let mut input = None::<ArcStr>;
+		let mut ast = None;
+		while let Some(field) = map.next_key()? {
+			match field {
+				Field::Input => {
+					if input.is_some() {
+						return Err(de::Error::duplicate_field("input"));
+					}
+					input = Some(map.next_value::<String>()?.into());
+				},
+				Field::Ast => {
+					if ast.is_some() {
+						return Err(de::Error::duplicate_field("ast"));
+					}
+					let Some(input) = &input else {
+						return Err(
+							de::Error::custom("field `input` must come before `ast`"),
+						);
+					};
+
+					let _guard = InputGuard::new(ArcStr::clone(input));
+					ast = Some(map.next_value()?);
+				},
+			}
+		}
+
+		let input = input
+			.ok_or_else(|| de::Error::missing_field("input"))?;
+		let ast = ast
+			.ok_or_else(|| de::Error::missing_field("ast"))?;
+
+		Ok(Lossless { input, ast })
+	}
+}
+
+/// Returns the input of the ast currently being (de)serialized losslessly, if any
+pub(crate) fn input() -> Option<ArcStr> {
+	INPUT.borrow().clone()
+}
+
+/// Input of the ast currently being (de)serialized losslessly
+#[thread_local]
+static INPUT: RefCell<Option<ArcStr>> = RefCell::new(None);
+
+/// Guard that sets the lossless input until dropped
+struct InputGuard {
+	prev: Option<ArcStr>,
+}
+
+impl InputGuard {
+	fn new(input: ArcStr) -> Self {
+		let prev = INPUT.replace(Some(input));
+		Self { prev }
+	}
+}
+
+impl Drop for InputGuard {
+	fn drop(&mut self) {
+		INPUT.replace(self.prev.take());
+	}
+}
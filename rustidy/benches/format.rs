@@ -42,7 +42,7 @@ fn format_use_decl_group(bencher: &mut Bencher) {
 		bencher,
 		"use {a, b, c};",
 		Whitespace::PRESERVE,
-		()
+		(),
 	);
 }
 
@@ -54,12 +54,12 @@ fn format_with<T, PrefixWs, Args>(
 	bencher: &mut Bencher,
 	input: &str,
 	prefix_ws: PrefixWs,
-	args: Args
+	args: Args,
 )
 where
 	T: Parse + Format<PrefixWs, Args>,
 	PrefixWs: Copy,
-	Args: Copy
+	Args: Copy,
 {
 	let mut parser = Parser::new(input);
 	let mut value: T = parser
@@ -29,7 +29,7 @@ fn parse_crate_empty(bencher: &mut Bencher) {
 fn parse_crate_hello_world(bencher: &mut Bencher) {
 	self::parse::<Crate>(
 		bencher,
-		r#"fn main() { println!("Hello, world!"); }"#
+		r#"fn main() { println!("Hello, world!"); }"#,
 	);
 }
 
@@ -24,7 +24,7 @@ fn print_crate_empty(bencher: &mut Bencher) {
 fn print_crate_hello_world(bencher: &mut Bencher) {
 	self::print::<Crate>(
 		bencher,
-		r#"fn main() { println!("Hello, World!"); }"#
+		r#"fn main() { println!("Hello, World!"); }"#,
 	);
 }
 
@@ -40,7 +40,7 @@ fn print_expr_path(bencher: &mut Bencher) {
 	self::print::<Expression>(bencher, "a");
 }
 
-fn print<T>(bencher: &mut Bencher, input: &str,)
+fn print<T>(bencher: &mut Bencher, input: &str)
 where
 	T: Parse + Print,
 {
@@ -1,7 +1,7 @@
 //! Arguments
 
 // Imports
-use std::{ffi::OsString, path::PathBuf};
+use {core::num::NonZeroUsize, std::{ffi::OsString, path::PathBuf}};
 
 /// Rustidy cargo subcommand
 #[derive(Debug)]
@@ -42,6 +42,35 @@ pub struct Rustidy {
 	#[arg(long = "check")]
 	pub check:         bool,
 
+	/// Run rustidy with `--emit`
+	#[arg(long = "emit", value_parser = ["diff"], requires = "check")]
+	pub emit:          Option<String>,
+
+	/// Run rustidy with `--emit-report`
+	#[arg(
+		long = "emit-report",
+		value_parser = ["json", "checkstyle", "sarif"],
+		requires = "check",
+		conflicts_with = "emit"
+	)]
+	pub emit_report:   Option<String>,
+
+	/// Run rustidy with `--config`
+	#[arg(long = "config")]
+	pub config:        Vec<String>,
+
+	/// Run rustidy with `--deny-warnings`
+	#[arg(long = "deny-warnings")]
+	pub deny_warnings: bool,
+
+	/// Run rustidy with `--recover`
+	#[arg(long = "recover")]
+	pub recover:       bool,
+
+	/// Run rustidy with `--jobs`
+	#[arg(short = 'j', long = "jobs")]
+	pub jobs:          Option<NonZeroUsize>,
+
 	/// Arguments to pass to `rustidy`
 	pub extra_args:    Vec<OsString>,
 }
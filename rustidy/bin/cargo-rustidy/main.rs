@@ -17,8 +17,9 @@ use {
 	std::{
 		collections::HashSet,
 		env,
-		ffi::{OsStr, OsString},
+		ffi::OsStr,
 		io,
+		mem,
 		path::{Path, PathBuf},
 		process::{Command, ExitCode},
 	},
@@ -41,14 +42,16 @@ fn run() -> Result<(), AppError> {
 
 	// Parse arguments
 	let args = Args::parse();
-	let args::Command::Rustidy(args) = args.command;
+	let args::Command::Rustidy(mut args) = args.command;
 	tracing::debug!(?args, "Arguments");
 
 	// Set logger file from arguments
 	logger.set_file(args.log_file.as_deref());
 
-	let packages = args.packages.into_iter().collect::<HashSet<_>>();
-	let manifest_path = match args.manifest_path {
+	let packages = mem::take(&mut args.packages)
+		.into_iter()
+		.collect::<HashSet<_>>();
+	let manifest_path = match &args.manifest_path {
 		Some(manifest_path) => {
 			let manifest_path = manifest_path
 				.canonicalize()
@@ -62,19 +65,13 @@ fn run() -> Result<(), AppError> {
 		None => None,
 	};
 
-	self::format(
-		packages,
-		manifest_path.as_deref(),
-		&args.extra_args,
-		args.check
-	)
+	self::format(packages, manifest_path.as_deref(), &args)
 }
 
 fn format(
 	packages: HashSet<String>,
 	manifest_path: Option<&Path>,
-	extra_args: &[OsString],
-	check: bool
+	args: &args::Rustidy,
 ) -> Result<(), AppError> {
 	// If we got no targets, error out
 	let targets = self::get_targets(packages, manifest_path)?;
@@ -88,10 +85,28 @@ fn format(
 
 	let mut command = Command::new(rustidy);
 	command.args(targets);
-	command.args(extra_args);
-	if check {
+	command.args(&args.extra_args);
+	if args.check {
 		command.arg("--check");
 	}
+	if let Some(emit) = &args.emit {
+		command.args(["--emit", emit]);
+	}
+	if let Some(emit_report) = &args.emit_report {
+		command.args(["--emit-report", emit_report]);
+	}
+	for config in &args.config {
+		command.args(["--config", config]);
+	}
+	if args.deny_warnings {
+		command.arg("--deny-warnings");
+	}
+	if args.recover {
+		command.arg("--recover");
+	}
+	if let Some(jobs) = args.jobs {
+		command.args(["--jobs", &jobs.to_string()]);
+	}
 
 	command
 		.status()
@@ -109,7 +124,7 @@ fn format(
 /// Based on the specified `CargoFmtStrategy`, returns a set of main source files.
 fn get_targets(
 	mut packages: HashSet<String>,
-	manifest_path: Option<&Path>
+	manifest_path: Option<&Path>,
 ) -> Result<Vec<PathBuf>, AppError> {
 	let mut targets = vec![];
 
@@ -1,29 +1,249 @@
 //! Arguments
 
 // Imports
-use std::path::PathBuf;
+use {
+	super::report::ReportFormat,
+	core::{num::NonZeroUsize, ops::RangeInclusive},
+	std::path::{Path, PathBuf},
+	util::{CONFIG_FIELDS, PartialConfig},
+};
 
 /// Rustidy formatter
 #[derive(Debug)]
 #[derive(clap::Parser)]
 #[clap(author, version, about)]
+#[expect(clippy::struct_excessive_bools, reason = "They're all independent flags")]
 pub struct Args {
 	/// Logs output to a file.
 	///
 	/// You can use `RUST_FILE_LOG` to set filtering options
 	#[clap(long = "log-file")]
-	pub log_file:    Option<PathBuf>,
+	pub log_file:              Option<PathBuf>,
 
-	/// Configuration file.
+	/// Configuration file to use for all files.
 	///
-	/// By default, `.rustidy.toml` is used.
+	/// By default, the nearest `.rustidy.toml` or `rustidy.toml` to
+	/// each file is used.
 	#[clap(long = "config-file")]
-	pub config_file: Option<PathBuf>,
+	pub config_file:           Option<PathBuf>,
+
+	/// Overrides a configuration value, as `<key>=<value>`.
+	///
+	/// Values are parsed as in the configuration file, or as a string if they're invalid.
+	/// These take precedence over the configuration file, but not over
+	/// `#[rustidy::config]` attributes.
+	#[clap(long = "config", value_parser = ConfigArg::parse)]
+	pub config:                Vec<ConfigArg>,
+
+	/// Prints a configuration as toml and exits
+	#[clap(long = "print-config", value_enum)]
+	pub print_config:          Option<PrintConfig>,
+
+	/// File to print the current configuration of.
+	///
+	/// By default, prints the configuration of the current directory.
+	#[clap(long = "for-file", requires = "print_config")]
+	pub for_file:              Option<PathBuf>,
+
+	/// Prints a json schema of the configuration file and exits
+	#[clap(long = "config-schema", conflicts_with = "print_config")]
+	pub config_schema:         bool,
+
+	/// Fails on any warnings, such as malformed `#[rustidy]` attributes
+	#[clap(long = "deny-warnings")]
+	pub deny_warnings:         bool,
+
+	/// Skips items and statements that fail to parse instead of failing the whole file.
+	///
+	/// Skipped regions are left unformatted and emitted as warnings.
+	#[clap(long = "recover")]
+	pub recover:               bool,
+
+	/// Format of the diagnostics for files that fail to parse
+	#[clap(long = "error-format", value_enum, default_value_t = ErrorFormat::Human)]
+	pub error_format:          ErrorFormat,
+
+	/// Prints the syntax tree of the file (or stdin) instead of formatting it
+	#[clap(long = "dump-ast", value_enum, conflicts_with_all = ["check", "lines"])]
+	pub dump_ast:              Option<DumpAst>,
+
+	/// Formats the file before printing it's syntax tree
+	#[clap(long = "dump-ast-after-format", requires = "dump_ast")]
+	pub dump_ast_after_format: bool,
 
 	/// Just check, don't overwrite
 	#[clap(long = "check")]
-	pub check:       bool,
+	pub check:                 bool,
+
+	/// What to emit for unformatted files when checking
+	#[clap(long = "emit", value_enum, requires = "check")]
+	pub emit:                  Option<Emit>,
+
+	/// Writes a report of all checked files to stdout
+	#[clap(long = "emit-report", value_enum, requires = "check", conflicts_with = "emit")]
+	pub emit_report:           Option<ReportFormat>,
+
+	/// Only formats the specified lines, as `[<file>:]<start>[-<end>]`.
+	///
+	/// Lines are 1-indexed and inclusive. Ranges without a file apply
+	/// to all files. Files without any ranges are left untouched.
+	#[clap(long = "lines", value_parser = LineRange::parse)]
+	pub lines:                 Vec<LineRange>,
+
+	/// Number of files to format in parallel.
+	///
+	/// By default, uses the available parallelism.
+	#[clap(short = 'j', long = "jobs")]
+	pub jobs:                  Option<NonZeroUsize>,
 
 	/// Files to format
-	pub files:       Vec<PathBuf>,
+	pub files:                 Vec<PathBuf>,
+}
+
+impl Args {
+	/// Returns all line ranges for a file
+	pub fn file_lines(&self, file_path: Option<&Path>) -> Vec<RangeInclusive<usize>> {
+		self
+			.lines
+			.iter()
+			.filter(|range| range.applies_to(file_path))
+			.map(|range| range.start..=range.end)
+			.collect()
+	}
+}
+
+/// Configuration to print
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(clap::ValueEnum)]
+pub enum PrintConfig {
+	/// Default configuration
+	Default,
+
+	/// Current configuration, alongside where each value came from
+	Current,
+}
+
+/// Emit mode
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(clap::ValueEnum)]
+pub enum Emit {
+	/// Unified diff between the file and it's formatted output
+	Diff,
+}
+
+/// Diagnostics format
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(clap::ValueEnum)]
+pub enum ErrorFormat {
+	/// Source snippets with labels, colored if writing to a terminal
+	Human,
+
+	/// A json object per line
+	Json,
+}
+
+/// Syntax tree format
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(clap::ValueEnum)]
+pub enum DumpAst {
+	/// Json, as serialized by the ast
+	Json,
+
+	/// Indented tree of each node, with it's range, tokens and whitespace
+	Tree,
+}
+
+/// Configuration override
+#[derive(Clone, Debug)]
+pub struct ConfigArg(pub PartialConfig);
+
+impl ConfigArg {
+	/// Parses a configuration override from `<key>=<value>`
+	fn parse(s: &str) -> Result<Self, String> {
+		let (key, value) = s.split_once('=').ok_or_else(
+			|| format!("Expected `<key>=<value>`, found {s:?}"),
+		)?;
+		let key = key.trim();
+		if !CONFIG_FIELDS
+			.iter()
+			.any(|field| field.name == key) {
+			let fields = CONFIG_FIELDS.iter().map(|field| field.name);
+			return Err(format!(
+				"Unknown configuration `{key}`{}",
+				util::did_you_mean(key, fields)
+			));
+		}
+
+		// Note: Shells usually remove quotes from strings, so if
+		//       it's not a valid value, we treat it as a string.
+		let value = match toml::from_str::<toml::Table>(&format!("value = {value}")) {
+			Ok(mut table) => table
+				.remove("value")
+				.expect("Parsed table should have a value"),
+			Err(_) => toml::Value::String(value.to_owned()),
+		};
+
+		let config = toml::Table::from_iter([(key.to_owned(), value)])
+			.try_into::<PartialConfig>()
+			.map_err(
+				|err| format!("Invalid value for {key:?}: {}", err.message()),
+			)?;
+
+		Ok(Self(config))
+	}
+}
+
+/// Line range
+#[derive(Clone, Debug)]
+pub struct LineRange {
+	pub file:  Option<PathBuf>,
+	pub start: usize,
+	pub end:   usize,
+}
+
+impl LineRange {
+	/// Returns if this range applies to a file
+	fn applies_to(&self, file_path: Option<&Path>) -> bool {
+		let (Some(file), Some(file_path)) = (&self.file, file_path) else {
+			return self.file.is_none();
+		};
+		if file == file_path {
+			return true;
+		}
+
+		match (file.canonicalize(), file_path.canonicalize()) {
+			(Ok(file), Ok(file_path)) => file == file_path,
+			_ => false,
+		}
+	}
+
+	/// Parses a line range from `[<file>:]<start>[-<end>]`
+	fn parse(s: &str) -> Result<Self, String> {
+		let (file, lines) = match s.rsplit_once(':') {
+			Some((file, lines)) => (Some(PathBuf::from(file)), lines),
+			None => (None, s),
+		};
+
+		let parse_line = |line: &str| line
+			.parse::<usize>()
+			.ok()
+			.filter(|&line| line != 0)
+			.ok_or_else(
+				|| format!("Expected a line number (starting at 1), found {line:?}"),
+			);
+		let (start, end) = match lines.split_once('-') {
+			Some((start, end)) => (parse_line(start)?, parse_line(end)?),
+			None => {
+				let line = parse_line(lines)?;
+				(line, line)
+			},
+		};
+		if start > end {
+			return Err(
+				format!("Line range start ({start}) was after it's end ({end})"),
+			);
+		}
+
+		Ok(Self { file, start, end })
+	}
 }
@@ -0,0 +1,563 @@
+//! Configuration discovery
+
+// Imports
+use {
+	app_error::{AppError, Context, app_error},
+	core::ops::Range,
+	globset::{GlobBuilder, GlobSet, GlobSetBuilder},
+	parse::ParserLoc,
+	serde::Deserialize,
+	std::{
+		collections::HashMap,
+		env,
+		fs,
+		io::Write,
+		path::{Path, PathBuf},
+		sync::{Arc, Mutex},
+	},
+	toml::{Spanned, de::{DeTable, DeValue, ValueDeserializer}},
+	util::{
+		ApplyConfig,
+		CONFIG_FIELDS,
+		Config,
+		ConfigField,
+		ConfigType,
+		PartialConfig,
+	},
+};
+
+/// Configuration file names, in order of priority
+const FILE_NAMES: [&str; 2] = [".rustidy.toml", "rustidy.toml"];
+
+/// Source of each configuration field that isn't the default
+pub type ConfigSources = HashMap<String, String>;
+
+/// Configuration loader.
+///
+/// Finds the configuration of each file by searching for a configuration
+/// file in it's directory, and then in each of it's parents.
+#[derive(Debug)]
+pub struct ConfigLoader {
+	/// Configuration file for all files, if one was specified
+	config_file: Option<Arc<ConfigFile>>,
+
+	/// Configuration file of each directory
+	dirs:        Mutex<HashMap<PathBuf, Option<Arc<ConfigFile>>>>,
+
+	/// Configuration overrides from the command line.
+	///
+	/// Applied on top of the configuration files.
+	args:        Vec<PartialConfig>,
+}
+
+impl ConfigLoader {
+	/// Creates a new loader.
+	///
+	/// If `config_path` is specified, it's configuration is used for all files.
+	pub fn new(config_path: Option<&Path>, args: Vec<PartialConfig>) -> Result<Self, AppError> {
+		let mut loader = Self {
+			config_file: None,
+			dirs: Mutex::new(HashMap::new()),
+			args,
+		};
+
+		if let Some(config_path) = config_path {
+			let config_path = fs::canonicalize(config_path)
+				.context("Unable to canonicalize configuration path")?;
+			loader.config_file = Some(loader.load(&config_path)?);
+		}
+
+		Ok(loader)
+	}
+
+	/// Returns the configuration of a file.
+	///
+	/// If `file_path` is `None`, returns the configuration of the current directory.
+	pub fn for_file(&self, file_path: Option<&Path>) -> Result<Config, AppError> {
+		self.resolve(file_path, None)
+	}
+
+	/// Returns the configuration of a file, alongside the source of each field.
+	///
+	/// If `file_path` is `None`, returns the configuration of the current directory.
+	pub fn for_file_with_sources(
+		&self,
+		file_path: Option<&Path>,
+	) -> Result<(Config, ConfigSources), AppError> {
+		let mut sources = ConfigSources::new();
+		let config = self.resolve(file_path, Some(&mut sources))?;
+
+		Ok((config, sources))
+	}
+
+	fn resolve(
+		&self,
+		file_path: Option<&Path>,
+		mut sources: Option<&mut ConfigSources>,
+	) -> Result<Config, AppError> {
+		let file_path = match file_path {
+			Some(file_path) => Some(
+				fs::canonicalize(file_path)
+					.context("Unable to canonicalize path")?,
+			),
+			None => None,
+		};
+
+		let config_file = match &self.config_file {
+			Some(config_file) => Some(Arc::clone(config_file)),
+			None => {
+				let dir = match &file_path {
+					Some(file_path) => file_path
+						.parent()
+						.expect("File had no parent")
+						.to_path_buf(),
+					None => env::current_dir()
+						.context("Unable to get current directory")?,
+				};
+				self.for_dir(&dir)?
+			},
+		};
+
+		let mut config = Config::default();
+		if let Some(config_file) = config_file {
+			config_file.apply(
+				&mut config,
+				file_path.as_deref(),
+				sources.as_deref_mut(),
+			)?;
+		}
+
+		for arg in &self.args {
+			arg.apply(&mut config);
+			if let Some(sources) = &mut sources {
+				self::add_sources(sources, arg, || "--config".to_owned())?;
+			}
+		}
+
+		Ok(config)
+	}
+
+	/// Returns the configuration file of a directory
+	fn for_dir(&self, dir: &Path) -> Result<Option<Arc<ConfigFile>>, AppError> {
+		if let Some(config_file) = self.dirs.lock().expect("Poisoned").get(dir) {
+			return Ok(config_file.clone());
+		}
+
+		let config_file = match self::find_file(dir)? {
+			Some(config_path) => Some(self.load(&config_path)?),
+			None => match dir.parent() {
+				Some(parent) => self.for_dir(parent)?,
+				None => None,
+			},
+		};
+
+		self
+			.dirs
+			.lock()
+			.expect("Poisoned")
+			.insert(dir.to_path_buf(), config_file.clone());
+
+		Ok(config_file)
+	}
+
+	/// Loads a configuration file
+	fn load(&self, config_path: &Path) -> Result<Arc<ConfigFile>, AppError> {
+		let contents = fs::read_to_string(config_path).with_context(
+			|| format!("Unable to read configuration {config_path:?}"),
+		)?;
+		let repr = ConfigFileRepr::parse(&contents)
+			.map_err(|err| err.to_app_error(config_path, &contents))?;
+
+		let overrides = repr
+			.overrides
+			.into_iter()
+			.map(ConfigOverride::new)
+			.collect::<Result<_, AppError>>()
+			.with_context(
+				|| format!("Unable to parse configuration {config_path:?}"),
+			)?;
+
+		// Note: The configuration file's directory is the one being
+		//       configured, so we inherit from it's parent.
+		let parent = match repr.inherit {
+			true => match config_path.parent().and_then(Path::parent) {
+				Some(parent_dir) => self.for_dir(parent_dir)?,
+				None => None,
+			},
+			false => None,
+		};
+
+		let config_file = ConfigFile {
+			path: config_path.to_path_buf(),
+			config: repr.config,
+			overrides,
+			parent,
+		};
+		tracing::debug!(?config_file, "Loaded configuration");
+
+		Ok(Arc::new(config_file))
+	}
+}
+
+/// Configuration file
+#[derive(Debug)]
+struct ConfigFile {
+	path:      PathBuf,
+	config:    PartialConfig,
+	overrides: Vec<ConfigOverride>,
+
+	/// Configuration file this one inherits from
+	parent:    Option<Arc<Self>>,
+}
+
+impl ConfigFile {
+	/// Applies this configuration to `config` for a file.
+	///
+	/// Any parent configuration is applied first, and overrides are
+	/// applied after the base configuration.
+	fn apply(
+		&self,
+		config: &mut Config,
+		file_path: Option<&Path>,
+		mut sources: Option<&mut ConfigSources>,
+	) -> Result<(), AppError> {
+		if let Some(parent) = &self.parent {
+			parent
+				.apply(config, file_path, sources.as_deref_mut())?;
+		}
+
+		self.config.apply(config);
+		if let Some(sources) = &mut sources {
+			self::add_sources(
+				sources,
+				&self.config,
+				|| self.path.display().to_string(),
+			)?;
+		}
+
+		// Note: Overrides are relative to the configuration file's directory.
+		if let Some(file_path) = file_path {
+			let dir = self.path.parent().expect("File had no parent");
+			let file_path = file_path.strip_prefix(dir).unwrap_or(file_path);
+			for (override_idx, config_override) in self.overrides.iter().enumerate() {
+				if !config_override.files.is_match(file_path) {
+					continue;
+				}
+
+				config_override.config.apply(config);
+				if let Some(sources) = &mut sources {
+					self::add_sources(sources, &config_override.config, || {
+						format!("{} (override #{})", self.path.display(), override_idx + 1)
+					})?;
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Configuration override
+#[derive(Debug)]
+struct ConfigOverride {
+	/// Files this override applies to
+	files:  GlobSet,
+	config: PartialConfig,
+}
+
+impl ConfigOverride {
+	fn new(repr: ConfigOverrideRepr) -> Result<Self, AppError> {
+		let mut files = GlobSetBuilder::new();
+		for glob in &repr.files {
+			let glob = GlobBuilder::new(glob)
+				.literal_separator(true)
+				.build()
+				.with_context(|| format!("Invalid glob {glob:?}"))?;
+			files.add(glob);
+		}
+		let files = files.build().context("Unable to build globs")?;
+
+		Ok(Self { files, config: repr.config, })
+	}
+}
+
+#[derive(Debug)]
+struct ConfigFileRepr {
+	/// Whether to inherit the configuration of the parent directories
+	inherit:   bool,
+	overrides: Vec<ConfigOverrideRepr>,
+	config:    PartialConfig,
+}
+
+impl ConfigFileRepr {
+	/// Parses a configuration file.
+	///
+	/// Unknown keys are rejected, and all errors keep their position.
+	// Note: We can't use `#[serde(flatten)]` for the configuration, since
+	//       it ignores unknown keys and loses the position of any errors.
+	fn parse(contents: &str) -> Result<Self, ParseError> {
+		let mut table = DeTable::parse(contents)?;
+		self::check_keys(table.get_ref(), &["inherit", "overrides"])?;
+
+		let inherit = match table.get_mut().remove("inherit") {
+			Some(inherit) => bool::deserialize(ValueDeserializer::from(inherit))?,
+			None => false,
+		};
+		let overrides = match table.get_mut().remove("overrides") {
+			Some(overrides) => ConfigOverrideRepr::parse_all(overrides)?,
+			None => vec![],
+		};
+		let config = PartialConfig::deserialize(toml::de::Deserializer::from(table))?;
+
+		Ok(Self { inherit, overrides, config })
+	}
+}
+
+#[derive(Debug)]
+struct ConfigOverrideRepr {
+	files:  Vec<String>,
+	config: PartialConfig,
+}
+
+impl ConfigOverrideRepr {
+	/// Parses all overrides from the `overrides` array
+	fn parse_all(overrides: Spanned<DeValue<'_>>) -> Result<Vec<Self>, ParseError> {
+		let span = overrides.span();
+		let DeValue::Array(overrides) = overrides.into_inner() else {
+			return Err(ParseError::new(
+				span,
+				"Expected `overrides` to be an array of tables",
+			));
+		};
+
+		overrides
+			.into_iter()
+			.map(Self::parse)
+			.collect()
+	}
+
+	/// Parses an override
+	fn parse(table: Spanned<DeValue<'_>>) -> Result<Self, ParseError> {
+		let span = table.span();
+		let DeValue::Table(mut table) = table.into_inner() else {
+			return Err(
+				ParseError::new(span, "Expected override to be a table"),
+			);
+		};
+		self::check_keys(&table, &["files"])?;
+
+		let files = table.remove("files").ok_or_else(
+			|| ParseError::new(span.clone(), "Override is missing `files`"),
+		)?;
+		let files = Vec::deserialize(ValueDeserializer::from(files))?;
+		let table = Spanned::new(span, DeValue::Table(table));
+		let config = PartialConfig::deserialize(ValueDeserializer::from(table))?;
+
+		Ok(Self { files, config })
+	}
+}
+
+/// Configuration file parse error
+#[derive(Debug)]
+struct ParseError {
+	/// Byte range of the error, if known
+	span:    Option<Range<usize>>,
+	message: String,
+}
+
+impl ParseError {
+	fn new(span: Range<usize>, message: impl Into<String>) -> Self {
+		let message = message.into();
+		Self { span: Some(span), message }
+	}
+
+	/// Converts this error into an `AppError` with it's location
+	fn to_app_error(&self, config_path: &Path, contents: &str) -> AppError {
+		let config_path = config_path.display();
+		match &self.span {
+			Some(span) => {
+				let loc = ParserLoc::from_pos(contents, span.start);
+				app_error!("{config_path}:{loc}: {}", self.message)
+			},
+			None => app_error!("{config_path}: {}", self.message),
+		}
+	}
+}
+
+impl From<toml::de::Error> for ParseError {
+	fn from(err: toml::de::Error) -> Self {
+		Self {
+			span: err.span(),
+			message: err.message().to_owned(),
+		}
+	}
+}
+
+/// Checks that all keys of `table` are configuration fields or `extra_keys`
+fn check_keys(table: &DeTable<'_>, extra_keys: &[&str]) -> Result<(), ParseError> {
+	let keys = || CONFIG_FIELDS
+		.iter()
+		.map(|field| field.name)
+		.chain(extra_keys.iter().copied());
+	for key in table.keys() {
+		if keys().any(|name| name == key.get_ref()) {
+			continue;
+		}
+
+		let message = format!(
+			"Unknown configuration `{}`{}",
+			key.get_ref(),
+			util::did_you_mean(key.get_ref(), keys())
+		);
+		return Err(ParseError::new(key.span(), message));
+	}
+
+	Ok(())
+}
+
+/// Finds a configuration file in `dir`
+fn find_file(dir: &Path) -> Result<Option<PathBuf>, AppError> {
+	for file_name in FILE_NAMES {
+		let config_path = dir.join(file_name);
+		if fs::exists(&config_path)
+			.context("Unable to check if file exists")? {
+			return Ok(Some(config_path));
+		}
+	}
+
+	Ok(None)
+}
+
+/// Sets the source of all fields set in `config`
+fn add_sources(
+	sources: &mut ConfigSources,
+	config: &PartialConfig,
+	source: impl Fn() -> String,
+) -> Result<(), AppError> {
+	let config = toml::Table::try_from(config)
+		.context("Unable to serialize configuration")?;
+	for field in config.keys() {
+		sources.insert(field.clone(), source());
+	}
+
+	Ok(())
+}
+
+/// Writes a configuration as toml.
+///
+/// Each field is followed by a comment with it's source.
+pub fn write_toml(
+	config: &Config,
+	sources: &ConfigSources,
+	mut writer: impl Write,
+) -> Result<(), AppError> {
+	let values = toml::Table::try_from(PartialConfig::from(config))
+		.context("Unable to serialize configuration")?;
+
+	let lines = CONFIG_FIELDS
+		.iter()
+		.map(|field| {
+			let line = match values.get(field.name) {
+				Some(value) => format!("{} = {value}", field.name),
+				// Note: Toml has no way to represent unset values
+				None => format!("# {} = <unset>", field.name),
+			};
+			let source = sources
+				.get(field.name)
+				.map_or("Default", String::as_str);
+
+			(line, source)
+		})
+		.collect::<Vec<_>>();
+
+	let width = lines
+		.iter()
+		.map(|(line, _)| line.len())
+		.max()
+		.unwrap_or(0);
+	for (line, source) in lines {
+		writeln!(writer, "{line:width$} # {source}")
+			.context("Unable to write configuration")?;
+	}
+
+	Ok(())
+}
+
+/// Writes a json schema of the configuration file
+pub fn write_schema(mut writer: impl Write) -> Result<(), AppError> {
+	let defaults = serde_json::to_value(PartialConfig::from(&Config::default()))
+		.context("Unable to serialize default configuration")?;
+	let fields = CONFIG_FIELDS
+		.iter()
+		.map(|field| {
+			let schema = self::field_schema(field, defaults.get(field.name));
+			(field.name.to_owned(), schema)
+		})
+		.collect::<serde_json::Map<_, _>>();
+
+	let mut override_properties = fields.clone();
+	override_properties.insert("files".to_owned(), serde_json::json!({
+		"description": "Globs of the files to apply this override to, relative to the configuration file",
+		"type": "array",
+		"items": { "type": "string" },
+	}));
+
+	let mut properties = fields;
+	properties.insert("inherit".to_owned(), serde_json::json!({
+		"description": "Whether to inherit the configuration of the parent directories",
+		"type": "boolean",
+		"default": false,
+	}));
+	properties.insert("overrides".to_owned(), serde_json::json!({
+		"description": "Configuration overrides for specific files",
+		"type": "array",
+		"items": {
+			"type": "object",
+			"properties": override_properties,
+			"required": ["files"],
+			"additionalProperties": false,
+		},
+	}));
+
+	let schema = serde_json::json!({
+		"$schema": "https://json-schema.org/draft/2020-12/schema",
+		"title": "Rustidy configuration",
+		"type": "object",
+		"properties": properties,
+		"additionalProperties": false,
+	});
+
+	serde_json::to_writer_pretty(&mut writer, &schema)
+		.context("Unable to serialize schema")?;
+	writeln!(writer)
+		.context("Unable to write schema")
+}
+
+/// Returns the json schema of a configuration field
+fn field_schema(field: &ConfigField, default: Option<&serde_json::Value>) -> serde_json::Value {
+	let description = field
+		.docs
+		.iter()
+		.map(|line| line.strip_prefix(' ').unwrap_or(line))
+		.collect::<Vec<_>>()
+		.join("\n");
+
+	let mut schema = serde_json::json!({ "description": description });
+	match field.ty {
+		ConfigType::String => schema["type"] = "string".into(),
+		ConfigType::Bool => schema["type"] = "boolean".into(),
+		ConfigType::Integer => {
+			schema["type"] = "integer".into();
+			schema["minimum"] = 0.into();
+		},
+		ConfigType::Enum(variants) => {
+			schema["type"] = "string".into();
+			schema["enum"] = variants.into();
+		},
+	}
+	if let Some(default) = default && !default.is_null() {
+		schema["default"] = default.clone();
+	}
+
+	schema
+}
@@ -22,25 +22,38 @@
 
 // Modules
 mod args;
+mod config;
+mod modules;
+mod pool;
+mod report;
 
 // Imports
 use {
-	self::args::Args,
-	app_error::{AppError, Context, bail, ensure},
-	ast::{attr::OuterAttrOrDocComment, item::{ItemInner, Module, VisItemInner}},
-	ast_literal::Identifier,
+	self::{
+		args::{Args, DumpAst, Emit, ErrorFormat, PrintConfig},
+		config::{ConfigLoader, ConfigSources},
+		modules::SourceFile,
+		report::{FileReport, Report},
+	},
+	app_error::{AppError, Context, app_error, ensure},
 	clap::Parser as _,
-	format::FormatOutput,
+	core::cell::LazyCell,
+	format::{FormatOutput, FormatWarning},
+	parse::ParserLoc,
 	print::Print,
+	rustidy::{Diagnostic, Diff, SkippedRegion},
 	std::{
-		borrow::Cow,
+		collections::{HashMap, HashSet},
 		fs,
-		io::{self, Write},
+		io::{self, IsTerminal, Write},
+		mem,
 		path::{Path, PathBuf},
 		process::ExitCode,
+		sync::Mutex,
+		thread,
 		time::Instant,
 	},
-	util::Config,
+	util::{AstPos, ColumnUnit, Config, LineCol, LineIndex},
 	zutil_logger::Logger,
 };
 
@@ -59,115 +72,488 @@ fn run() -> Result<(), AppError> {
 	let logger = Logger::new();
 
 	// Parse arguments
-	let args = Args::parse();
+	let mut args = Args::parse();
 	tracing::debug!(?args, "Arguments");
 
 	// Set logger file from arguments
 	logger.set_file(args.log_file.as_deref());
 
-	let config_path = match args.config_file {
-		Some(config_path) => Some(config_path),
-		None => {
-			let cur_dir = std::env::current_dir()
-				.context("Unable to get current directory")?;
-			let mut cur_dir = cur_dir.as_path();
-			loop {
-				// TODO: Should we also allow `rustidy.toml`?
-				let config_path = cur_dir.join(".rustidy.toml");
-				if fs::exists(&config_path)
-					.context("Unable to check if directory exists")? {
-					break Some(config_path);
-				}
+	if args.config_schema {
+		return config::write_schema(io::stdout().lock());
+	}
 
-				match cur_dir.parent() {
-					Some(parent) => cur_dir = parent,
-					None => break None,
-				}
-			}
-		},
-	};
-	let config = match config_path {
-		Some(config_path) => {
-			let config = fs::read_to_string(config_path)
-				.context("Unable to read configuration")?;
-			toml::from_str(&config)
-				.context("Unable to parse configuration")?
-		},
-		None => Config::default(),
+	let config_args = args
+		.config
+		.iter()
+		.map(|arg| arg.0.clone())
+		.collect();
+	let configs = ConfigLoader::new(args.config_file.as_deref(), config_args)?;
+	if let Some(print_config) = args.print_config {
+		let (config, sources) = match print_config {
+			PrintConfig::Default => (Config::default(), ConfigSources::new()),
+			PrintConfig::Current => configs
+				.for_file_with_sources(args.for_file.as_deref())?,
+		};
+
+		return config::write_toml(&config, &sources, io::stdout().lock());
+	}
+
+	if let Some(dump_ast) = args.dump_ast {
+		ensure!(args.files.len() <= 1, "`--dump-ast` only supports a single file");
+		return self::dump_ast(&configs, &args, dump_ast);
+	}
+
+	let mut report = args.emit_report.map(|_| Report::default());
+	let mut unformatted_files = 0_usize;
+	let mut emit_file = |file: FormattedFile| -> Result<(), AppError> {
+		if let Some((path, output)) = file.output {
+			fs::write(path, output)
+				.context("Unable to write file")?;
+		}
+		if let Some(diff) = file.diff {
+			io::stdout()
+				.write_all(diff.as_bytes())
+				.context("Unable to write to stdout")?;
+		}
+		if let Some(report) = &mut report && let Some(file_report) = file.report {
+			report.files.push(file_report);
+		}
+		if !file.is_formatted {
+			unformatted_files += 1;
+		}
+
+		Ok(())
 	};
-	tracing::debug!(?config, "Configuration");
 
+	let mut errs = vec![];
 	match args.files.is_empty() {
-		true => self::format_file(&config, None, None, args.check)?,
+		true => {
+			let config = configs.for_file(None)?;
+			let mut logs = FileLogs::default();
+			let res = self::format_file(&config, &args, None, &mut logs);
+			logs.emit()?;
+			emit_file(res?)?;
+		},
 		false => {
-			let mut files = args.files;
-			while let Some(file_path) = files.pop() {
+			let jobs = match args.jobs {
+				Some(jobs) => jobs,
+				None => thread::available_parallelism()
+					.context("Unable to get available parallelism")?,
+			};
+			let roots = mem::take(&mut args.files);
+			let files = roots
+				.iter()
+				.cloned()
+				.map(SourceFile::new)
+				.collect::<Vec<_>>();
+			let visited = Mutex::new(VisitedFiles::default());
+			let results = pool::run(files.clone(), jobs, |source| {
 				let start = Instant::now();
-				self::format_file(
-					&config,
-					Some(&mut files),
-					Some(&file_path),
-					args.check
-				)
-					.with_context(|| format!("While formatting {file_path:?}"))?;
+				let res = self::format_source(&configs, &args, source, &visited);
 				let duration = start.elapsed();
+				tracing::info!("{:?}: {duration:.2?}", source.path);
+
+				let modules = match &res {
+					Some(SourceResult { modules: Ok(modules), .. }) => modules.clone(),
+					_ => vec![],
+				};
+				(res, modules)
+			});
+
+			let mut results = results
+				.into_iter()
+				.filter_map(|(_, res)| res)
+				.collect::<FormatResults>();
+			let mut reached = HashSet::new();
+			let mut emit_source = |source: &SourceFile, logs: FileLogs, res: Result<FormattedFile, AppError>| {
+				logs.emit()?;
+				match res {
+					Ok(file) => emit_file(file)?,
+					Err(err) => errs.push(
+						err
+							.with_context(|| format!("While formatting {:?}", source.path)),
+					),
+				}
 
-				tracing::info!("{file_path:?}: {duration:.2?}");
+				Ok(())
+			};
+			for source in &files {
+				results.visit(
+					source,
+					&mut reached,
+					&mut vec![],
+					&mut emit_source,
+				)?;
+			}
+
+			for path in modules::find_unreached(&roots, &reached)? {
+				tracing::warn!("{path:?}: File isn't reachable from any module and wasn't formatted");
 			}
 		},
 	}
 
+	if let Some(report) = report && let Some(format) = args.emit_report {
+		report.write(format, io::stdout().lock())?;
+	}
+	if !errs.is_empty() {
+		return Err(AppError::from_multiple(errs));
+	}
+	ensure!(unformatted_files == 0, "{unformatted_files} file(s) were not formatted");
 
 	Ok(())
 }
 
+/// Formatted file
+struct FormattedFile {
+	/// Whether the file was already formatted.
+	///
+	/// Only `false` when checking
+	is_formatted: bool,
+
+	/// Output to write to the file
+	output:       Option<(PathBuf, String)>,
+
+	/// Diff to emit
+	diff:         Option<String>,
+
+	/// Report of this file
+	report:       Option<FileReport>,
+
+	/// Modules of this file that should be formatted next
+	modules:      Result<Vec<SourceFile>, AppError>,
+}
+
+/// Logs of a file.
+///
+/// Files are formatted in parallel, so instead of logging while formatting,
+/// these are emitted afterwards, in the same order as the files.
+#[derive(Default)]
+struct FileLogs(Vec<FileLog>);
+
+impl FileLogs {
+	/// Emits all logs
+	fn emit(self) -> Result<(), AppError> {
+		for log in self.0 {
+			match log {
+				FileLog::Diagnostics(diagnostics) => io::stderr()
+					.write_all(diagnostics.as_bytes())
+					.context("Unable to write to stderr")?,
+				FileLog::Warning(warning) => tracing::warn!("{warning}"),
+				FileLog::Error(err) => tracing::error!("{err}"),
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Log of a file
+enum FileLog {
+	/// Rendered diagnostics, written to stderr
+	Diagnostics(String),
+
+	/// Warning
+	Warning(String),
+
+	/// Error
+	Error(String),
+}
+
+/// Files visited by the workers
+#[derive(Default)]
+struct VisitedFiles {
+	/// Canonical paths of all visited files, alongside whether they own their directory
+	sources:   HashSet<(PathBuf, bool)>,
+
+	/// Canonical paths of all formatted files
+	formatted: HashSet<PathBuf>,
+}
+
+/// Result of visiting a source file
+struct SourceResult {
+	/// Canonical path of the file
+	path:      PathBuf,
+
+	/// Whether the file owns it's directory
+	is_mod_rs: bool,
+
+	/// Logs and result of formatting the file, if it was formatted by this visit
+	formatted: Option<(FileLogs, Result<FormattedFile, AppError>)>,
+
+	/// Modules of the file
+	modules:   Result<Vec<SourceFile>, AppError>,
+}
+
+/// Results of visiting all source files
+#[derive(Default)]
+struct FormatResults {
+	/// Logs and result of formatting each file, by canonical path
+	files:   HashMap<PathBuf, (FileLogs, Result<FormattedFile, AppError>)>,
+
+	/// Modules of each file, by canonical path and whether it owns it's directory
+	modules: HashMap<(PathBuf, bool), Result<Vec<SourceFile>, AppError>>,
+}
+
+impl FormatResults {
+	/// Visits `source` and all of it's modules, calling `f` with each file the first time it's reached.
+	///
+	/// Files are visited in the same order they'd be formatted in sequentially, regardless of
+	/// which worker formatted them, so that the output doesn't depend on the number of jobs.
+	fn visit(
+		&mut self,
+		source: &SourceFile,
+		reached: &mut HashSet<PathBuf>,
+		parents: &mut Vec<PathBuf>,
+		f: &mut impl FnMut(&SourceFile,FileLogs,Result<FormattedFile, AppError>) -> Result<(), AppError>,
+	) -> Result<(), AppError> {
+		let path = self::canonical_path(&source.path);
+		if let Some(idx) = parents.iter().position(|parent| *parent == path) {
+			let cycle = parents[idx..]
+				.iter()
+				.chain([&path])
+				.map(|path| format!("{path:?}"))
+				.collect::<Vec<_>>()
+				.join(" -> ");
+			tracing::warn!("Found module cycle, skipping: {cycle}");
+			return Ok(());
+		}
+
+		if !reached.insert(path.clone()) {
+			tracing::debug!("{:?}: File was already formatted, skipping", source.path);
+			return Ok(());
+		}
+
+		let (logs, res) = self
+			.files
+			.remove(&path)
+			.expect("Reached file wasn't formatted");
+		f(source, logs, res)?;
+
+		let modules = self
+			.modules
+			.remove(&(path.clone(), source.is_mod_rs))
+			.expect("Reached file wasn't visited");
+		let modules = match modules {
+			Ok(modules) => modules,
+			Err(err) => return f(source, FileLogs::default(), Err(err)),
+		};
+
+		parents.push(path);
+		for module in &modules {
+			self.visit(module, reached, parents, f)?;
+		}
+		parents.pop();
+
+		Ok(())
+	}
+}
+
+impl FromIterator<SourceResult> for FormatResults {
+	fn from_iter<I: IntoIterator<Item = SourceResult>>(iter: I) -> Self {
+		let mut results = Self::default();
+		for res in iter {
+			if let Some(formatted) = res.formatted {
+				results
+					.files
+					.insert(res.path.clone(), formatted);
+			}
+			results
+				.modules
+				.insert((res.path, res.is_mod_rs), res.modules);
+		}
+
+		results
+	}
+}
+
+/// Visits a source file, unless it was already visited.
+///
+/// The first visit of each file formats it, while later visits, which may
+/// own a different directory, only find it's modules.
+/// Nothing is written here, so that only the files reached first, in order,
+/// are written.
+///
+/// Note: Files are marked as visited before being formatted, so that
+///       the same file isn't formatted by multiple workers at once.
+fn format_source(
+	configs: &ConfigLoader,
+	args: &Args,
+	source: &SourceFile,
+	visited: &Mutex<VisitedFiles>,
+) -> Option<SourceResult> {
+	let path = self::canonical_path(&source.path);
+	let is_first_visit = {
+		let mut visited = visited.lock().expect("Poisoned");
+		if !visited
+			.sources
+			.insert((path.clone(), source.is_mod_rs)) {
+			tracing::debug!("{:?}: File was already visited, skipping", source.path);
+			return None;
+		}
+
+		visited.formatted.insert(path.clone())
+	};
+
+	let (formatted, modules) = match is_first_visit {
+		true => {
+			let mut logs = FileLogs::default();
+			let mut res = configs.for_file(Some(&path)).and_then(
+				|config| self::format_file(&config, args, Some(source), &mut logs),
+			);
+			let modules = match &mut res {
+				Ok(file) => mem::replace(&mut file.modules, Ok(vec![])),
+				Err(_) => Ok(vec![]),
+			};
+			(Some((logs, res)), modules)
+		},
+		false => (None, self::find_modules(args, source)),
+	};
+
+	Some(SourceResult {
+		path,
+		is_mod_rs: source.is_mod_rs,
+		formatted,
+		modules,
+	})
+}
+
+/// Finds the modules of a source file, without formatting it.
+///
+/// Any errors reading or parsing the file are ignored, since they're
+/// reported when formatting it.
+fn find_modules(args: &Args, source: &SourceFile) -> Result<Vec<SourceFile>, AppError> {
+	let Ok(input) = self::read_input(Some(&source.path)) else {
+		return Ok(vec![]);
+	};
+	let parsed = match args.recover {
+		true => rustidy::parse_recovering(&input, &source.path)
+			.map(|(crate_, _)| crate_),
+		false => rustidy::parse_with_loc(&input, &source.path),
+	};
+
+	match parsed {
+		Ok(ast::Crate { items: Some(items), .. }) => modules::find(source, &items),
+		_ => Ok(vec![]),
+	}
+}
+
+/// Returns the canonical path of `path`, or `path` itself, if it can't be canonicalized.
+///
+/// Note: Files that can't be canonicalized will fail to be read, so we
+///       let that report the error.
+fn canonical_path(path: &Path) -> PathBuf {
+	fs::canonicalize(path)
+		.unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Formats a file, or stdin, if `source` is `None`.
+///
+/// When emitting a report, parse errors are added to it
+/// instead of returned.
 fn format_file(
 	config: &util::Config,
-	files: Option<&mut Vec<PathBuf>>,
-	file_path: Option<&Path>,
-	check: bool
-) -> Result<(), AppError> {
+	args: &Args,
+	source: Option<&SourceFile>,
+	logs: &mut FileLogs,
+) -> Result<FormattedFile, AppError> {
+	let file_path = source.map(|source| &*source.path);
+
 	// Parse
-	let input = match file_path {
-		Some(file_path) => fs::read_to_string(file_path)
-			.context("Unable to read file")?,
-		None => io::read_to_string(io::stdin())
-			.context("Unable to read stdin")?,
+	let input = self::read_input(file_path)?;
+	let display_path = file_path.unwrap_or_else(|| Path::new("<stdin>"));
+	let mut file = FormattedFile {
+		is_formatted: true,
+		output: None,
+		diff: None,
+		report: args
+			.emit_report
+			.map(|_| FileReport::new(display_path)),
+		modules: Ok(vec![]),
 	};
-	let mut crate_ = rustidy::parse(
-		&input,
-		file_path
-			.unwrap_or_else(|| Path::new("<stdin>"))
-	)?;
-
-	// Queue modules for formatting.
-	if let Some(file_path) = file_path && let Some(files) = files && let Some(items) = &crate_.items {
-		for item in &items.0 {
-			// If it's not a module definition, skip it
-			// TODO: Support modules inside of other modules (and other items).
-			let ItemInner::Vis(vis_item) = &item.0.inner else { continue };
-			let VisItemInner::Module(mod_) = &vis_item.inner else { continue };
-			if mod_.inner.is_def() {
-				continue;
+	let parsed = match args.recover {
+		true => rustidy::parse_recovering(&input, display_path),
+		false => rustidy::parse_with_loc(&input, display_path)
+			.map(|crate_| (crate_, vec![])),
+	};
+	let (mut crate_, skipped) = match parsed {
+		Ok(parsed) => parsed,
+		Err(err) => {
+			let diagnostics = err
+				.diagnostics
+				.iter()
+				.map(|diagnostic| (&**err.line_index(), diagnostic));
+			logs.0.push(
+				FileLog::Diagnostics(self::render_diagnostics(args, diagnostics)?),
+			);
+			match &mut file.report {
+				Some(file_report) => {
+					file_report.add_parse_error(&err);
+					file.is_formatted = false;
+					return Ok(file);
+				},
+				None => do yeet app_error!("Unable to parse file"),
 			}
+		},
+	};
 
-			// Then get it's path
-			let mod_path = self::mod_path(file_path, &item.0.attrs, mod_)?;
-			files.push(mod_path);
-		}
+	// Queue modules for formatting.
+	if let Some(source) = source && let Some(items) = &crate_.items {
+		file.modules = modules::find(source, items);
 	}
 
 	// Format
-	let _: FormatOutput = rustidy::format(&input, config, &mut crate_);
+	let mut warnings = vec![];
+	let output = match args.lines.is_empty() {
+		true => {
+			let _: FormatOutput = rustidy::format(&input, config, &mut crate_, &mut warnings);
+			crate_.print_to(Print::print)
+		},
+		false => {
+			let lines = args.file_lines(file_path);
+			rustidy::format_lines(
+				&input,
+				config,
+				&mut crate_,
+				&lines,
+				&mut warnings,
+			)
+		},
+	};
+	self::emit_warnings(
+		args,
+		logs,
+		display_path,
+		&input,
+		&skipped,
+		&warnings,
+	)?;
+
+	match args.check {
+		true => if input != output.as_str() {
+			match args.emit {
+				Some(Emit::Diff) => {
+					let diff = Diff::new(&input, output.as_str(), 3);
+					file.diff = Some(
+						format!("--- {0}\n+++ {0}\n{diff}", display_path.display()),
+					);
+				},
+				None => logs.0.push(FileLog::Error(
+					format!("{display_path:?}: File was not formatted"),
+				)),
+			}
+			if let Some(file_report) = &mut file.report {
+				file_report
+					.add_changes(&input, output.as_str());
+			}
 
-	let output = crate_.print_to(Print::print);
-	match check {
-		true => ensure!(input == output.as_str(), "File was not formatted"),
+			file.is_formatted = false;
+		},
 		false => {
 			match file_path {
 				Some(file_path) => if input != output.as_str() {
-					fs::write(file_path, output.as_str())
-						.context("Unable to write file")?;
+					file.output = Some(
+						(file_path.to_path_buf(), output.as_str().to_owned()),
+					);
 				},
 				None => io::stdout()
 					.write_all(output.as_str().as_bytes())
@@ -176,80 +562,143 @@ fn format_file(
 		}
 	}
 
-	Ok(())
+	Ok(file)
 }
 
-/// Returns a module's path
-fn mod_path<'a>(
-	file_path: &Path,
-	attrs: impl IntoIterator<Item = &'a OuterAttrOrDocComment>,
-	mod_: &Module,
-) -> Result<PathBuf, AppError> {
-	let path = match self::find_path_attr(attrs)? {
-		// If it had a `#[path = ...]` attribute, use that
-		Some(name) => file_path.with_file_name("").join(&*name),
-
-		// Otherwise, use it's identifier
-		None => {
-			let name = match &mod_.ident {
-				Identifier::Raw(ident) => {
-					super let ident = ident.1.str();
-					let ident = ident
-						.strip_prefix("r#")
-						.expect("Raw identified didn't start with `r#`");
-					Cow::Borrowed(ident)
-				},
-				Identifier::NonKw(ident) => ident.0.1.str(),
-			};
-
-			// Try `<name>/mod.rs` first
-			let mod_rs_path = file_path
-				.parent()
-				.expect("File had no parent")
-				.join(&*name)
-				.join("mod.rs");
-			match mod_rs_path
-				.try_exists()
-				.context("Unable to check if file exists")? {
-				true => mod_rs_path,
-				// If it fails, try the new module system
-				false => {
-					// TODO: Is this the proper way to check for the root?
-					let is_root = ["lib.rs", "main.rs", "mod.rs"]
-						.iter()
-						.any(|file| file_path.ends_with(file));
-					let mod_file_name = format!("{name}.rs");
-					match is_root {
-						true => file_path.with_file_name(mod_file_name),
-						false => file_path.with_extension("").join(mod_file_name),
-					}
-				},
-			}
+/// Prints the syntax tree of a file, or stdin, if no files were passed
+fn dump_ast(configs: &ConfigLoader, args: &Args, dump_ast: DumpAst) -> Result<(), AppError> {
+	let file_path = args.files.first().map(PathBuf::as_path);
+	let input = self::read_input(file_path)?;
+	let display_path = file_path.unwrap_or_else(|| Path::new("<stdin>"));
+	let mut logs = FileLogs::default();
+	let parsed = match args.recover {
+		true => rustidy::parse_recovering(&input, display_path),
+		false => rustidy::parse_with_loc(&input, display_path)
+			.map(|crate_| (crate_, vec![])),
+	};
+	let (mut crate_, skipped) = match parsed {
+		Ok(parsed) => parsed,
+		Err(err) => {
+			let diagnostics = err
+				.diagnostics
+				.iter()
+				.map(|diagnostic| (&**err.line_index(), diagnostic));
+			logs.0.push(
+				FileLog::Diagnostics(self::render_diagnostics(args, diagnostics)?),
+			);
+			logs.emit()?;
+			do yeet app_error!("Unable to parse file");
 		},
 	};
 
-	Ok(path)
+	let mut warnings = vec![];
+	if args.dump_ast_after_format {
+		let config = configs.for_file(file_path)?;
+		let _: FormatOutput = rustidy::format(&input, &config, &mut crate_, &mut warnings);
+	}
+	self::emit_warnings(
+		args,
+		&mut logs,
+		display_path,
+		&input,
+		&skipped,
+		&warnings,
+	)?;
+	logs.emit()?;
+
+	let output = match dump_ast {
+		DumpAst::Json => serde_json::to_string_pretty(&crate_)
+			.context("Unable to serialize ast")?
+			+ "\n",
+		DumpAst::Tree => rustidy::ast_tree(&crate_)
+			.context("Unable to create ast tree")?,
+	};
+	io::stdout()
+		.write_all(output.as_bytes())
+		.context("Unable to write to stdout")
 }
 
-/// Finds a `#[path = ...]` attribute
-// TODO: Support `cfg_attr(..., path = ...)` and others?
-fn find_path_attr<'a>(
-	attrs: impl IntoIterator<Item = &'a OuterAttrOrDocComment>,
-) -> Result<Option<Cow<'a, str>>, AppError> {
-	for attr in attrs {
-		let Some(attr) = attr.try_as_attr_ref() else { continue };
-		let Some(meta) = attr.open.value.try_as_meta_ref() else { continue };
-		if !(meta.path().is_str("path")) {
-			continue;
-		}
-		let Some(meta) = meta.try_as_eq_expr_ref() else { bail!("Malformed `#[path = ...]` attribute") };
-		// TODO: Support raw strings here
-		let Some(literal) = meta.expr.as_string_literal() else {
-			bail!("Expected a literal expression in `#[path = ...]` attribute")
-		};
+/// Reads a file, or stdin, if `file_path` is `None`
+fn read_input(file_path: Option<&Path>) -> Result<String, AppError> {
+	match file_path {
+		Some(file_path) => fs::read_to_string(file_path)
+			.context("Unable to read file"),
+		None => io::read_to_string(io::stdin())
+			.context("Unable to read stdin"),
+	}
+}
+
+/// Emits all warnings of a file to `logs`, including any regions skipped while parsing.
+///
+/// If denying warnings, they're returned as an error instead.
+fn emit_warnings(
+	args: &Args,
+	logs: &mut FileLogs,
+	display_path: &Path,
+	input: &str,
+	skipped: &[SkippedRegion],
+	warnings: &[FormatWarning],
+) -> Result<(), AppError> {
+	let line_index = LazyCell::new(|| LineIndex::new(input));
+	let warnings = warnings
+		.iter()
+		.map(|warning| match warning.pos {
+			Some(pos) => {
+				let LineCol { line, column } = line_index
+					.line_col(AstPos(pos), ColumnUnit::Bytes);
+				let loc = ParserLoc { line, column };
+				format!("{}:{loc}: {}", display_path.display(), warning.message)
+			},
+			None => format!("{}: {}", display_path.display(), warning.message),
+		});
 
-		return Ok(Some(literal.contents()));
+	match args.deny_warnings {
+		true => {
+			let errs = skipped
+				.iter()
+				.map(SkippedRegion::to_app_error)
+				.chain(warnings.map(AppError::fmt))
+				.collect::<Vec<_>>();
+			if !errs.is_empty() {
+				return Err(AppError::from_multiple(errs));
+			}
+		},
+		false => {
+			let diagnostics = skipped.iter().map(
+				|region| (&**region.line_index(), &region.diagnostic),
+			);
+			logs.0.push(
+				FileLog::Diagnostics(self::render_diagnostics(args, diagnostics)?),
+			);
+			logs.0.extend(warnings.map(FileLog::Warning));
+		},
 	}
 
-	Ok(None)
+	Ok(())
+}
+
+/// Renders diagnostics for stderr, alongside the line index of their input
+fn render_diagnostics<'a>(
+	args: &Args,
+	diagnostics: impl IntoIterator<Item = (&'a LineIndex, &'a Diagnostic)>,
+) -> Result<String, AppError> {
+	let output = match args.error_format {
+		ErrorFormat::Human => {
+			let color = io::stderr().is_terminal();
+			diagnostics.into_iter().map(
+				|(line_index, diagnostic)| diagnostic
+					.render(line_index, color) + "\n",
+			).collect::<String>()
+		},
+		ErrorFormat::Json => diagnostics
+			.into_iter()
+			.map(
+				|(_, diagnostic)| serde_json::to_string(diagnostic)
+					.map(|diagnostic| diagnostic + "\n"),
+			)
+			.collect::<Result<String, _>>()
+			.context("Unable to serialize diagnostics")?,
+	};
+
+	Ok(output)
 }
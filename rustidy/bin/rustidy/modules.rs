@@ -0,0 +1,338 @@
+//! Module discovery
+
+// Imports
+use {
+	app_error::{AppError, Context, bail},
+	ast::{
+		attr::{OuterAttrOrDocComment, meta::{MetaItem, MetaItemInner}},
+		expr::Expression,
+		item::{ItemInner, Items, Module, VisItemInner, mod_::ModuleInner},
+	},
+	ast_literal::Identifier,
+	std::{borrow::Cow, collections::{BTreeSet, HashSet}, fs, path::{Path, PathBuf}},
+};
+
+/// Source file
+#[derive(Clone, Debug)]
+pub struct SourceFile {
+	pub path:      PathBuf,
+
+	/// Whether this file owns it's directory.
+	///
+	/// This is the case for crate roots, `mod.rs` files and
+	/// files included with `#[path = ...]`.
+	pub is_mod_rs: bool,
+}
+
+impl SourceFile {
+	/// Creates a source file from a path given by the user.
+	///
+	/// These are always treated as crate roots, like `rustc` does, since
+	/// cargo targets may have any name, such as `src/bin/tool.rs`.
+	pub const fn new(path: PathBuf) -> Self {
+		Self { path, is_mod_rs: true }
+	}
+}
+
+/// Finds all out-of-line modules declared in `file`, at any depth.
+pub fn find(file: &SourceFile, items: &Items) -> Result<Vec<SourceFile>, AppError> {
+	let path = file.path.parent().expect("File had no parent");
+	let relative = match file.is_mod_rs {
+		true => None,
+		false => file
+			.path
+			.file_stem()
+			.map(|name| name.to_string_lossy().into_owned()),
+	};
+	let dir = ModDir {
+		path: path.to_path_buf(),
+		relative,
+		is_cfg: false,
+	};
+
+	let mut modules = vec![];
+	self::find_in_items(&dir, items, &mut modules)?;
+
+	Ok(modules)
+}
+
+/// Finds all `.rs` files next to the modules of `roots` that weren't `reached`.
+///
+/// Only `lib.rs` and `main.rs` roots are considered to have a source directory, and within it,
+/// only the directories that reached modules (or their submodules) would live in are searched.
+/// Files within it's `bin/` directory are skipped, since they're separate targets.
+pub fn find_unreached(
+	roots: &[PathBuf],
+	reached: &HashSet<PathBuf>,
+) -> Result<Vec<PathBuf>, AppError> {
+	let mut dirs = BTreeSet::new();
+	for root in roots {
+		if !["lib.rs", "main.rs"]
+			.iter()
+			.any(|file| root.ends_with(file)) {
+			continue;
+		}
+
+		let root = fs::canonicalize(root)
+			.context("Unable to canonicalize path")?;
+		let src_dir = root.parent().expect("File had no parent");
+		let skip_dir = src_dir.join("bin");
+		for path in reached {
+			if !path.starts_with(src_dir) || path.starts_with(&skip_dir) {
+				continue;
+			}
+
+			let dir = path.parent().expect("File had no parent");
+			dirs.insert(dir.to_path_buf());
+
+			// Note: Submodules of non-`mod.rs` files are in a directory with their name
+			if *path != root && let Some(name) = path.file_stem() && name != "mod" {
+				let mods_dir = dir.join(name);
+				if mods_dir.is_dir() {
+					dirs.insert(mods_dir);
+				}
+			}
+		}
+	}
+
+	let mut unreached = vec![];
+	for dir in &dirs {
+		self::find_unreached_in_dir(dir, reached, &mut unreached)?;
+	}
+
+	unreached.sort();
+	Ok(unreached)
+}
+
+/// Finds all `.rs` files directly within `dir` that weren't `reached`
+fn find_unreached_in_dir(
+	dir: &Path,
+	reached: &HashSet<PathBuf>,
+	unreached: &mut Vec<PathBuf>,
+) -> Result<(), AppError> {
+	let entries = fs::read_dir(dir)
+		.with_context(|| format!("Unable to read directory {dir:?}"))?;
+	for entry in entries {
+		let entry = entry.context("Unable to read directory entry")?;
+		if entry
+			.path()
+			.extension()
+			.is_none_or(|ext| ext != "rs") {
+			continue;
+		}
+
+		// Note: Symlinks might be dangling, which we just skip.
+		let path = match fs::canonicalize(entry.path()) {
+			Ok(path) => path,
+			Err(err) => {
+				tracing::warn!("{:?}: Unable to canonicalize path, skipping: {err}", entry.path());
+				continue;
+			},
+		};
+		if path.is_file() && !reached.contains(&path) {
+			unreached.push(entry.path());
+		}
+	}
+
+	Ok(())
+}
+
+/// Module directory.
+///
+/// Mirrors how `rustc` tracks the directory of the current module.
+#[derive(Clone, Debug)]
+struct ModDir {
+	/// Directory that `#[path = ...]` attributes are relative to
+	path:     PathBuf,
+
+	/// Directory component to append to `path` for modules without
+	/// a `#[path = ...]` attribute.
+	///
+	/// Only set at the top-level of non-`mod.rs` files.
+	relative: Option<String>,
+
+	/// Whether this directory is only used under some `cfg`.
+	///
+	/// Missing modules within it are skipped, since we can't
+	/// know if they're actually used.
+	is_cfg:   bool,
+}
+
+impl ModDir {
+	/// Returns the directory that modules without a `#[path = ...]`
+	/// attribute are in.
+	fn mods_path(&self) -> PathBuf {
+		match &self.relative {
+			Some(relative) => self.path.join(relative),
+			None => self.path.clone(),
+		}
+	}
+}
+
+/// Finds all out-of-line modules in `items`
+fn find_in_items(dir: &ModDir, items: &Items, modules: &mut Vec<SourceFile>) -> Result<(), AppError> {
+	for item in &items.0 {
+		// Note: Modules may only be declared out-of-line within other modules,
+		//       so we don't need to look inside any other items.
+		let ItemInner::Vis(vis_item) = &item.0.inner else { continue };
+		let VisItemInner::Module(mod_) = &vis_item.inner else { continue };
+
+		// Note: Since we can't evaluate `cfg`s, we use all possible paths.
+		//       If they're all conditional, the module might also not
+		//       use any of them, so we also use it's default path.
+		let path_attrs = self::find_path_attrs(&item.0.attrs)?;
+		let is_cfg = dir.is_cfg || path_attrs.iter().any(|attr| attr.is_cfg);
+		let mut paths = path_attrs
+			.iter()
+			.map(|attr| Some(&*attr.path))
+			.collect::<Vec<_>>();
+		if path_attrs.iter().all(|attr| attr.is_cfg) {
+			paths.push(None);
+		}
+
+		for path in paths {
+			match &mod_.inner {
+				ModuleInner::Def(def) => {
+					let dir = match path {
+						// Note: On inline modules, `#[path = ...]` specifies the
+						//       module's directory, not a file.
+						Some(path) => ModDir {
+							path: dir.path.join(path),
+							relative: None,
+							is_cfg,
+						},
+						None => ModDir {
+							path: dir.mods_path().join(self::mod_name(mod_)),
+							relative: None,
+							is_cfg,
+						},
+					};
+
+					if let Some(items) = &def.0.value.inner {
+						self::find_in_items(&dir, items, modules)?;
+					}
+				},
+				ModuleInner::None(_) => {
+					let module = self::mod_file(dir, mod_, path)?;
+					if is_cfg && !module
+						.path
+						.try_exists()
+						.context("Unable to check if file exists")? {
+						tracing::debug!("Skipping conditional module {:?}, file doesn't exist", module.path);
+						continue;
+					}
+
+					modules.push(module);
+				},
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Returns an out-of-line module's file
+fn mod_file(dir: &ModDir, mod_: &Module, path_attr: Option<&str>) -> Result<SourceFile, AppError> {
+	// If it had a `#[path = ...]` attribute, use that
+	if let Some(path) = path_attr {
+		return Ok(
+			SourceFile { path: dir.path.join(path), is_mod_rs: true, },
+		);
+	}
+
+	// Otherwise, try `<name>/mod.rs` first
+	let name = self::mod_name(mod_);
+	let mods_path = dir.mods_path();
+	let mod_rs_path = mods_path.join(&*name).join("mod.rs");
+	let file = match mod_rs_path
+		.try_exists()
+		.context("Unable to check if file exists")? {
+		true => SourceFile { path: mod_rs_path, is_mod_rs: true, },
+		// If it fails, try the new module system
+		false => SourceFile {
+			path: mods_path.join(format!("{name}.rs")),
+			is_mod_rs: false,
+		},
+	};
+
+	Ok(file)
+}
+
+/// Returns a module's name
+fn mod_name(mod_: &Module) -> String {
+	match &mod_.ident {
+		Identifier::Raw(ident) => ident.1
+			.str()
+			.strip_prefix("r#")
+			.expect("Raw identified didn't start with `r#`")
+			.to_owned(),
+		Identifier::NonKw(ident) => ident.0.1.str().into_owned(),
+	}
+}
+
+/// `#[path = ...]` attribute
+#[derive(Clone, Debug)]
+struct PathAttr<'a> {
+	path:   Cow<'a, str>,
+
+	/// Whether this attribute was within a `cfg_attr`
+	is_cfg: bool,
+}
+
+/// Finds all `#[path = ...]` attributes, including those within `cfg_attr`s.
+fn find_path_attrs<'a>(
+	attrs: impl IntoIterator<Item = &'a OuterAttrOrDocComment>,
+) -> Result<Vec<PathAttr<'a>>, AppError> {
+	let mut path_attrs = vec![];
+	for attr in attrs {
+		let Some(attr) = attr.try_as_attr_ref() else { continue };
+		let Some(meta) = attr.open.value.try_as_meta_ref() else { continue };
+		self::find_path_attrs_in_meta(meta, false, &mut path_attrs)?;
+	}
+
+	Ok(path_attrs)
+}
+
+/// Finds all `#[path = ...]` attributes in a meta item
+fn find_path_attrs_in_meta<'a>(
+	meta: &'a MetaItem,
+	is_cfg: bool,
+	path_attrs: &mut Vec<PathAttr<'a>>,
+) -> Result<(), AppError> {
+	match meta {
+		MetaItem::EqExpr(meta) if meta.path.is_str("path") => {
+			let path = self::str_contents(&meta.expr).context(
+				"Expected a string literal expression in `#[path = ...]` attribute",
+			)?;
+			path_attrs.push(PathAttr { path, is_cfg });
+		},
+
+		// Note: The first item of a `cfg_attr` is the predicate, which we
+		//       can't evaluate, so we just check all the attributes after it.
+		MetaItem::Seq(meta) if meta.path.is_str("cfg_attr") => {
+			let Some(seq) = &meta.seq.value else {
+				bail!("Malformed `#[cfg_attr(...)]` attribute")
+			};
+			for attr in seq.0.values().skip(1) {
+				// Note: If it's not a meta item, it can't be `path` nor `cfg_attr`.
+				let Some(meta) = MetaItemInner::try_as_meta_ref(attr) else { continue };
+				self::find_path_attrs_in_meta(meta, true, path_attrs)?;
+			}
+		},
+
+		_ if meta.path().is_str("path") => bail!("Malformed `#[path = ...]` attribute"),
+		_ => (),
+	}
+
+	Ok(())
+}
+
+/// Returns the contents of a string or raw string literal expression
+fn str_contents(expr: &Expression) -> Option<Cow<'_, str>> {
+	match expr.as_string_literal() {
+		Some(literal) => Some(literal.contents()),
+		None => expr
+			.as_raw_string_literal()
+			.map(|literal| literal.contents()),
+	}
+}
@@ -0,0 +1,128 @@
+//! Worker pool
+
+// Imports
+use {
+	core::num::NonZeroUsize,
+	std::{panic::{self, AssertUnwindSafe}, sync::{Condvar, Mutex}, thread},
+};
+
+/// Stack size of each worker.
+///
+/// Note: Parsing and formatting are heavily recursive, so the default
+///       stack size for spawned threads isn't enough.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Runs `f` on all `items` using `jobs` worker threads.
+///
+/// `f` may return more items to process, which are ordered right after
+/// the item that returned them.
+/// Results are returned in this order, regardless of when each item finished.
+///
+/// Note: Since the ast arenas are thread-local, each item should be fully
+///       parsed and formatted within `f`, with only it's results leaving it.
+pub fn run<T, R, F>(items: Vec<T>, jobs: NonZeroUsize, f: F) -> Vec<(T, R)>
+where
+	T: Send,
+	R: Send,
+	F: Fn(&T) -> (R, Vec<T>) + Sync,
+{
+	let state = Mutex::new(State {
+		queue: items
+			.into_iter()
+			.enumerate()
+			.rev()
+			.map(|(idx, item)| (vec![idx], item))
+			.collect(),
+		running: 0,
+		panicked: false,
+		results: vec![],
+	});
+	let cond = Condvar::new();
+
+	thread::scope(|s| {
+		for _ in 0..jobs.get() {
+			thread::Builder::new()
+				.stack_size(STACK_SIZE)
+				.spawn_scoped(s, || self::worker(&state, &cond, &f))
+				.expect("Unable to spawn worker thread");
+		}
+	});
+
+	let mut results = state.into_inner().expect("Poisoned").results;
+	results
+		.sort_unstable_by(|(lhs, ..), (rhs, ..)| lhs.cmp(rhs));
+	results
+		.into_iter()
+		.map(|(_, item, result)| (item, result))
+		.collect()
+}
+
+/// Pool state
+struct State<T, R> {
+	/// Items left to process, alongside their order.
+	///
+	/// The next item to process is at the end.
+	queue:    Vec<(Vec<usize>, T)>,
+
+	/// Number of items currently being processed
+	running:  usize,
+
+	/// Whether any worker panicked
+	panicked: bool,
+
+	/// Results, alongside their order
+	results:  Vec<(Vec<usize>, T, R)>,
+}
+
+/// Runs a worker until there are no more items to process
+fn worker<T, R, F>(state: &Mutex<State<T, R>>, cond: &Condvar, f: &F)
+where
+	F: Fn(&T) -> (R, Vec<T>),
+{
+	let lock = || state.lock().expect("Poisoned");
+	let mut state = lock();
+	loop {
+		if state.panicked {
+			break;
+		}
+
+		let Some((order, item)) = state.queue.pop() else {
+			match state.running {
+				0 => break,
+				_ => {
+					state = cond.wait(state).expect("Poisoned");
+					continue;
+				},
+			}
+		};
+		state.running += 1;
+		drop(state);
+
+		let res = panic::catch_unwind(AssertUnwindSafe(|| f(&item)));
+
+		state = lock();
+		state.running -= 1;
+		match res {
+			Ok((result, children)) => {
+				for (idx, child) in children.into_iter().enumerate().rev() {
+					let mut child_order = order.clone();
+					child_order.push(idx);
+					state.queue.push((child_order, child));
+				}
+				state.results.push((order, item, result));
+				cond.notify_all();
+			},
+			// Note: If we panicked, we must still wake up the other workers,
+			//       else they'd be waiting for us forever.
+			Err(payload) => {
+				state.panicked = true;
+				drop(state);
+				cond.notify_all();
+				panic::resume_unwind(payload);
+			},
+		}
+	}
+
+	// Note: Wake up any other workers so they can exit too.
+	cond.notify_all();
+}
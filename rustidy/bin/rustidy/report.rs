@@ -0,0 +1,316 @@
+//! Check reports
+
+// Imports
+use {
+	app_error::{AppError, Context},
+	core::fmt::Write as _,
+	rustidy::{CrateParseError, Diff, diff::DiffLine},
+	std::{io::{self, Write}, path::{Component, Path}},
+};
+
+/// Report format
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(clap::ValueEnum)]
+pub enum ReportFormat {
+	/// Json
+	Json,
+
+	/// Checkstyle xml
+	Checkstyle,
+
+	/// Sarif 2.1
+	Sarif,
+}
+
+/// Check report
+#[derive(Default, Debug)]
+#[derive(serde::Serialize)]
+pub struct Report {
+	pub files: Vec<FileReport>,
+}
+
+impl Report {
+	/// Writes this report in `format` to `writer`
+	pub fn write(&self, format: ReportFormat, writer: impl Write) -> Result<(), AppError> {
+		match format {
+			ReportFormat::Json => self.write_json(writer),
+			ReportFormat::Checkstyle => self.write_checkstyle(writer),
+			ReportFormat::Sarif => self.write_sarif(writer),
+		}
+	}
+
+	fn write_json(&self, mut writer: impl Write) -> Result<(), AppError> {
+		serde_json::to_writer_pretty(&mut writer, self)
+			.context("Unable to serialize report")?;
+		writeln!(writer)
+			.context("Unable to write report")
+	}
+
+	fn write_checkstyle(&self, mut writer: impl Write) -> Result<(), AppError> {
+		let res: io::Result<()> = try {
+			writeln!(writer, "<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
+			writeln!(writer, "<checkstyle version=\"4.3\">")?;
+			for file in &self.files {
+				writeln!(writer, "\t<file name=\"{}\">", self::escape_xml(&file.path))?;
+				for err in &file.parse_errors {
+					writeln!(
+						writer,
+						"\t\t<error line=\"{}\" column=\"{}\" severity=\"error\" message=\"{}\" source=\"rustidy\" />",
+						err.line,
+						err.column,
+						self::escape_xml(&err.message)
+					)?;
+				}
+				for change in &file.changes {
+					writeln!(
+						writer,
+						"\t\t<error line=\"{}\" severity=\"warning\" message=\"{}\" source=\"rustidy\" />",
+						change.start_line,
+						self::escape_xml(&change.message())
+					)?;
+				}
+				writeln!(writer, "\t</file>")?;
+			}
+			writeln!(writer, "</checkstyle>")?;
+		};
+
+		res.context("Unable to write report")
+	}
+
+	fn write_sarif(&self, mut writer: impl Write) -> Result<(), AppError> {
+		let results = self
+			.files
+			.iter()
+			.flat_map(|file| {
+				let parse_errors = file
+					.parse_errors
+					.iter()
+					.map(|err| serde_json::json!({
+						"ruleId": "parse-error",
+						"level": "error",
+						"message": { "text": err.message },
+						"locations": [{
+							"physicalLocation": {
+								"artifactLocation": { "uri": file.uri },
+								"region": { "startLine": err.line, "startColumn": err.column },
+							},
+						}],
+					}));
+				let changes = file
+					.changes
+					.iter()
+					.map(|change| serde_json::json!({
+						"ruleId": "not-formatted",
+						"level": "warning",
+						"message": { "text": change.message() },
+						"locations": [{
+							"physicalLocation": {
+								"artifactLocation": { "uri": file.uri },
+								"region": { "startLine": change.start_line, "endLine": change.end_line },
+							},
+						}],
+					}));
+
+				parse_errors.chain(changes)
+			})
+			.collect::<Vec<_>>();
+
+		let sarif = serde_json::json!({
+			"$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+			"version": "2.1.0",
+			"runs": [{
+				"tool": {
+					"driver": {
+						"name": "rustidy",
+						"version": env!("CARGO_PKG_VERSION"),
+						"informationUri": env!("CARGO_PKG_REPOSITORY"),
+						"rules": [
+							{
+								"id": "not-formatted",
+								"shortDescription": { "text": "Code is not formatted" },
+							},
+							{
+								"id": "parse-error",
+								"shortDescription": { "text": "Unable to parse file" },
+							},
+						],
+					},
+				},
+				"results": results,
+			}],
+		});
+
+		serde_json::to_writer_pretty(&mut writer, &sarif)
+			.context("Unable to serialize report")?;
+		writeln!(writer)
+			.context("Unable to write report")
+	}
+}
+
+/// File report
+#[derive(Debug)]
+#[derive(serde::Serialize)]
+pub struct FileReport {
+	pub path:         String,
+	#[serde(skip)]
+	pub uri:          String,
+	pub changes:      Vec<Change>,
+	pub parse_errors: Vec<ParseError>,
+}
+
+impl FileReport {
+	/// Creates an empty report for a file
+	pub fn new(path: &Path) -> Self {
+		Self {
+			path: path.display().to_string(),
+			uri: self::path_uri(path),
+			changes: vec![],
+			parse_errors: vec![],
+		}
+	}
+
+	/// Adds all changes between `input` and it's formatted `output`
+	pub fn add_changes(&mut self, input: &str, output: &str) {
+		let diff = Diff::new(input, output, 0);
+		for hunk in diff.hunks {
+			let mut original = String::new();
+			let mut formatted = String::new();
+			for line in hunk.lines {
+				match line {
+					DiffLine::Context(_) => (),
+					DiffLine::Removed(line) => original.push_str(line),
+					DiffLine::Added(line) => formatted.push_str(line),
+				}
+			}
+
+			self.changes.push(Change {
+				start_line: hunk.old_start + 1,
+				end_line: hunk.old_start + hunk.old_len.max(1),
+				original,
+				formatted,
+			});
+		}
+	}
+
+	/// Adds all diagnostics of a parse error
+	pub fn add_parse_error(&mut self, err: &CrateParseError) {
+		for diagnostic in &err.diagnostics {
+			let (line, column) = match diagnostic.primary_label() {
+				Some(label) => (label.span.start.line, label.span.start.column),
+				None => (err.loc.line + 1, err.loc.column + 1),
+			};
+
+			self.parse_errors.push(ParseError {
+				line,
+				column,
+				message: diagnostic.message.clone(),
+			});
+		}
+	}
+}
+
+/// Changed lines.
+///
+/// Lines are 1-indexed and refer to the original file.
+/// If lines were only added, both `start_line` and `end_line` will
+/// be the line they're inserted before.
+#[derive(Debug)]
+#[derive(serde::Serialize)]
+pub struct Change {
+	pub start_line: usize,
+	pub end_line:   usize,
+	pub original:   String,
+	pub formatted:  String,
+}
+
+impl Change {
+	/// Returns a message describing this change
+	fn message(&self) -> String {
+		match self.formatted.is_empty() {
+			true => "Not formatted, lines should be removed"
+				.to_owned(),
+			false => format!("Not formatted, should be:\n{}", self.formatted),
+		}
+	}
+}
+
+/// Parse error.
+///
+/// `line` and `column` are 1-indexed.
+#[derive(Debug)]
+#[derive(serde::Serialize)]
+pub struct ParseError {
+	pub line:    usize,
+	pub column:  usize,
+	pub message: String,
+}
+
+/// Returns the uri of a path.
+///
+/// Relative paths are returned as a relative uri reference, while
+/// absolute paths are returned as a `file://` uri.
+fn path_uri(path: &Path) -> String {
+	let mut uri = String::new();
+	for component in path.components() {
+		match component {
+			Component::Prefix(prefix) => {
+				// Note: Prefixes are windows drives, such as `C:`, which are kept as-is
+				uri.push_str("file:///");
+				uri
+					.push_str(&prefix.as_os_str().to_string_lossy());
+			},
+			Component::RootDir => match uri.is_empty() {
+				true => uri.push_str("file:///"),
+				false => uri.push('/'),
+			},
+			Component::CurDir => (),
+			Component::ParentDir => {
+				self::push_uri_separator(&mut uri);
+				uri.push_str("..");
+			},
+			Component::Normal(segment) => {
+				self::push_uri_separator(&mut uri);
+				self::push_uri_segment(&mut uri, &segment.to_string_lossy());
+			},
+		}
+	}
+
+	uri
+}
+
+/// Pushes a separator to `uri`, unless it's empty or already ends with one
+fn push_uri_separator(uri: &mut String) {
+	if !uri.is_empty() && !uri.ends_with('/') {
+		uri.push('/');
+	}
+}
+
+/// Pushes a percent-encoded path segment to `uri`
+fn push_uri_segment(uri: &mut String, segment: &str) {
+	for &byte in segment.as_bytes() {
+		match byte {
+			b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => uri.push(char::from(byte)),
+			_ => write!(uri, "%{byte:02X}")
+				.expect("Writing to a string can't fail"),
+		}
+	}
+}
+
+/// Escapes a string for use in an xml attribute
+fn escape_xml(s: &str) -> String {
+	let mut escaped = String::with_capacity(s.len());
+	for ch in s.chars() {
+		match ch {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&apos;"),
+			'\n' => escaped.push_str("&#10;"),
+			'\t' => escaped.push_str("&#9;"),
+			_ => escaped.push(ch),
+		}
+	}
+
+	escaped
+}
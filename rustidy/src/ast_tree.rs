@@ -0,0 +1,561 @@
+//! Ast tree
+
+// Imports
+use {
+	core::{fmt::{self, Write}, ops::Range},
+	parse::ParserLoc,
+	serde::ser,
+	util::{ColumnUnit, LineCol, LineIndex},
+};
+
+/// Returns an indented tree of an ast.
+///
+/// Each node is shown with it's type name (or enum variant) and the range it spans
+/// (not including whitespace), with tokens and whitespace (alongside any comments) shown
+/// inline. Ranges are of the printed output of the ast, which is the input itself, unless
+/// it was formatted.
+pub fn ast_tree<T: serde::Serialize>(ast: &T) -> Result<String, AstTreeError> {
+	let mut text = String::new();
+	let node = ast.serialize(Serializer { text: &mut text })?;
+
+	let line_index = LineIndex::new(text);
+	let mut output = String::new();
+	node.render(&line_index, None, 0, &mut output);
+
+	Ok(output)
+}
+
+/// Ast tree error
+#[derive(Debug)]
+pub struct AstTreeError(String);
+
+impl fmt::Display for AstTreeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl std::error::Error for AstTreeError {}
+
+impl ser::Error for AstTreeError {
+	fn custom<T: fmt::Display>(msg: T) -> Self {
+		Self(msg.to_string())
+	}
+}
+
+/// Node
+#[derive(Debug)]
+struct Node {
+	kind:  NodeKind,
+
+	/// Range of all non-whitespace strings in this node
+	range: Option<Range<usize>>,
+}
+
+impl Node {
+	/// Creates a node from it's children
+	fn new(kind: NodeKind) -> Self {
+		let children: Box<dyn Iterator<Item = &Self>> = match &kind {
+			NodeKind::Named { fields, .. } => Box::new(fields.iter().map(|(_, node)| node)),
+			NodeKind::Seq(nodes) | NodeKind::Tuple(nodes) => Box::new(nodes.iter()),
+			_ => Box::new(core::iter::empty()),
+		};
+		let mut ranges = children.filter_map(|node| node.range.clone());
+		let range = ranges.next().map(|first| {
+			let end = ranges.last().map_or(first.end, |last| last.end);
+			first.start..end
+		});
+
+		Self { kind, range }
+	}
+
+	/// Creates a node without any strings
+	const fn empty(kind: NodeKind) -> Self {
+		Self { kind, range: None }
+	}
+
+	/// Returns if this node shouldn't be shown
+	fn is_hidden(&self) -> bool {
+		match &self.kind {
+			NodeKind::Str(s) | NodeKind::Whitespace { s, .. } => s.is_empty(),
+			NodeKind::Value(_) => false,
+			NodeKind::Named { fields, .. } => fields.iter().all(|(_, node)| node.is_hidden()),
+			NodeKind::Seq(nodes) | NodeKind::Tuple(nodes) => nodes.iter().all(Self::is_hidden),
+			NodeKind::Empty => true,
+		}
+	}
+
+	/// Appends all strings of this node, including whitespace, to `output`
+	fn write_str(&self, output: &mut String) {
+		match &self.kind {
+			NodeKind::Str(s) | NodeKind::Whitespace { s, .. } => output.push_str(s),
+			NodeKind::Named { fields, .. } => for (_, node) in fields {
+				node.write_str(output);
+			},
+			NodeKind::Seq(nodes) | NodeKind::Tuple(nodes) => for node in nodes {
+				node.write_str(output);
+			},
+			NodeKind::Value(_) | NodeKind::Empty => (),
+		}
+	}
+
+	/// Renders this node
+	fn render(
+		&self,
+		line_index: &LineIndex,
+		label: Option<&str>,
+		depth: usize,
+		output: &mut String,
+	) {
+		if self.is_hidden() {
+			return;
+		}
+
+		// Note: Sequences and tuples don't get their own line, their elements
+		//       are shown at the same depth, with the index in their label.
+		match &self.kind {
+			NodeKind::Seq(nodes) => {
+				for (idx, node) in nodes.iter().enumerate() {
+					let label = format!("{}[{idx}]", label.unwrap_or_default());
+					node
+						.render(line_index, Some(&label), depth, output);
+				}
+				return;
+			},
+			NodeKind::Tuple(nodes) => {
+				for (idx, node) in nodes.iter().enumerate() {
+					let label = match label {
+						Some(label) => format!("{label}.{idx}"),
+						None => idx.to_string(),
+					};
+					node
+						.render(line_index, Some(&label), depth, output);
+				}
+				return;
+			},
+			_ => (),
+		}
+
+		for _ in 0..depth {
+			output.push_str("  ");
+		}
+		if let Some(label) = label {
+			output.push_str(label);
+			output.push_str(": ");
+		}
+
+		match &self.kind {
+			NodeKind::Str(s) => {
+				_ = write!(output, "{s:?}");
+				self::render_range(self.range.clone(), line_index, output);
+			},
+			NodeKind::Whitespace { s, range } => {
+				output.push_str("Whitespace");
+				self::render_range(Some(range.clone()), line_index, output);
+				_ = write!(output, " {s:?}");
+			},
+			NodeKind::Value(value) => output.push_str(value),
+			NodeKind::Named { name, fields } => {
+				output.push_str(name);
+				self::render_range(self.range.clone(), line_index, output);
+
+				// If we only have a single string, with any whitespace, show it inline
+				let fields = fields
+					.iter()
+					.filter(|(_, node)| !node.is_hidden())
+					.collect::<Vec<_>>();
+				let (whitespace, rest) = fields
+					.iter()
+					.copied()
+					.partition::<Vec<_>, _>(
+						|(_, node)| matches!(node.kind, NodeKind::Whitespace { .. }),
+					);
+				if let [(_, node)] = *rest && let NodeKind::Str(s) = &node.kind {
+					_ = write!(output, " {s:?}");
+					for (_, node) in whitespace {
+						let mut s = String::new();
+						node.write_str(&mut s);
+						_ = write!(output, " (whitespace {s:?})");
+					}
+					output.push('\n');
+					return;
+				}
+
+				output.push('\n');
+				let is_tuple = fields.len() > 1 && fields.iter().all(|(field, _)| field.is_none());
+				for (idx, (field, node)) in fields.iter().enumerate() {
+					let label = match field {
+						Some(field) => Some((*field).to_owned()),
+						None => is_tuple.then(|| idx.to_string()),
+					};
+					node
+						.render(line_index, label.as_deref(), depth + 1, output);
+				}
+				return;
+			},
+			NodeKind::Seq(_) | NodeKind::Tuple(_) | NodeKind::Empty => unreachable!(),
+		}
+		output.push('\n');
+	}
+}
+
+/// Renders a range, if any, as ` <start>..<end>`
+fn render_range(
+	range: Option<Range<usize>>,
+	line_index: &LineIndex,
+	output: &mut String,
+) {
+	let Some(range) = range else { return };
+	let [start, end] = [range.start, range.end].map(|pos| {
+		let LineCol { line, column } = line_index
+			.line_col(util::AstPos(pos), ColumnUnit::Bytes);
+		ParserLoc { line, column }
+	});
+	_ = write!(output, " {start}..{end}");
+}
+
+/// Node kind
+#[derive(Debug)]
+enum NodeKind {
+	/// String
+	Str(String),
+
+	/// Whitespace, alongside any comments
+	Whitespace {
+		s:     String,
+		range: Range<usize>,
+	},
+
+	/// Any other value, such as a number
+	Value(String),
+
+	/// Named node, such as a struct or enum variant
+	Named {
+		name:   String,
+		fields: Vec<(Option<&'static str>, Node)>,
+	},
+
+	/// Sequence
+	Seq(Vec<Node>),
+
+	/// Tuple
+	Tuple(Vec<Node>),
+
+	/// Nothing, such as `None` or `()`
+	Empty,
+}
+
+/// Serializer into a node
+struct Serializer<'a> {
+	/// All strings serialized so far
+	text: &'a mut String,
+}
+
+impl<'a> Serializer<'a> {
+	/// Creates a string node
+	fn str(self, s: String) -> Node {
+		let start = self.text.len();
+		self.text.push_str(&s);
+		let range = start..self.text.len();
+
+		Node { kind: NodeKind::Str(s), range: Some(range) }
+	}
+
+	/// Creates a compound node
+	const fn compound(self, kind: CompoundKind) -> Compound<'a> {
+		Compound { text: self.text, kind, fields: vec![] }
+	}
+}
+
+impl<'a> ser::Serializer for Serializer<'a> {
+	type Error = AstTreeError;
+	type Ok = Node;
+	type SerializeMap = Compound<'a>;
+	type SerializeSeq = Compound<'a>;
+	type SerializeStruct = Compound<'a>;
+	type SerializeStructVariant = Compound<'a>;
+	type SerializeTuple = Compound<'a>;
+	type SerializeTupleStruct = Compound<'a>;
+	type SerializeTupleVariant = Compound<'a>;
+
+	serialize_values! {
+		serialize_bool: bool,
+		serialize_i8: i8,
+		serialize_i16: i16,
+		serialize_i32: i32,
+		serialize_i64: i64,
+		serialize_u8: u8,
+		serialize_u16: u16,
+		serialize_u32: u32,
+		serialize_u64: u64,
+		serialize_f32: f32,
+		serialize_f64: f64,
+	}
+
+	fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+		Ok(self.str(v.to_string()))
+	}
+
+	fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+		Ok(self.str(v.to_owned()))
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+		Ok(Node::empty(NodeKind::Value(format!("{v:?}"))))
+	}
+
+	fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+		Ok(Node::empty(NodeKind::Empty))
+	}
+
+	fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+	where
+		T: ?Sized + serde::Serialize,
+	{
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+		Ok(Node::empty(NodeKind::Empty))
+	}
+
+	fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+		Ok(Node::empty(
+			NodeKind::Named { name: name.to_owned(), fields: vec![] },
+		))
+	}
+
+	fn serialize_unit_variant(
+		self,
+		name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+	) -> Result<Self::Ok, Self::Error> {
+		Ok(Node::empty(NodeKind::Named {
+			name: format!("{name}::{variant}"),
+			fields: vec![]
+		}))
+	}
+
+	fn serialize_newtype_struct<T>(
+		self,
+		name: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Self::Error>
+	where
+		T: ?Sized + serde::Serialize,
+	{
+		// Note: Whitespace is shown as a single string, with it's comments, and
+		//       isn't included in the range of it's parents.
+		if name == "Whitespace" {
+			let start = self.text.len();
+			let node = value.serialize(self)?;
+			let mut s = String::new();
+			node.write_str(&mut s);
+			let range = start..start + s.len();
+
+			return Ok(Node::empty(NodeKind::Whitespace { s, range }));
+		}
+
+		let node = value.serialize(self)?;
+		Ok(Node::new(NodeKind::Named {
+			name: name.to_owned(),
+			fields: vec![(None, node)]
+		}))
+	}
+
+	fn serialize_newtype_variant<T>(
+		self,
+		name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Self::Error>
+	where
+		T: ?Sized + serde::Serialize,
+	{
+		let node = value.serialize(self)?;
+		Ok(Node::new(NodeKind::Named {
+			name: format!("{name}::{variant}"),
+			fields: vec![(None, node)]
+		}))
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+		Ok(self.compound(CompoundKind::Seq))
+	}
+
+	fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+		Ok(self.compound(CompoundKind::Tuple))
+	}
+
+	fn serialize_tuple_struct(
+		self,
+		name: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleStruct, Self::Error> {
+		Ok(
+			self
+				.compound(CompoundKind::Named(name.to_owned())),
+		)
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleVariant, Self::Error> {
+		Ok(self.compound(
+			CompoundKind::Named(format!("{name}::{variant}")),
+		))
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+		Ok(self.compound(CompoundKind::Seq))
+	}
+
+	fn serialize_struct(
+		self,
+		name: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStruct, Self::Error> {
+		Ok(
+			self
+				.compound(CompoundKind::Named(name.to_owned())),
+		)
+	}
+
+	fn serialize_struct_variant(
+		self,
+		name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStructVariant, Self::Error> {
+		Ok(self.compound(
+			CompoundKind::Named(format!("{name}::{variant}")),
+		))
+	}
+}
+
+/// Implements serializing values that are shown as-is
+macro serialize_values($($serialize:ident: $Ty:ty),* $(,)?) {
+	$(
+		fn $serialize(self, v: $Ty) -> Result<Self::Ok, Self::Error> {
+			Ok(Node::empty(NodeKind::Value(v.to_string())))
+		}
+	)*
+}
+
+/// Compound node kind
+enum CompoundKind {
+	Named(String),
+	Seq,
+	Tuple,
+}
+
+/// Compound node serializer
+struct Compound<'a> {
+	text:   &'a mut String,
+	kind:   CompoundKind,
+	fields: Vec<(Option<&'static str>, Node)>,
+}
+
+impl Compound<'_> {
+	/// Adds a field
+	fn add<T>(&mut self, field: Option<&'static str>, value: &T) -> Result<(), AstTreeError>
+	where
+		T: ?Sized + serde::Serialize,
+	{
+		let node = value.serialize(Serializer { text: self.text })?;
+		self.fields.push((field, node));
+
+		Ok(())
+	}
+
+	/// Finishes this node
+	fn finish(self) -> Node {
+		let fields = self.fields;
+		let nodes = |fields: Vec<_>| fields
+			.into_iter()
+			.map(|(_, node)| node)
+			.collect();
+		let kind = match self.kind {
+			CompoundKind::Named(name) => NodeKind::Named { name, fields },
+			CompoundKind::Seq => NodeKind::Seq(nodes(fields)),
+			CompoundKind::Tuple => NodeKind::Tuple(nodes(fields)),
+		};
+
+		Node::new(kind)
+	}
+}
+
+macro impl_compound {
+	($Trait:ident, $serialize:ident) => {
+		impl ser::$Trait for Compound<'_> {
+			type Error = AstTreeError;
+			type Ok = Node;
+
+			fn $serialize<T>(&mut self, value: &T) -> Result<(), Self::Error>
+			where
+				T: ?Sized + serde::Serialize,
+			{
+				self.add(None, value)
+			}
+
+			fn end(self) -> Result<Self::Ok, Self::Error> {
+				Ok(self.finish())
+			}
+		}
+	},
+
+	($Trait:ident, $serialize:ident, key) => {
+		impl ser::$Trait for Compound<'_> {
+			type Error = AstTreeError;
+			type Ok = Node;
+
+			fn $serialize<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+			where
+				T: ?Sized + serde::Serialize,
+			{
+				self.add(Some(key), value)
+			}
+
+			fn end(self) -> Result<Self::Ok, Self::Error> {
+				Ok(self.finish())
+			}
+		}
+	},
+}
+
+impl_compound! { SerializeSeq, serialize_element }
+impl_compound! { SerializeTuple, serialize_element }
+impl_compound! { SerializeTupleStruct, serialize_field }
+impl_compound! { SerializeTupleVariant, serialize_field }
+impl_compound! { SerializeStruct, serialize_field, key }
+impl_compound! { SerializeStructVariant, serialize_field, key }
+
+impl ser::SerializeMap for Compound<'_> {
+	type Error = AstTreeError;
+	type Ok = Node;
+
+	fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+	where
+		T: ?Sized + serde::Serialize,
+	{
+		self.add(None, key)
+	}
+
+	fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+	where
+		T: ?Sized + serde::Serialize,
+	{
+		self.add(None, value)
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(self.finish())
+	}
+}
@@ -0,0 +1,773 @@
+//! Diagnostics
+
+// Imports
+use {
+	core::{fmt::Write, ops::Range},
+	parse::ParseErrorTree,
+	std::{collections::BTreeSet, path::{Path, PathBuf}},
+	util::{AstPos, AstRange, ColumnUnit, LineIndex},
+};
+
+/// Width of tabs when rendering source lines
+const TAB_WIDTH: usize = 4;
+
+/// Diagnostic
+#[derive(Clone, Debug)]
+#[derive(serde::Serialize)]
+pub struct Diagnostic {
+	/// Level
+	pub level:   DiagnosticLevel,
+
+	/// Message
+	pub message: String,
+
+	/// File
+	pub file:    PathBuf,
+
+	/// Labels.
+	///
+	/// The primary label, if any, is always the first.
+	pub labels:  Vec<DiagnosticLabel>,
+
+	/// Notes
+	pub notes:   Vec<String>,
+}
+
+impl Diagnostic {
+	/// Creates a diagnostic from a parse error.
+	///
+	/// Only the deepest errors are reported, alongside everything that was
+	/// expected at that position and the innermost construct being parsed.
+	#[must_use]
+	pub fn from_parse_error(
+		level: DiagnosticLevel,
+		line_index: &LineIndex,
+		file: &Path,
+		err: &ParseErrorTree,
+	) -> Self {
+		let input = line_index.input().as_str();
+		let pos = self::deepest_pos(err, 0);
+		let mut summary = ErrorSummary::default();
+		summary.visit(input, err, 0, pos, &mut vec![]);
+
+		let found = Found::new(input, pos);
+		let expected = match &*summary.expected {
+			[] => None,
+			[expected] => Some(format!("expected {expected}")),
+			[lhs, rhs] => Some(format!("expected one of {lhs} or {rhs}")),
+			[expected @ .., last] => Some(
+				format!("expected one of {}, or {last}", expected.join(", ")),
+			),
+		};
+		let mut messages = summary
+			.messages
+			.iter()
+			.map(|msg| self::lowercase_first(msg));
+		let (message, label_message) = match expected {
+			Some(expected) => (format!("{expected}, found {}", found.name), Some(expected)),
+			None => match messages.next() {
+				Some(message) => (message, None),
+				None => (format!("unexpected {}", found.name), None),
+			},
+		};
+
+		let mut labels = vec![DiagnosticLabel {
+			primary: true,
+			span:    DiagnosticSpan::new(line_index, found.range),
+			message: label_message,
+		}];
+		if let Some((name, range)) = summary.construct() {
+			let start = range.start.0 + self::whitespace_len(&input[range.start.0..pos]);
+			let end = start + input[start..pos].trim_end().len();
+			if start < end {
+				labels.push(DiagnosticLabel {
+					primary: false,
+					span: DiagnosticSpan::new(line_index, start..end),
+					message: Some(format!("while parsing {name}")),
+				});
+			}
+		}
+
+		Self {
+			level,
+			message,
+			file: file.to_path_buf(),
+			labels,
+			notes: messages.collect(),
+		}
+	}
+
+	/// Returns the primary label of this diagnostic
+	#[must_use]
+	pub fn primary_label(&self) -> Option<&DiagnosticLabel> {
+		self
+			.labels
+			.first()
+			.filter(|label| label.primary)
+	}
+
+	/// Renders this diagnostic, with a snippet of the source for each label.
+	///
+	/// `line_index` must be of the input this diagnostic was created for.
+	/// If `color` is true, ansi escape codes will be used.
+	#[must_use]
+	pub fn render(&self, line_index: &LineIndex, color: bool) -> String {
+		let level_style = match self.level {
+			DiagnosticLevel::Error => Style::Error,
+			DiagnosticLevel::Warning => Style::Warning,
+		};
+
+		let mut output = Output { s: String::new(), color };
+		output.push(self.level.as_str(), level_style);
+		output
+			.push(&format!(": {}", self.message), Style::Bold);
+		output.s.push('\n');
+
+		let mut shown_lines = self
+			.labels
+			.iter()
+			.flat_map(
+				|label| [label.span.start.line, label.span.end.line],
+			)
+			.collect::<BTreeSet<_>>();
+		// Note: We show single lines between labels instead of eliding them.
+		let gaps = shown_lines
+			.iter()
+			.zip(shown_lines.iter().skip(1))
+			.filter(|&(&prev, &next)| next == prev + 2)
+			.map(|(&prev, _)| prev + 1)
+			.collect::<Vec<_>>();
+		shown_lines.extend(gaps);
+
+		let gutter_width = shown_lines
+			.last()
+			.map_or(0, |line| line.to_string().len());
+		let pad = " ".repeat(gutter_width);
+		let file = self.file.display();
+		output
+			.push(&format!("{pad}--> "), Style::Secondary);
+		match self.primary_label() {
+			Some(label) => _ = writeln!(output.s, "{file}:{}:{}", label.span.start.line, label.span.start.column),
+			None => _ = writeln!(output.s, "{file}"),
+		}
+
+		if !self.labels.is_empty() {
+			output
+				.push(&format!("{pad} |"), Style::Secondary);
+			output.s.push('\n');
+		}
+
+		let multi_line_labels = self
+			.labels
+			.iter()
+			.filter(
+				|label| label.span.start.line != label.span.end.line,
+			)
+			.collect::<Vec<_>>();
+		let snippet = Snippet {
+			line_index,
+			multi_line_labels: &multi_line_labels,
+			level_style,
+		};
+		let mut prev_line = None;
+		for &line in &shown_lines {
+			if prev_line
+				.is_some_and(|prev_line| line > prev_line + 1) {
+				output.push("...", Style::Secondary);
+				output.s.push('\n');
+			}
+			prev_line = Some(line);
+
+			output.push(
+				&format!("{line:>gutter_width$} |"),
+				Style::Secondary,
+			);
+			snippet.source_row(line).write(&mut output);
+			for row in snippet.annotation_rows(&self.labels, line) {
+				output
+					.push(&format!("{pad} |"), Style::Secondary);
+				row.write(&mut output);
+			}
+		}
+
+		if !self.notes.is_empty() {
+			output
+				.push(&format!("{pad} |"), Style::Secondary);
+			output.s.push('\n');
+		}
+		for note in &self.notes {
+			output
+				.push(&format!("{pad} = "), Style::Secondary);
+			output.push("note", Style::Bold);
+			_ = writeln!(output.s, ": {note}");
+		}
+
+		output.s
+	}
+}
+
+/// Diagnostic level
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticLevel {
+	Error,
+	Warning,
+}
+
+impl DiagnosticLevel {
+	/// Returns this level as a string
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Error => "error",
+			Self::Warning => "warning",
+		}
+	}
+}
+
+/// Diagnostic label
+#[derive(Clone, Debug)]
+#[derive(serde::Serialize)]
+pub struct DiagnosticLabel {
+	/// Whether this is the primary label
+	pub primary: bool,
+
+	/// Span
+	pub span:    DiagnosticSpan,
+
+	/// Message
+	pub message: Option<String>,
+}
+
+/// Diagnostic span
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize)]
+pub struct DiagnosticSpan {
+	/// Start (inclusive)
+	pub start: DiagnosticLoc,
+
+	/// End (exclusive)
+	pub end:   DiagnosticLoc,
+}
+
+impl DiagnosticSpan {
+	/// Creates a span from a range of an input
+	#[must_use]
+	pub fn new(line_index: &LineIndex, range: Range<usize>) -> Self {
+		Self {
+			start: DiagnosticLoc::new(line_index, range.start),
+			end: DiagnosticLoc::new(line_index, range.end),
+		}
+	}
+}
+
+/// Diagnostic location.
+///
+/// `line` and `column` are 1-indexed, and `column` is in bytes.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize)]
+pub struct DiagnosticLoc {
+	/// Byte offset
+	pub offset: usize,
+
+	/// Line
+	pub line:   usize,
+
+	/// Column
+	pub column: usize,
+}
+
+impl DiagnosticLoc {
+	/// Creates a location from a byte offset of an input
+	#[must_use]
+	pub fn new(line_index: &LineIndex, offset: usize) -> Self {
+		let loc = line_index
+			.line_col(AstPos(offset), ColumnUnit::Bytes);
+		Self {
+			offset,
+			line: loc.line + 1,
+			column: loc.column + 1,
+		}
+	}
+}
+
+/// Summary of a parse error, at it's deepest position
+#[derive(Default)]
+struct ErrorSummary<'a> {
+	/// Everything that was expected
+	expected:   Vec<String>,
+
+	/// All other messages
+	messages:   Vec<&'a str>,
+
+	/// Named constructs being parsed by all errors, from outermost to innermost
+	constructs: Option<Vec<(&'a str, AstRange)>>,
+}
+
+impl<'a> ErrorSummary<'a> {
+	/// Visits an error tree.
+	///
+	/// `pos` is the position the tree's messages refer to, and `path`
+	/// are all the named constructs being parsed by the tree.
+	fn visit(
+		&mut self,
+		input: &str,
+		err: &'a ParseErrorTree,
+		pos: usize,
+		deepest_pos: usize,
+		path: &mut Vec<(&'a str, AstRange)>,
+	) {
+		match err {
+			ParseErrorTree::Message(msg) => {
+				if pos != deepest_pos {
+					return;
+				}
+
+				match msg.strip_prefix("Expected ") {
+					Some(expected) => for expected in expected.split(" or ") {
+						self.push_expected(expected);
+					},
+					None => if !self.messages.contains(&&**msg) {
+						self.messages.push(msg);
+					},
+				}
+				self.add_path(path);
+			},
+			ParseErrorTree::Parse { name, range, source } => {
+				if self::deepest_pos(source, range.end.0) != deepest_pos {
+					return;
+				}
+
+				let Some(name) = name else {
+					return self
+						.visit(input, source, range.end.0, deepest_pos, path);
+				};
+
+				// Note: If nothing was parsed by this construct, we report
+				//       it as expected instead of going into it.
+				if input[range
+					.start.0..deepest_pos]
+					.trim()
+					.is_empty() {
+					self.push_expected(name);
+					self.add_path(path);
+					return;
+				}
+
+				path.push((name, *range));
+				self
+					.visit(input, source, range.end.0, deepest_pos, path);
+				path.pop();
+			},
+			ParseErrorTree::Multiple(errs) => for err in errs {
+				self
+					.visit(input, err, pos, deepest_pos, path);
+			},
+		}
+	}
+
+	/// Adds an expected item, if it wasn't already added
+	fn push_expected(&mut self, expected: &str) {
+		if !self
+			.expected
+			.iter()
+			.any(|other| other == expected) {
+			self.expected.push(expected.to_owned());
+		}
+	}
+
+	/// Adds the path of an error, keeping only the constructs shared with all other errors
+	fn add_path(&mut self, path: &[(&'a str, AstRange)]) {
+		match &mut self.constructs {
+			Some(constructs) => {
+				let len = constructs
+					.iter()
+					.zip(path)
+					.take_while(|(lhs, rhs)| lhs == rhs)
+					.count();
+				constructs.truncate(len);
+			},
+			None => self.constructs = Some(path.to_vec()),
+		}
+	}
+
+	/// Returns the innermost construct being parsed by all errors
+	fn construct(&self) -> Option<(&'a str, AstRange)> {
+		self.constructs.as_deref()?.last().copied()
+	}
+}
+
+/// Returns the deepest position of an error tree, where `pos` is the position
+/// the tree's messages refer to.
+fn deepest_pos(err: &ParseErrorTree, pos: usize) -> usize {
+	match err {
+		ParseErrorTree::Message(_) => pos,
+		ParseErrorTree::Parse { range, source, .. } => self::deepest_pos(source, range.end.0),
+		ParseErrorTree::Multiple(errs) => errs
+			.iter()
+			.map(|err| self::deepest_pos(err, pos))
+			.max()
+			.unwrap_or(pos),
+	}
+}
+
+/// Token found at the position of an error
+struct Found {
+	/// Name
+	name:  String,
+
+	/// Range
+	range: Range<usize>,
+}
+
+impl Found {
+	/// Gets the token found at `pos`
+	fn new(input: &str, pos: usize) -> Self {
+		let rest = &input[pos..];
+		if rest.trim().is_empty() {
+			// Note: We point right after the last token, instead of at the end of the input.
+			let pos = input.trim_end().len().min(pos);
+			return Self {
+				name: "end of file".to_owned(),
+				range: pos..pos,
+			};
+		}
+
+		let (name, len) = match rest.chars().next() {
+			// Note: Whitespace might span multiple lines, so we just point at it's start.
+			Some(ch) if ch.is_whitespace() => ("whitespace".to_owned(), 0),
+			Some(ch) if ch == '_' || ch.is_alphanumeric() => {
+				let len = rest
+					.find(|ch: char| ch != '_' && !ch.is_alphanumeric())
+					.unwrap_or(rest.len());
+				(format!("`{}`", &rest[..len]), len)
+			},
+			Some(ch) => (format!("`{ch}`"), ch.len_utf8()),
+			None => unreachable!("Input should not be empty"),
+		};
+
+		Self { name, range: pos..pos + len, }
+	}
+}
+
+/// Returns the length of all leading whitespace and comments in `s`
+fn whitespace_len(s: &str) -> usize {
+	let mut rest = s;
+	loop {
+		rest = rest.trim_start();
+		if rest.starts_with("//") {
+			rest = &rest[rest.find('\n').unwrap_or(rest.len())..];
+		} else if let Some(comment) = rest.strip_prefix("/*") {
+			rest = comment
+				.find("*/")
+				.map_or("", |idx| &comment[idx + 2..]);
+		} else {
+			break;
+		}
+	}
+
+	s.len() - rest.len()
+}
+
+/// Lowercases the first character of a message, unless it's part of an acronym
+fn lowercase_first(msg: &str) -> String {
+	let mut chars = msg.chars();
+	match (chars.next(), chars.next()) {
+		(Some(first), second) if first.is_uppercase() && !second.is_some_and(char::is_uppercase) => first
+			.to_lowercase()
+			.chain(msg[first.len_utf8()..].chars())
+			.collect(),
+		_ => msg.to_owned(),
+	}
+}
+
+/// Source snippet
+struct Snippet<'a> {
+	/// Line index of the input
+	line_index:        &'a LineIndex,
+
+	/// All labels spanning multiple lines, each with it's own slot
+	multi_line_labels: &'a [&'a DiagnosticLabel],
+
+	/// Style of primary labels
+	level_style:       Style,
+}
+
+impl Snippet<'_> {
+	/// Returns the text of a line (1-indexed)
+	fn line(&self, line: usize) -> &str {
+		self.line_index.line(line - 1).unwrap_or("")
+	}
+
+	/// Returns the display column of a location
+	fn column(&self, loc: DiagnosticLoc) -> usize {
+		let line = self.line(loc.line);
+		let column = (loc.column - 1).min(line.len());
+		self.slots_width() + util::str_width(&line[..column], TAB_WIDTH)
+	}
+
+	/// Returns the width of all multi-line label slots
+	const fn slots_width(&self) -> usize {
+		match self.multi_line_labels.len() {
+			0 => 0,
+			len => 2 * len,
+		}
+	}
+
+	/// Returns the style of a label
+	const fn label_style(&self, label: &DiagnosticLabel) -> Style {
+		match label.primary {
+			true => self.level_style,
+			false => Style::Secondary,
+		}
+	}
+
+	/// Returns whether a multi-line label starts at the start of it's first line.
+	fn starts_line(&self, label: &DiagnosticLabel) -> bool {
+		let line = self.line(label.span.start.line);
+		let column = (label.span.start.column - 1).min(line.len());
+		line[..column].trim().is_empty()
+	}
+
+	/// Creates a row with the slots of all multi-line labels active after a line
+	fn slots_row(&self, line: usize) -> Row {
+		let mut row = Row::default();
+		for (slot, label) in self.multi_line_labels.iter().enumerate() {
+			let span = label.span;
+			let is_active = (span.start.line < line && line <= span.end.line)
+				|| (span.start.line == line && self.starts_line(label));
+			if is_active {
+				row
+					.set(2 * slot, '|', self.label_style(label));
+			}
+		}
+
+		row
+	}
+
+	/// Creates the row with the source of a line
+	fn source_row(&self, line: usize) -> Row {
+		let mut row = Row::default();
+		for (slot, label) in self.multi_line_labels.iter().enumerate() {
+			let span = label.span;
+			let ch = match () {
+				() if span.start.line == line => match self.starts_line(label) {
+					true => '/',
+					false => ' ',
+				},
+				() if span.start.line < line && line <= span.end.line => '|',
+				() => ' ',
+			};
+			row.set(2 * slot, ch, self.label_style(label));
+		}
+		row.pad(self.slots_width());
+
+		let text = self
+			.line(line)
+			.replace('\t', &" ".repeat(TAB_WIDTH));
+		row.tail = Some((text, Style::Plain));
+
+		row
+	}
+
+	/// Creates all rows with the annotations of a line
+	fn annotation_rows(&self, labels: &[DiagnosticLabel], line: usize) -> Vec<Row> {
+		let mut rows = vec![];
+
+		// Single-line labels
+		let mut single_line_labels = labels.iter().filter(
+			|label| label.span.start.line == line && label.span.end.line == line,
+		).collect::<Vec<_>>();
+		single_line_labels.sort_by_key(
+			|label| (self.column(label.span.start), label.primary),
+		);
+		if !single_line_labels.is_empty() {
+			let mut row = self.slots_row(line);
+			for label in &single_line_labels {
+				let start = self.column(label.span.start);
+				let end = self.column(label.span.end).max(start + 1);
+				let ch = match label.primary {
+					true => '^',
+					false => '-',
+				};
+				for column in start..end {
+					row.set(column, ch, self.label_style(label));
+				}
+			}
+
+			let mut labels = single_line_labels
+				.iter()
+				.filter_map(|label| Some(
+					(self.column(label.span.start), label.message.as_ref()?, self.label_style(label)),
+				))
+				.collect::<Vec<_>>();
+			if let Some((_, message, style)) = labels.pop() {
+				row.tail = Some((format!(" {message}"), style));
+			}
+			rows.push(row);
+
+			// Note: All other messages are placed below their labels, from right to left.
+			while let Some((column, message, style)) = labels.pop() {
+				let mut row = self.slots_row(line);
+				for &(column, _, style) in labels.iter().chain([&(column, message, style)]) {
+					row.set(column, '|', style);
+				}
+				rows.push(row);
+
+				let mut row = self.slots_row(line);
+				for &(column, _, style) in &labels {
+					row.set(column, '|', style);
+				}
+				row.pad(column);
+				row.tail = Some((message.clone(), style));
+				rows.push(row);
+			}
+		}
+
+		// Multi-line labels
+		for (slot, label) in self.multi_line_labels.iter().enumerate() {
+			let style = self.label_style(label);
+			let ch = match label.primary {
+				true => '^',
+				false => '-',
+			};
+			let span = label.span;
+			if span.start.line == line && !self.starts_line(label) {
+				let mut row = self.slots_row(line);
+				let start = self.column(span.start);
+				for column in 2 * slot + 1..start {
+					row.set(column, '_', style);
+				}
+				row.set(start, ch, style);
+				rows.push(row);
+			}
+
+			if span.end.line == line {
+				let mut row = self.slots_row(line);
+				let end = self
+					.column(span.end)
+					.saturating_sub(1)
+					.max(2 * slot + 1);
+				for column in 2 * slot + 1..end {
+					row.set(column, '_', style);
+				}
+				row.set(end, ch, style);
+				if let Some(message) = &label.message {
+					row.tail = Some((format!(" {message}"), style));
+				}
+				rows.push(row);
+			}
+		}
+
+		rows
+	}
+}
+
+/// Snippet row
+#[derive(Default)]
+struct Row {
+	/// Cells
+	cells: Vec<(char, Style)>,
+
+	/// Text after all cells
+	tail:  Option<(String, Style)>,
+}
+
+impl Row {
+	/// Sets a cell
+	fn set(&mut self, column: usize, ch: char, style: Style) {
+		self.pad(column + 1);
+		self.cells[column] = (ch, style);
+	}
+
+	/// Pads the cells with spaces until `len`
+	fn pad(&mut self, len: usize) {
+		if self.cells.len() < len {
+			self.cells.resize(len, (' ', Style::Plain));
+		}
+	}
+
+	/// Writes this row to `output`
+	fn write(mut self, output: &mut Output) {
+		if self
+			.tail
+			.as_ref()
+			.is_none_or(|(tail, _)| tail.is_empty()) {
+			while self
+				.cells
+				.pop_if(|&mut (ch, _)| ch == ' ')
+				.is_some() {}
+		}
+
+		if !self.cells.is_empty()
+			|| self
+				.tail
+				.as_ref()
+				.is_some_and(|(tail, _)| !tail.trim_end().is_empty()) {
+			output.s.push(' ');
+		}
+
+		let mut cells = self.cells.as_slice();
+		while let Some(&(_, style)) = cells.first() {
+			let len = cells
+				.iter()
+				.position(|&(_, other_style)| other_style != style)
+				.unwrap_or(cells.len());
+			let s = cells[..len]
+				.iter()
+				.map(|&(ch, _)| ch)
+				.collect::<String>();
+			output.push(&s, style);
+			cells = &cells[len..];
+		}
+		if let Some((tail, style)) = &self.tail {
+			output.push(tail.trim_end(), *style);
+		}
+		output.s.push('\n');
+	}
+}
+
+/// Output style
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Style {
+	Plain,
+	Bold,
+	Error,
+	Warning,
+	Secondary,
+}
+
+impl Style {
+	/// Returns the ansi code of this style
+	const fn ansi_code(self) -> Option<&'static str> {
+		match self {
+			Self::Plain => None,
+			Self::Bold => Some("1"),
+			Self::Error => Some("1;31"),
+			Self::Warning => Some("1;33"),
+			Self::Secondary => Some("1;34"),
+		}
+	}
+}
+
+/// Rendered output
+struct Output {
+	/// Output
+	s:     String,
+
+	/// Whether to use color
+	color: bool,
+}
+
+impl Output {
+	/// Pushes a string with a style
+	fn push(&mut self, s: &str, style: Style) {
+		match style
+			.ansi_code()
+			.filter(|_| self.color && !s.is_empty()) {
+			Some(code) => _ = write!(self.s, "\x1b[{code}m{s}\x1b[0m"),
+			None => self.s.push_str(s),
+		}
+	}
+}
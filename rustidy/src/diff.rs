@@ -0,0 +1,371 @@
+//! Line diffs
+
+// Imports
+use {core::ops, std::fmt};
+
+/// Line diff between two strings.
+///
+/// Displays as the hunks of a unified diff (without the file header).
+#[derive(Clone, Debug)]
+pub struct Diff<'a> {
+	pub hunks: Vec<DiffHunk<'a>>,
+}
+
+impl<'a> Diff<'a> {
+	/// Creates a diff between `old` and `new`, with `context` lines
+	/// around each change.
+	#[must_use]
+	pub fn new(old: &'a str, new: &'a str, context: usize) -> Self {
+		let old = old.split_inclusive('\n').collect::<Vec<_>>();
+		let new = new.split_inclusive('\n').collect::<Vec<_>>();
+		let ops = self::diff_ops(&old, &new);
+
+		let mut hunks = vec![];
+		let mut cur_op = 0;
+		while let Some(first_change) = ops[cur_op..]
+			.iter()
+			.position(|op| op.kind != DiffOpKind::Equal)
+			.map(|idx| cur_op + idx) {
+			// Keep extending the hunk while the next change is close enough
+			// that the context lines would overlap.
+			let mut end = first_change;
+			loop {
+				end += ops[end..]
+					.iter()
+					.take_while(|op| op.kind != DiffOpKind::Equal)
+					.count();
+				let equal_len = ops[end..]
+					.iter()
+					.take_while(|op| op.kind == DiffOpKind::Equal)
+					.count();
+				match end + equal_len < ops.len() && equal_len <= 2 * context {
+					true => end += equal_len,
+					false => break,
+				}
+			}
+
+			let start = first_change.saturating_sub(context).max(cur_op);
+			let end = (end + context).min(ops.len());
+			let hunk_ops = &ops[start..end];
+			let lines = hunk_ops
+				.iter()
+				.map(|op| match op.kind {
+					DiffOpKind::Equal => DiffLine::Context(old[op.old]),
+					DiffOpKind::Remove => DiffLine::Removed(old[op.old]),
+					DiffOpKind::Add => DiffLine::Added(new[op.new]),
+				})
+				.collect();
+			hunks.push(DiffHunk {
+				old_start: hunk_ops[0].old,
+				old_len: hunk_ops
+					.iter()
+					.filter(|op| op.kind != DiffOpKind::Add)
+					.count(),
+				new_start: hunk_ops[0].new,
+				new_len: hunk_ops
+					.iter()
+					.filter(|op| op.kind != DiffOpKind::Remove)
+					.count(),
+				lines,
+			});
+
+			cur_op = end;
+		}
+
+		Self { hunks }
+	}
+
+	/// Returns if there are no differences
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.hunks.is_empty()
+	}
+}
+
+impl fmt::Display for Diff<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for hunk in &self.hunks {
+			write!(f, "{hunk}")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Diff hunk
+#[derive(Clone, Debug)]
+pub struct DiffHunk<'a> {
+	/// Old start line (0-indexed)
+	pub old_start: usize,
+	pub old_len:   usize,
+
+	/// New start line (0-indexed)
+	pub new_start: usize,
+	pub new_len:   usize,
+
+	pub lines:     Vec<DiffLine<'a>>,
+}
+
+impl fmt::Display for DiffHunk<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		/// Formats a hunk range.
+		///
+		/// Empty ranges refer to the line before them, as in `diff -u`.
+		fn range(start: usize, len: usize) -> String {
+			match len {
+				0 => format!("{start},0"),
+				1 => format!("{}", start + 1),
+				_ => format!("{},{len}", start + 1),
+			}
+		}
+
+		writeln!(
+			f,
+			"@@ -{} +{} @@",
+			range(self.old_start, self.old_len),
+			range(self.new_start, self.new_len)
+		)?;
+		for line in &self.lines {
+			let (prefix, line) = match line {
+				DiffLine::Context(line) => (' ', line),
+				DiffLine::Removed(line) => ('-', line),
+				DiffLine::Added(line) => ('+', line),
+			};
+
+			match line.strip_suffix('\n') {
+				Some(line) => writeln!(f, "{prefix}{line}")?,
+				None => writeln!(f, "{prefix}{line}\n\\ No newline at end of file")?,
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Diff line.
+///
+/// Contains the line terminator, if any.
+#[derive(Clone, Copy, Debug)]
+pub enum DiffLine<'a> {
+	Context(&'a str),
+	Removed(&'a str),
+	Added(&'a str),
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum DiffOpKind {
+	Equal,
+	Remove,
+	Add,
+}
+
+/// Diff operation.
+///
+/// `old` and `new` are the line indices at the start of this
+/// operation in each input.
+#[derive(Clone, Copy, Debug)]
+struct DiffOp {
+	kind: DiffOpKind,
+	old:  usize,
+	new:  usize,
+}
+
+/// Calculates the operations to turn `old` into `new`
+///
+/// Uses the linear space variant of myers' algorithm, instead of
+/// the `difference` crate, since it's lcs table would take quadratic
+/// memory for large files.
+fn diff_ops(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+	let mut kinds = Vec::with_capacity(old.len() + new.len());
+	let max_d = self::max_d(old.len(), new.len());
+	let mut forward = V::new(max_d);
+	let mut backward = V::new(max_d);
+	self::diff_kinds(
+		old,
+		new,
+		&mut forward,
+		&mut backward,
+		&mut kinds,
+	);
+
+	// Note: Within each change, we show the removed lines before
+	//       the added ones, like most diff tools.
+	for change in kinds.chunk_by_mut(
+		|lhs, rhs| (*lhs == DiffOpKind::Equal) == (*rhs == DiffOpKind::Equal),
+	) {
+		change
+			.sort_by_key(|&kind| kind == DiffOpKind::Add);
+	}
+
+	let (mut old_idx, mut new_idx) = (0, 0);
+	kinds.into_iter().map(|kind| {
+		let op = DiffOp { kind, old: old_idx, new: new_idx };
+		match kind {
+			DiffOpKind::Equal => {
+				old_idx += 1;
+				new_idx += 1;
+			},
+			DiffOpKind::Remove => old_idx += 1,
+			DiffOpKind::Add => new_idx += 1,
+		}
+		op
+	}).collect()
+}
+
+/// Calculates the operations to turn `old` into `new`, using
+/// the linear space variant of myers' diff algorithm.
+fn diff_kinds(
+	mut old: &[&str],
+	mut new: &[&str],
+	forward: &mut V,
+	backward: &mut V,
+	kinds: &mut Vec<DiffOpKind>,
+) {
+	let prefix_len = self::common_prefix_len(old, new);
+	kinds
+		.extend((0..prefix_len).map(|_| DiffOpKind::Equal));
+	old = &old[prefix_len..];
+	new = &new[prefix_len..];
+
+	let suffix_len = self::common_suffix_len(old, new);
+	old = &old[..old.len() - suffix_len];
+	new = &new[..new.len() - suffix_len];
+
+	match (old.is_empty(), new.is_empty()) {
+		(true, _) => kinds
+			.extend((0..new.len()).map(|_| DiffOpKind::Add)),
+		(false, true) => kinds
+			.extend((0..old.len()).map(|_| DiffOpKind::Remove)),
+		(false, false) => {
+			let (old_mid, new_mid) = self::find_middle_snake(old, new, forward, backward);
+			self::diff_kinds(
+				&old[..old_mid],
+				&new[..new_mid],
+				forward,
+				backward,
+				kinds,
+			);
+			self::diff_kinds(
+				&old[old_mid..],
+				&new[new_mid..],
+				forward,
+				backward,
+				kinds,
+			);
+		},
+	}
+
+	kinds
+		.extend((0..suffix_len).map(|_| DiffOpKind::Equal));
+}
+
+/// Finds the start of the middle snake of the shortest edit script
+/// between `old` and `new`.
+///
+/// Both `old` and `new` must not be empty.
+fn find_middle_snake(
+	old: &[&str],
+	new: &[&str],
+	forward: &mut V,
+	backward: &mut V,
+) -> (usize, usize) {
+	let (old_len, new_len) = (old.len(), new.len());
+	let delta = old_len.cast_signed() - new_len.cast_signed();
+	let is_odd = delta % 2 != 0;
+	forward[1] = 0;
+	backward[1] = 0;
+
+	for d in 0..self::max_d(old_len, new_len).cast_signed() {
+		// Forward paths, with `x` as the index into `old`
+		for k in (-d..=d).rev().step_by(2) {
+			let mut x = match k == -d || (k != d && forward[k - 1] < forward[k + 1]) {
+				true => forward[k + 1],
+				false => forward[k - 1] + 1,
+			};
+			let y = (x.cast_signed() - k).cast_unsigned();
+			let (start_x, start_y) = (x, y);
+			if x < old_len && y < new_len {
+				x += self::common_prefix_len(&old[x..], &new[y..]);
+			}
+			forward[k] = x;
+
+			if is_odd && (k - delta).abs() < d && forward[k] + backward[delta - k] >= old_len {
+				return (start_x, start_y);
+			}
+		}
+
+		// Backward paths, with `x` as the number of lines from the end of `old`
+		for k in (-d..=d).rev().step_by(2) {
+			let mut x = match k == -d || (k != d && backward[k - 1] < backward[k + 1]) {
+				true => backward[k + 1],
+				false => backward[k - 1] + 1,
+			};
+			let mut y = (x.cast_signed() - k).cast_unsigned();
+			if x < old_len && y < new_len {
+				let len = self::common_suffix_len(&old[..old_len - x], &new[..new_len - y]);
+				x += len;
+				y += len;
+			}
+			backward[k] = x;
+
+			if !is_odd && (k - delta).abs() <= d && backward[k] + forward[delta - k] >= old_len {
+				return (old_len - x, new_len - y);
+			}
+		}
+	}
+
+	unreachable!("Middle snake should be found within the maximum edit distance");
+}
+
+/// Returns the maximum number of iterations to find the middle snake
+const fn max_d(old_len: usize, new_len: usize) -> usize {
+	(old_len + new_len).div_ceil(2) + 1
+}
+
+/// Returns the length of the common prefix between `old` and `new`
+fn common_prefix_len(old: &[&str], new: &[&str]) -> usize {
+	old
+		.iter()
+		.zip(new)
+		.take_while(|(lhs, rhs)| lhs == rhs)
+		.count()
+}
+
+/// Returns the length of the common suffix between `old` and `new`
+fn common_suffix_len(old: &[&str], new: &[&str]) -> usize {
+	old
+		.iter()
+		.rev()
+		.zip(new.iter().rev())
+		.take_while(|(lhs, rhs)| lhs == rhs)
+		.count()
+}
+
+/// Furthest reaching `x` of each diagonal `k`
+struct V {
+	offset: isize,
+	values: Vec<usize>,
+}
+
+impl V {
+	fn new(max_d: usize) -> Self {
+		Self {
+			offset: max_d.cast_signed() + 1,
+			values: vec![0; 2 * max_d + 3],
+		}
+	}
+}
+
+impl ops::Index<isize> for V {
+	type Output = usize;
+
+	fn index(&self, k: isize) -> &Self::Output {
+		&self.values[(k + self.offset).cast_unsigned()]
+	}
+}
+
+impl ops::IndexMut<isize> for V {
+	fn index_mut(&mut self, k: isize) -> &mut Self::Output {
+		&mut self.values[(k + self.offset).cast_unsigned()]
+	}
+}
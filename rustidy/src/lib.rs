@@ -29,67 +29,331 @@
 // Lints
 #![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
 
+// Modules
+pub mod ast_tree;
+pub mod diagnostic;
+pub mod diff;
+
+// Exports
+pub use self::{
+	ast_tree::{AstTreeError, ast_tree},
+	diagnostic::{
+		Diagnostic,
+		DiagnosticLabel,
+		DiagnosticLevel,
+		DiagnosticLoc,
+		DiagnosticSpan,
+	},
+	diff::Diff,
+};
+
 // Imports
 use {
 	app_error::{AppError, app_error},
 	ast::Crate,
-	format::{FormatOutput},
-	parse::{ParseError, Parser},
-	std::path::Path,
-	util::Config,
+	core::ops::RangeInclusive,
+	format::{FormatOutput, FormatWarning},
+	parse::{ParseError, ParseErrorTree, Parser, ParserLoc, RecoveredError},
+	print::{Print, PrintOutput},
+	std::{path::Path, sync::Arc},
+	util::{Config, LineIndex},
 };
 
 /// Formats the crate `crate_`.
-pub fn format(input: &str, config: &Config, crate_: &mut Crate) -> FormatOutput {
+///
+/// Any warnings emitted while formatting are added to `warnings`.
+pub fn format(
+	input: &str,
+	config: &Config,
+	crate_: &mut Crate,
+	warnings: &mut Vec<FormatWarning>,
+) -> FormatOutput {
 	let mut ctx = format::Context::new(input, config);
-	ctx.format(crate_, ())
+	let output = ctx.format(crate_, ());
+	warnings.extend(ctx.take_warnings());
+
+	output
+}
+
+/// Formats only the lines `lines` of the crate `crate_`, returning the output.
+///
+/// Lines are 1-indexed and inclusive. Only the values and whitespace
+/// whose input overlaps `lines` are formatted.
+///
+/// Any warnings emitted while formatting are added to `warnings`.
+pub fn format_lines(
+	input: &str,
+	config: &Config,
+	crate_: &mut Crate,
+	lines: &[RangeInclusive<usize>],
+	warnings: &mut Vec<FormatWarning>,
+) -> PrintOutput {
+	// Note: The ranges start at the newline before the first line and end
+	//       before the newline of the last line, so that we format the
+	//       indentation of the first line, but not of the line after the last.
+	let line_index = Arc::new(LineIndex::new(input));
+	let line_end = |line: usize| line_index
+		.line_start(line)
+		.map_or(input.len(), |pos| pos.0.saturating_sub(1));
+	let ranges = lines.iter().map(
+		|lines| line_end(lines.start().saturating_sub(1))..line_end(*lines.end()),
+	).collect::<Vec<_>>();
+
+	let mut ctx = format::Context::from_line_index(line_index, config);
+	ctx.set_ranges(&ranges);
+	let _: FormatOutput = ctx.format(crate_, ());
+	warnings.extend(ctx.take_warnings());
+
+	crate_.print_to(Print::print)
 }
 
 /// Parses the input `input`.
 ///
 /// `file` is only used for error reporting and does not have to exist.
 pub fn parse(input: &str, file: &Path) -> Result<ast::Crate, AppError> {
+	self::parse_with_loc(input, file)
+		.map_err(|err| err.to_app_error())
+}
+
+/// Parses the input `input`, keeping the location of any error.
+///
+/// On error, the input is parsed again while recovering, so that
+/// diagnostics are reported for all errors, instead of just the first.
+///
+/// `file` is only used for error reporting and does not have to exist.
+pub fn parse_with_loc(input: &str, file: &Path) -> Result<ast::Crate, CrateParseError> {
 	let mut parser = Parser::new(input);
+	self::parse_crate(&mut parser, file).map_err(|mut err| {
+		let mut parser = Parser::from_line_index(Arc::clone(parser.line_index()));
+		parser.set_recovering(true);
+		let res = self::parse_crate(&mut parser, file);
+
+		let diagnostics = self::recovered_errors(&mut parser)
+			.iter()
+			.map(|err| Diagnostic::from_parse_error(
+				DiagnosticLevel::Error,
+				parser.line_index(),
+				file,
+				&err.tree,
+			))
+			.chain(res
+				.err()
+				.into_iter()
+				.flat_map(|err| err.diagnostics))
+			.collect::<Vec<_>>();
+		if !diagnostics.is_empty() {
+			err.diagnostics = diagnostics;
+		}
+
+		err
+	})
+}
+
+/// Parses the input `input`, skipping any items or statements that fail to parse.
+///
+/// Skipped regions are kept exactly as they are in the input, and are returned
+/// alongside the crate. Errors that can't be skipped, such as unclosed delimiters,
+/// still fail the whole parse.
+///
+/// `file` is only used for error reporting and does not have to exist.
+pub fn parse_recovering(
+	input: &str,
+	file: &Path,
+) -> Result<(ast::Crate, Vec<SkippedRegion>), CrateParseError> {
+	let mut parser = Parser::new(input);
+	parser.set_recovering(true);
+	let res = self::parse_crate(&mut parser, file);
+
+	let skipped = self::recovered_errors(&mut parser)
+		.into_iter()
+		.map(|err| {
+			parser.set_pos(err.pos);
+			parser.reverse_whitespace();
+			let loc = parser.cur_loc();
+
+			let start = parser.loc(err.range.start);
+			let end = parser.loc(err.range.end);
+			let mut diagnostic = Diagnostic::from_parse_error(
+				DiagnosticLevel::Warning,
+				parser.line_index(),
+				file,
+				&err.tree,
+			);
+			diagnostic.notes.push(
+				format!("skipped {start}..{end}, leaving it unformatted"),
+			);
+
+			SkippedRegion {
+				start,
+				end,
+				loc,
+				err: err.err,
+				diagnostic,
+				line_index: Arc::clone(parser.line_index()),
+			}
+		})
+		.collect::<Vec<_>>();
+
+	match res {
+		Ok(crate_) => Ok((crate_, skipped)),
+		Err(mut err) => {
+			err.diagnostics.splice(
+				0..0,
+				skipped
+					.into_iter()
+					.map(|region| region.diagnostic),
+			);
+			Err(err)
+		},
+	}
+}
+
+/// Parses a crate from `parser`
+fn parse_crate(parser: &mut Parser, file: &Path) -> Result<ast::Crate, CrateParseError> {
 	parser.parse::<ast::Crate>().map_err(|err| {
 		if let Some(pos) = err.pos() {
 			parser.set_pos(pos);
 		}
 		parser.reverse_whitespace();
 
-		err
-			.to_app_error(&parser)
-			.with_context(|| self::parser_error_ctx(file, &parser))
+		// Note: The crate spans the whole input, so we don't label it.
+		let tree = match err.to_tree() {
+			ParseErrorTree::Parse { range, source, .. } => ParseErrorTree::Parse {
+				name: None,
+				range,
+				source
+			},
+			tree => tree,
+		};
+
+		CrateParseError {
+			loc: parser.cur_loc(),
+			err: err.to_app_error(parser),
+			diagnostics: vec![Diagnostic::from_parse_error(DiagnosticLevel::Error, parser.line_index(), file, &tree)],
+			line_index: Arc::clone(parser.line_index()),
+		}
 	}).and_then(|ast| match parser.is_finished() {
 		true => Ok(ast),
-		false => Err(
-			app_error!("Unexpected tokens at the end of file")
-				.with_context(|| self::parser_error_ctx(file, &parser))
-		),
+		false => {
+			let pos = parser.cur_pos().0;
+			let end = parser.input().trim_end().len().max(pos);
+			let diagnostic = Diagnostic {
+				level: DiagnosticLevel::Error,
+				message: "unexpected tokens at the end of file".to_owned(),
+				file: file.to_path_buf(),
+				labels: vec![DiagnosticLabel {
+					primary: true,
+					span:    DiagnosticSpan::new(parser.line_index(), pos..end),
+					message: None,
+				}],
+				notes: vec![],
+			};
+
+			Err(CrateParseError {
+				loc: parser.cur_loc(),
+				err: app_error!("Unexpected tokens at the end of file"),
+				diagnostics: vec![diagnostic],
+				line_index: Arc::clone(parser.line_index()),
+			})
+		},
 	})
 }
 
-/// Creates context for a parser error.
-fn parser_error_ctx(file: &Path, parser: &Parser) -> String {
-	let line = parser.cur_line();
-	let line_indent = line
-		.find(|ch: char| !ch.is_ascii_whitespace())
-		.unwrap_or(line.len());
-
-	let loc = parser.cur_loc();
-
-	let indent = line[..loc
-		.column][line_indent..]
-		.chars()
-		.map(|ch| match ch.is_whitespace() {
-			true => ch,
-			false => ' ',
-		})
-		.collect::<String>();
-
-	format!(
-		"Error at {}:{loc}:\n    |\n{:>3} | {}\n    | {indent}^",
-		file.display(),
-		loc.line + 1,
-		&line[line_indent..]
-	)
+/// Takes all errors recovered from by `parser`, sorted by position
+fn recovered_errors(parser: &mut Parser) -> Vec<RecoveredError> {
+	// Note: Some regions may have been parsed more than once, if their parent
+	//       was peeked before being parsed.
+	let mut errs = parser.take_recovered_errors();
+	errs.sort_by_key(|err| err.range.start);
+	errs.dedup_by_key(|err| err.range);
+
+	errs
+}
+
+/// Crate parse error
+#[derive(Clone, Debug)]
+pub struct CrateParseError {
+	/// Location of the error
+	pub loc:         ParserLoc,
+
+	/// Error
+	pub err:         AppError,
+
+	/// Diagnostics for all errors found
+	pub diagnostics: Vec<Diagnostic>,
+
+	/// Line index of the input
+	line_index:  Arc<LineIndex>,
+}
+
+impl CrateParseError {
+	/// Returns the line index of the input that failed to parse
+	#[must_use]
+	pub const fn line_index(&self) -> &Arc<LineIndex> {
+		&self.line_index
+	}
+
+	/// Renders all diagnostics of this error
+	#[must_use]
+	pub fn render(&self, color: bool) -> String {
+		let rendered = self
+			.diagnostics
+			.iter()
+			.map(
+				|diagnostic| diagnostic
+					.render(&self.line_index, color),
+			)
+			.collect::<Vec<_>>()
+			.join("\n");
+		rendered
+			.strip_suffix('\n')
+			.unwrap_or(&rendered)
+			.to_owned()
+	}
+
+	/// Converts this error to an `AppError`, with all diagnostics rendered
+	#[must_use]
+	pub fn to_app_error(&self) -> AppError {
+		AppError::fmt(self.render(false))
+	}
+}
+
+/// Region skipped while parsing, because it failed to parse
+#[derive(Clone, Debug)]
+pub struct SkippedRegion {
+	/// Start of the region
+	pub start:      ParserLoc,
+
+	/// End of the region
+	pub end:        ParserLoc,
+
+	/// Location of the error
+	pub loc:        ParserLoc,
+
+	/// Error
+	pub err:        AppError,
+
+	/// Diagnostic for the error
+	pub diagnostic: Diagnostic,
+
+	/// Line index of the input
+	line_index: Arc<LineIndex>,
+}
+
+impl SkippedRegion {
+	/// Returns the line index of the input this region was skipped from
+	#[must_use]
+	pub const fn line_index(&self) -> &Arc<LineIndex> {
+		&self.line_index
+	}
+
+	/// Converts this region to an `AppError`, with the diagnostic rendered
+	#[must_use]
+	pub fn to_app_error(&self) -> AppError {
+		let rendered = self.diagnostic.render(&self.line_index, false);
+		AppError::fmt(rendered
+			.strip_suffix('\n')
+			.unwrap_or(&rendered)
+			.to_owned())
+	}
 }
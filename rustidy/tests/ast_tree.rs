@@ -0,0 +1,95 @@
+//! Ast tree tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	format::FormatOutput,
+	std::path::Path,
+};
+
+/// Test case, with the input, whether to format it, and the expected tree
+type Case = (&'static str, bool, &'static str);
+
+#[test]
+pub fn ast_tree() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let cases: [Case; _] = [
+		("// Comment\nfn f( ) {}\n", false, r#"Crate 2:1..2:11
+  items: Items 2:1..2:11
+    AtLeast1 2:1..2:11
+      first: Item 2:1..2:11
+        WithOuterAttributes 2:1..2:11
+          inner: ItemInner::Vis 2:1..2:11
+            VisItem 2:1..2:11
+              inner: VisItemInner::Function 2:1..2:11
+                Function 2:1..2:11
+                  fn_: Fn 2:1..2:3 "fn" (whitespace "// Comment\n")
+                  ident: Identifier::NonKw 2:4..2:5
+                    NonKeywordIdentifier 2:4..2:5
+                      IdentifierOrKeyword 2:4..2:5 "f" (whitespace " ")
+                  params: Delimited 2:5..2:8
+                    prefix: ParenOpen 2:5..2:6 "("
+                    suffix: ParenClose 2:7..2:8 ")" (whitespace " ")
+                  body: FunctionBody::Expr 2:9..2:11
+                    BlockExpression 2:9..2:11
+                      BracedWithInnerAttributes 2:9..2:11
+                        Delimited 2:9..2:11
+                          prefix: BracesOpen 2:9..2:10 "{" (whitespace " ")
+                          suffix: BracesClose 2:10..2:11 "}"
+  suffix_ws: Whitespace 2:11..3:1 "\n"
+"#),
+		("// Comment\nfn f( ) {}\n", true, r#"Crate 2:1..2:10
+  items: Items 2:1..2:10
+    AtLeast1 2:1..2:10
+      first: Item 2:1..2:10
+        WithOuterAttributes 2:1..2:10
+          inner: ItemInner::Vis 2:1..2:10
+            VisItem 2:1..2:10
+              inner: VisItemInner::Function 2:1..2:10
+                Function 2:1..2:10
+                  fn_: Fn 2:1..2:3 "fn" (whitespace "// Comment\n")
+                  ident: Identifier::NonKw 2:4..2:5
+                    NonKeywordIdentifier 2:4..2:5
+                      IdentifierOrKeyword 2:4..2:5 "f" (whitespace " ")
+                  params: Delimited 2:5..2:7
+                    prefix: ParenOpen 2:5..2:6 "("
+                    suffix: ParenClose 2:6..2:7 ")"
+                  body: FunctionBody::Expr 2:8..2:10
+                    BlockExpression 2:8..2:10
+                      BracedWithInnerAttributes 2:8..2:10
+                        Delimited 2:8..2:10
+                          prefix: BracesOpen 2:8..2:9 "{" (whitespace " ")
+                          suffix: BracesClose 2:9..2:10 "}"
+  suffix_ws: Whitespace 2:10..3:1 "\n"
+"#),
+	];
+
+	for (input, format, expected) in cases {
+		self::test_case(input, format, expected)
+			.with_context(|| format!("Test case {input:?} failed"))?;
+	}
+
+	Ok(())
+}
+
+fn test_case(input: &str, format: bool, expected: &str) -> Result<(), AppError> {
+	let mut crate_ = rustidy::parse(input, Path::new("<input>"))
+		.context("Unable to parse input")?;
+	if format {
+		let config = util::Config::default();
+		let _: FormatOutput = rustidy::format(input, &config, &mut crate_, &mut vec![]);
+	}
+
+	let found = rustidy::ast_tree(&crate_)
+		.context("Unable to create ast tree")?;
+	ensure!(found == expected, "Found wrong tree.\nExpected:\n{expected}\nFound:\n{found}");
+
+	Ok(())
+}
@@ -0,0 +1,317 @@
+//! Configuration tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	std::{env, fs, path::{Path, PathBuf}, process::{self, Command}},
+};
+
+#[test]
+pub fn config_discovery() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let dir = self::create_tree("discovery", &[
+		(".rustidy.toml", "indent = \"  \"\nmax_chain_len = 10\n"),
+		("a.rs", ""),
+		("inherit/.rustidy.toml", "inherit = true\nmax_chain_len = 20\n"),
+		("inherit/a.rs", ""),
+		("inherit/nested/a.rs", ""),
+		("no-inherit/rustidy.toml", "max_chain_len = 30\n"),
+		("no-inherit/a.rs", ""),
+	])?;
+
+	let cases: [(&str, ExpectedConfig); _] = [
+		("a.rs", &[
+			("indent", "\"  \"", ".rustidy.toml"),
+			("max_chain_len", "10", ".rustidy.toml"),
+		]),
+		("inherit/a.rs", &[
+			("indent", "\"  \"", ".rustidy.toml"),
+			("max_chain_len", "20", "inherit/.rustidy.toml"),
+		]),
+		("inherit/nested/a.rs", &[
+			("indent", "\"  \"", ".rustidy.toml"),
+			("max_chain_len", "20", "inherit/.rustidy.toml"),
+		]),
+		("no-inherit/a.rs", &[
+			("indent", "\"\\t\"", "Default"),
+			("max_chain_len", "30", "no-inherit/rustidy.toml"),
+		]),
+	];
+
+	for (file, expected) in cases {
+		self::test_config(&dir, file, &[], expected)
+			.with_context(|| format!("Test case {file:?} failed"))?;
+	}
+
+	fs::remove_dir_all(&dir)
+		.context("Unable to remove directory")?;
+
+	Ok(())
+}
+
+#[test]
+pub fn config_overrides() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	const CONFIG: &str = "max_chain_len = 10\n\
+		\n\
+		[[overrides]]\n\
+		files = [\"tests/**\"]\n\
+		max_chain_len = 20\n\
+		indent = \"  \"\n\
+		\n\
+		[[overrides]]\n\
+		files = [\"tests/*.rs\", \"src/tables/*.rs\"]\n\
+		max_chain_len = 30\n";
+	let dir = self::create_tree("overrides", &[
+		(".rustidy.toml", CONFIG),
+		("src/a.rs", ""),
+		("src/tables/a.rs", ""),
+		("src/tables/nested/a.rs", ""),
+		("tests/a.rs", ""),
+		("tests/nested/a.rs", ""),
+		("tests/nested/.rustidy.toml", "inherit = true\nmax_chain_len = 40\n"),
+	])?;
+
+	let cases: [(&str, ExpectedConfig); _] = [
+		("src/a.rs", &[("max_chain_len", "10", ".rustidy.toml")]),
+		("src/tables/a.rs", &[("max_chain_len", "30", ".rustidy.toml (override #2)")]),
+		("src/tables/nested/a.rs", &[("max_chain_len", "10", ".rustidy.toml")]),
+		("tests/a.rs", &[
+			("indent", "\"  \"", ".rustidy.toml (override #1)"),
+			("max_chain_len", "30", ".rustidy.toml (override #2)"),
+		]),
+		("tests/nested/a.rs", &[
+			("indent", "\"  \"", ".rustidy.toml (override #1)"),
+			("max_chain_len", "40", "tests/nested/.rustidy.toml"),
+		]),
+	];
+
+	for (file, expected) in cases {
+		self::test_config(&dir, file, &[], expected)
+			.with_context(|| format!("Test case {file:?} failed"))?;
+	}
+
+	fs::remove_dir_all(&dir)
+		.context("Unable to remove directory")?;
+
+	Ok(())
+}
+
+#[test]
+pub fn print_config() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	// Note: The default configuration shouldn't be affected by any configuration files
+	let dir = self::create_tree(
+		"print-config",
+		&[(".rustidy.toml", "max_chain_len = 10\n")],
+	)?;
+	let (success, stdout, stderr) = self::run(&dir, &["--print-config", "default"])?;
+	ensure!(success, "Unable to print configuration:\n{stderr}");
+	let config = self::parse_config(&stdout)?;
+	ensure!(
+		config.iter().all(|(_, _, source)| *source == "Default"),
+		"Found non-default values:\n{stdout}"
+	);
+	for (key, value) in [
+		("indent", "\"\\t\""),
+		("max_chain_len", "50"),
+		("array_expr_cols", "<unset>"),
+	] {
+		ensure!(
+			config.contains(&(key, value, "Default")),
+			"Missing default value {key} = {value}:\n{stdout}"
+		);
+	}
+
+	// Note: The schema should have the same fields as the configuration, with the same defaults.
+	let (success, schema, stderr) = self::run(&dir, &["--config-schema"])?;
+	ensure!(success, "Unable to print configuration schema:\n{stderr}");
+	let schema = serde_json::from_str::<serde_json::Value>(&schema)
+		.context("Unable to parse configuration schema")?;
+	let properties = schema["properties"]
+		.as_object()
+		.context("Schema had no properties")?;
+	let has_file_properties = ["inherit", "overrides"]
+		.iter()
+		.all(|key| properties.contains_key(*key));
+	ensure!(
+		properties.len() == config.len() + 2 && has_file_properties,
+		"Found wrong schema properties: {:?}",
+		properties.keys().collect::<Vec<_>>()
+	);
+	for &(key, value, _) in &config {
+		let property = properties
+			.get(key)
+			.with_context(|| format!("Schema is missing {key:?}"))?;
+		ensure!(
+			property["description"].as_str().is_some_and(|description| !description.is_empty()),
+			"Schema is missing a description for {key:?}"
+		);
+
+		let expected_default = match value {
+			"<unset>" => serde_json::Value::Null,
+			_ => toml::from_str::<toml::Table>(&format!("value = {value}"))
+				.context("Unable to parse value")?
+				.remove("value")
+				.map(serde_json::to_value)
+				.transpose()
+				.context("Unable to convert value")?
+				.context("Missing value")?,
+		};
+		let found_default = property
+			.get("default")
+			.cloned()
+			.unwrap_or(serde_json::Value::Null);
+		ensure!(
+			found_default == expected_default,
+			"Found wrong default for {key:?}.\nExpected: {expected_default}\nFound   : {found_default}"
+		);
+	}
+
+	fs::remove_dir_all(&dir)
+		.context("Unable to remove directory")?;
+
+	Ok(())
+}
+
+#[test]
+pub fn config_args() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let dir = self::create_tree(
+		"args",
+		&[(".rustidy.toml", "max_chain_len = 10\n"), ("a.rs", "")],
+	)?;
+
+	// Note: Values that aren't valid toml are treated as strings, since shells remove quotes.
+	let cases: [(&[&str], ExpectedConfig); _] = [
+		(&[], &[("max_chain_len", "10", ".rustidy.toml")]),
+		(&["--config", "max_chain_len=7"], &[("max_chain_len", "7", "--config")]),
+		(&["--config", "max_chain_len=7", "--config", "max_chain_len=8"], &[
+			("max_chain_len", "8", "--config"),
+		]),
+		(&["--config", "skip = true"], &[("skip", "true", "--config")]),
+		(&["--config", "indent=\"  \""], &[("indent", "\"  \"", "--config")]),
+		(&["--config", "indent=    "], &[("indent", "\"    \"", "--config")]),
+		(&["--config", "indent=\\t"], &[("indent", "'\\t'", "--config")]),
+	];
+	for (args, expected) in cases {
+		self::test_config(&dir, "a.rs", args, expected)
+			.with_context(|| format!("Test case {args:?} failed"))?;
+	}
+
+	let cases = [
+		("max_chan_len=7", "Unknown configuration `max_chan_len`"),
+		("max_chain_len=abc", "Invalid value for \"max_chain_len\""),
+		("max_chain_len", "Expected `<key>=<value>`"),
+	];
+	for (arg, expected) in cases {
+		let (success, _, stderr) = self::run(
+			&dir,
+			&["--print-config", "current", "--config", arg],
+		)?;
+		ensure!(
+			!success && stderr.contains(expected),
+			"Expected error {expected:?} for {arg:?}, found:\n{stderr}"
+		);
+	}
+
+	fs::remove_dir_all(&dir)
+		.context("Unable to remove directory")?;
+
+	Ok(())
+}
+
+/// Expected configuration values, as `(key, value, source)`
+type ExpectedConfig<'a> = &'a [(&'a str, &'a str, &'a str)];
+
+/// Creates a tree of files within a temporary directory, returning it
+fn create_tree(name: &str, files: &[(&str, &str)]) -> Result<PathBuf, AppError> {
+	let dir = env::temp_dir().join(
+		format!("rustidy-test-config-{}-{name}", process::id()),
+	);
+	for (path, contents) in files {
+		let path = dir.join(path);
+		fs::create_dir_all(path.parent().expect("File had no parent"))
+			.context("Unable to create directory")?;
+		fs::write(&path, contents)
+			.context("Unable to write file")?;
+	}
+
+	Ok(dir)
+}
+
+/// Runs rustidy within `dir`, returning whether it succeeded, alongside it's stdout and stderr
+fn run(dir: &Path, args: &[&str]) -> Result<(bool, String, String), AppError> {
+	let output = Command::new(env!("CARGO_BIN_EXE_rustidy"))
+		.current_dir(dir)
+		.args(args)
+		.output()
+		.context("Unable to run rustidy")?;
+	let stdout = String::from_utf8(output.stdout)
+		.context("Output wasn't utf-8")?;
+	let stderr = String::from_utf8(output.stderr)
+		.context("Output wasn't utf-8")?;
+
+	Ok((output.status.success(), stdout, stderr))
+}
+
+/// Checks that the configuration of `file` within `dir` contains all
+/// of `expected`'s keys, with their value and source.
+///
+/// Sources are relative to `dir`.
+fn test_config(
+	dir: &Path,
+	file: &str,
+	args: &[&str],
+	expected: ExpectedConfig,
+) -> Result<(), AppError> {
+	let mut run_args = vec!["--print-config", "current", "--for-file", file];
+	run_args.extend(args);
+	let (success, stdout, stderr) = self::run(dir, &run_args)?;
+	ensure!(success, "Unable to print configuration:\n{stderr}");
+
+	let config = self::parse_config(&stdout)?;
+	let dir = format!("{}/", dir.display());
+	for &(key, value, source) in expected {
+		let &(_, found_value, found_source) = config
+			.iter()
+			.find(|(found_key, ..)| *found_key == key)
+			.with_context(|| format!("Missing key {key:?}:\n{stdout}"))?;
+		let found_source = found_source
+			.strip_prefix(&dir)
+			.unwrap_or(found_source);
+		ensure!(
+			(found_value, found_source) == (value, source),
+			"Found wrong value for {key:?}.\nExpected: {value} # {source}\nFound   : {found_value} # {found_source}"
+		);
+	}
+
+	Ok(())
+}
+
+/// Parses the output of `--print-config` into `(key, value, source)` lines.
+///
+/// Unset values have a value of `<unset>`.
+fn parse_config(output: &str) -> Result<Vec<(&str, &str, &str)>, AppError> {
+	output.lines().map(|line| {
+		let (key, rest) = line
+			.trim_start_matches("# ")
+			.split_once(" = ")
+			.with_context(|| format!("Missing key: {line:?}"))?;
+		let (value, source) = rest
+			.split_once(" # ")
+			.with_context(|| format!("Missing source: {line:?}"))?;
+
+		Ok((key, value.trim(), source.trim()))
+	}).collect()
+}
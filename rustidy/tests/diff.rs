@@ -0,0 +1,45 @@
+//! Diff tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {app_error::{AppError, Context, ensure}, rustidy::Diff};
+
+#[test]
+pub fn diff() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let cases = [
+		("", "", ""),
+		("a\n", "a\n", ""),
+		("a\n", "b\n", "@@ -1 +1 @@\n-a\n+b\n"),
+		("", "a\n", "@@ -0,0 +1 @@\n+a\n"),
+		("a\n", "", "@@ -1 +0,0 @@\n-a\n"),
+		("a", "a\n", "@@ -1 +1 @@\n-a\n\\ No newline at end of file\n+a\n"),
+		("a\nb\nc\nd\ne\nf\ng\n", "a\nb\nc\nD\ne\nf\ng\n", "@@ -1,7 +1,7 @@\n a\n b\n c\n-d\n+D\n e\n f\n g\n"),
+		("1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n", "0\n1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n", "@@ -1,3 +1,4 @@\n+0\n 1\n 2\n 3\n@@ -9,4 +10,3 @@\n 9\n 10\n 11\n-12\n"),
+		("1\n2\n3\n4\n5\n6\n7\n", "0\n1\n2\n3\n4\n5\n6\n", "@@ -1,7 +1,7 @@\n+0\n 1\n 2\n 3\n 4\n 5\n 6\n-7\n"),
+	];
+
+	for (old, new, expected) in cases {
+		self::test_case(old, new, expected).with_context(
+			|| format!("Test case {old:?} -> {new:?} failed"),
+		)?;
+	}
+
+	Ok(())
+}
+
+fn test_case(old: &str, new: &str, expected: &str) -> Result<(), AppError> {
+	let diff = Diff::new(old, new, 3);
+	ensure!(diff.is_empty() == (old == new), "Diff emptiness didn't match input equality");
+
+	let found = diff.to_string();
+	ensure!(found == expected, "Found wrong diff.\nExpected: {expected:?}\nFound   : {found:?}");
+
+	Ok(())
+}
@@ -44,6 +44,11 @@ pub fn format() -> Result<(), AppError> {
 	Ok(())
 }
 
+/// Tests whose output doesn't parse back into the same code.
+///
+/// These use comments as indentation, which are parsed as comments again.
+const NON_REPARSABLE_TESTS: &[&str] = &["Configuration"];
+
 /// Tests a case from a directory
 fn test_case(test_dir: &Path) -> Result<(), AppError> {
 	let test_path = test_dir.join("input.rs");
@@ -54,12 +59,14 @@ fn test_case(test_dir: &Path) -> Result<(), AppError> {
 		.context("Unable to parse input")?;
 
 	let config = util::Config::default();
-	let _: FormatOutput = rustidy::format(&input, &config, &mut crate_);
+	let mut warnings = vec![];
+	let _: FormatOutput = rustidy::format(&input, &config, &mut crate_, &mut warnings);
+	ensure!(warnings.is_empty(), "Formatting emitted warnings: {warnings:?}");
 
 	let found_output = crate_.print_to(Print::print);
 
 	{
-		let _: FormatOutput = rustidy::format(&input, &config, &mut crate_);
+		let _: FormatOutput = rustidy::format(&input, &config, &mut crate_, &mut vec![]);
 
 		let found_output2 = crate_.print_to(Print::print);
 
@@ -70,6 +77,29 @@ fn test_case(test_dir: &Path) -> Result<(), AppError> {
 		);
 	}
 
+	// Note: Formatting the output again should also yield the same output, since
+	//       any comments that were moved must still apply to the same nodes.
+	if !NON_REPARSABLE_TESTS
+		.iter()
+		.any(|name| test_dir.ends_with(name)) {
+		let mut crate_ = rustidy::parse(found_output.as_str(), &test_path)
+			.context("Unable to parse output")?;
+		let _: FormatOutput = rustidy::format(
+			found_output.as_str(),
+			&config,
+			&mut crate_,
+			&mut vec![],
+		);
+
+		let found_output2 = crate_.print_to(Print::print);
+
+		ensure!(
+			found_output.as_str() == found_output2.as_str(),
+			"Formatting the output did not yield the same output:\n{}",
+			difference::Changeset::new(found_output.as_str(), found_output2.as_str(), "\n")
+		);
+	}
+
 	let output_path = test_dir.join("output.rs");
 	match env::var("RUSTIDY_FORMAT_UPDATE_OUTPUT")
 		.is_ok_and(|value| !value.trim().is_empty()) {
@@ -0,0 +1,37 @@
+mod a {
+	#[cfg_attr(rustidy, rustidy::config(indent = "  "))]
+	fn a() { a; b; }
+
+	#[cfg_attr(any(rustidy, feature = "a"), rustidy::config(indent = "  "))]
+	fn a() { a; b; }
+
+	#[cfg_attr(all(rustidy, any(rustidy, feature = "a")), a, rustidy::config(indent = "  "))]
+	fn a() { a; b; }
+
+	#[cfg_attr(rustidy, cfg_attr(any(unix, rustidy), rustidy::config(indent = "  ")))]
+	fn a() { a; b; }
+
+	#[cfg_attr(not(rustidy), rustidy::config(indent = "  "))]
+	fn a() { a; b; }
+
+	#[cfg_attr(all(rustidy, not(rustidy)), rustidy::config(indent = "  "))]
+	fn a() { a; b; }
+
+	#[cfg_attr(any(), rustidy::config(indent = "  "))]
+	fn a() { a; b; }
+
+	#[cfg_attr(all(unix, not(rustidy)), rustidy::config(indent = "  "))]
+	fn a() { a; b; }
+}
+
+mod b {
+	#![cfg_attr(rustidy, rustidy::config(indent = "  "))]
+
+	fn a() { a; b; }
+}
+
+mod c {
+	#[cfg_attr(rustidy, rustidy::skip)]
+	fn a(  ) {  }
+	fn b(  ) {  }
+}
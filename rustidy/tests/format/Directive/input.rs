@@ -0,0 +1,47 @@
+mod a {
+	fn a() { a; b; }
+
+	// rustidy::config(indent = "  ")
+	fn a() { a; b; }
+
+	/* rustidy::config(indent = "    ") */
+	#[a]
+	fn a() { a; b; }
+
+	// rustidy::config(indent = "  ")
+	#[rustidy::config(indent = "    ")]
+	fn a() { a; b; }
+}
+
+mod b {
+	// rustidy::skip
+	fn a(  ) {  }
+	fn b(  ) {  }
+}
+
+mod c {
+	fn a(  ) {  }
+	// rustidy::skip-start
+	fn b(  ) {  }
+	  struct  C  {  }
+	// rustidy::skip-end
+	fn c(  ) {  }
+}
+
+fn d() {
+	let a  =  1;
+	// rustidy::skip-start
+	let b  =  2;
+	let c  =  3;
+	// rustidy::skip-end
+}
+
+fn e(  ) {  }
+
+fn f() {
+	let a = 1; // rustidy::skip
+	let   b   =   2;
+	// rustidy::skip-start
+	let   c   =   3;
+}
+fn   g( ) {}
@@ -44,6 +44,39 @@ fn function_call() {
 	a(|| {
 		looooooooooooooooooooooooooooooooooooooooooooooooong;
 	});
+
+	a(a, b, c,);
+	a(
+		a,
+		b,
+		c,
+	);
+	#[rustidy::config(max_call_args_len = 5)]
+	a(a, b, c);
+
+	a(aaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbb, || {
+		let _ = a;
+	});
+	a(aaaaaaaaaaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbbbb, || {
+		let _ = a;
+	});
+	a(aaaaaaaaaaaaaaaa, S { aaaaaaaaaaaaaaaa: 1, bbbbbbbbbbbbbbbb: 2, cccccccccccccccc: 3, dddd: 4 });
+}
+
+fn binary() {
+	let _ = a && b && c;
+	let _ = aaaaaaaaaaaa && bbbbbbbbbbbbbbbbbbbbb && cccccccccccccccccccccccc && dddddddddddddddddddddddd;
+	let _ = aaaaaaaaaaaaaaaa * bbbbbbbbbbbbbbbbbbbbb + cccccccccccccccccccccccc * dddddddddddddddddddddddd - e;
+	let _ = f(aaaaaaaaaaaaaaaaaaaaaaaaa && bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb) && cccccccccccccccccc;
+
+	#[rustidy::config(max_binary_expr_len = 10)]
+	let _ = a + b == c + d;
+
+	if aaaaaaaaaaaaaaaaaaaa == bbbbbbbbbbbbbbbbbbbbb && cccccccccccccccccccccc != dddddddddddddddddddddddd {
+		let _ = a;
+	}
+	while aaaaaaaaaaaaaaaaaaaa.is_some() || bbbbbbbbbbbbbbbbbbbbb.is_none() || f(ccccccccccccccccccccccccc) {}
+	if let Some(aaaaaaaaaaaaaaaa) = bbbbbbbbbbbbbbbbbbbbb && let Some(c) = dddddddddddddddddddd && eeeeeeeeee {}
 }
 
 fn doc_comment_newline() {
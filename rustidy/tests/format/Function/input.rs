@@ -31,4 +31,16 @@ fn a(  &'a  mut  self  ,  );
 fn a(a:u32,b:u32);
 fn a(  a  :  u32  ,  b  :  u32  );
 
-fn a(&self, a: u32, loooooooooooooooooooooooooooooooooooong: u32, b: u32);
\ No newline at end of file
+fn a(&self, a: u32, loooooooooooooooooooooooooooooooooooong: u32, b: u32);
+fn a(a: u32, b: u32,);
+fn a(
+	a: u32,
+	b: u32,
+);
+fn a(&self, a: u32, b: u32) -> Result<Looooooooooooooooooooooooooooooooooooooooong, Looooooooooooong>;
+fn a(#[cfg(a)] a: u32, #[cfg(b)] b: u32, #[cfg(c)] c: u32, d: u32);
+unsafe extern "C" fn a(format: *const c_char, aaaaaaaaaaaaaaaaaaaa: u32, bbbbbbbbbbbbbbbbbbbb: u32, ...);
+#[rustidy::config(max_fn_params_len = 10)]
+fn a(a: u32, b: u32);
+#[rustidy::config(max_fn_params_len = 10)]
+fn a(&mut self) {}
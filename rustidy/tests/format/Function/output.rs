@@ -56,5 +56,33 @@ fn a(
 	&self,
 	a: u32,
 	loooooooooooooooooooooooooooooooooooong: u32,
-	b: u32
+	b: u32,
 );
+fn a(a: u32, b: u32,);
+fn a(a: u32, b: u32);
+fn a(
+	&self,
+	a: u32,
+	b: u32,
+) -> Result<Looooooooooooooooooooooooooooooooooooooooong, Looooooooooooong>;
+fn a(
+	#[cfg(a)] a: u32,
+	#[cfg(b)] b: u32,
+	#[cfg(c)] c: u32,
+	d: u32,
+);
+unsafe extern "C" fn a(
+	format: *const c_char,
+	aaaaaaaaaaaaaaaaaaaa: u32,
+	bbbbbbbbbbbbbbbbbbbb: u32,
+	...
+);
+#[rustidy::config(max_fn_params_len = 10)]
+fn a(
+	a: u32,
+	b: u32,
+);
+#[rustidy::config(max_fn_params_len = 10)]
+fn a(
+	&mut self,
+) {}
@@ -0,0 +1,33 @@
+fn a<T, U,>() {}
+fn a<
+	T,
+	U,
+>() {}
+fn a<Tttttttttttttttttttt, Uuuuuuuuuuuuuuuuuuuuu, Vvvvvvvvvvvvvvvvvvvvvvvv>() {}
+fn a<T: Clone + Send + Sync + 'static + core::fmt::Debug + core::fmt::Display>() {}
+fn a<T>() where T: Clone {}
+fn a<T>() where T: Clone, U: Copy, ;
+fn a<T>() where T: Clone + Send + Sync + 'static + core::fmt::Debug + core::fmt::Display {}
+
+struct A<T> where T: Clone { a: T }
+struct A<T>(T) where T: Clone,;
+enum A<T> where T: Clone { A(T) }
+union A<T> where T: Copy { a: T }
+trait A where Self: Sized {}
+impl<T> A<T> where T: Clone {}
+impl<T> B for A<T> where T: Clone {}
+type A<T> where T: Clone = T;
+
+#[rustidy::config(where_style = "auto")]
+fn a<T>() where T: Clone {}
+#[rustidy::config(where_style = "auto")]
+impl<T> B for A<T> where T: Clone, U: Copy {}
+#[rustidy::config(where_style = "auto")]
+fn a<T>() where Tttttttttttttttttttt: Clone, Uuuuuuuuuuuuuuuuuuuuu: Copy {}
+#[rustidy::config(where_style = "auto")]
+struct A<T>(T) where T: Clone,;
+
+#[rustidy::config(max_generic_params_len = 5)]
+fn a<T, U>() {}
+#[rustidy::config(max_bounds_len = 5)]
+fn a<T>() where T: Clone + Copy {}
@@ -0,0 +1,94 @@
+#![rustidy::config(max_width = 40)]
+#![rustidy::config(tab_width = 4)]
+
+const A: [u32; 4] = [1, 2, 3, 4];
+const B: [u32; 12] = [
+	1,
+	2,
+	3,
+	4,
+	5,
+	6,
+	7,
+	8,
+	9,
+	10,
+	11,
+	12
+];
+
+fn f() {
+	f(aaaa, bbbb, cccc, dddd, eeee);
+	f(
+		aaaaaaaa,
+		bbbbbbbb,
+		cccccccc,
+		dddddddd,
+	);
+	aaaa.bbbb();
+	aaaaaaaa
+		.bbbbbbbb()
+		.cccccccc()
+		.dddddddd();
+
+	{
+		{
+			{
+				f(
+					aaaa,
+					bbbb,
+					cccc,
+					dddd,
+					eeee,
+				);
+			}
+		}
+	}
+}
+
+use {
+	a::{b, c},
+	aaaa::{
+		bbbbbbbb,
+		cccccccc,
+		dddddddd,
+		eeeeeeee,
+	},
+};
+
+struct S(u32, u32);
+struct T(
+	aaaaaaaa,
+	bbbbbbbb,
+	cccccccc,
+	dddddddd,
+);
+
+#[rustidy::config(max_width = 100)]
+fn g() {
+	f(aaaaaaaa, bbbbbbbb, cccccccc, dddddddd);
+}
+
+fn h() {
+	f("日本語日本語", "日本語日本語");
+	f(/* 🦀🦀🦀 */aaaa, bbbb, cccc);
+}
+
+#[rustidy::config(tab_width = 8)]
+fn h() {
+	f(
+		"日本語日本語",
+		"日本語日本語",
+	);
+}
+
+// Note: These aren't split yet, and overflow the line.
+impl Aaaaaaaa for Bbbbbbbbbbbbbbbbbbbbbbbbbbbbbb {}
+
+fn i() {
+	let (aaaaaaaa, bbbbbbbb, cccccccc, dddddddd) = e;
+	let _: Aaaaaaaa<Bbbbbbbb, Cccccccc, Dddddddd> = e;
+	let _ = f::<Aaaaaaaa, Bbbbbbbb, Cccccccc, Dddd>();
+	let _ = (aaaaaaaa, bbbbbbbb, cccccccc, dddddddd);
+	let _ = |aaaaaaaa, bbbbbbbb, cccccccc, dddddddd| e;
+}
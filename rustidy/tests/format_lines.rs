@@ -0,0 +1,80 @@
+//! Line range format tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	core::ops::RangeInclusive,
+	std::path::Path,
+};
+
+#[test]
+pub fn format_lines() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let input = "fn a(){\nlet x=[1,2];\n}\nfn b(){\nlet  y  =  2;\n}\nstruct  A{a:u32,b:u32}\n";
+	let cases: [(&[RangeInclusive<usize>], &str); _] = [
+		(&[], input),
+		(&[1..=3], "fn a() {\n\tlet x = [1, 2];\n}\nfn b(){\nlet  y  =  2;\n}\nstruct  A{a:u32,b:u32}\n"),
+		(&[5..=5], "fn a(){\nlet x=[1,2];\n}\nfn b(){\n\tlet y = 2;\n}\nstruct  A{a:u32,b:u32}\n"),
+		(&[2..=2, 7..=7], "fn a(){\n\tlet x = [1, 2];\n}\nfn b(){\nlet  y  =  2;\n}\nstruct A {\n\ta: u32,\n\tb: u32\n}\n"),
+	];
+
+	for (lines, expected) in cases {
+		self::test_case(input, lines, expected)
+			.with_context(|| format!("Test case {lines:?} failed"))?;
+	}
+
+	// Note: The indentation of the line after the ranges must be kept, even
+	//       if it's also misformatted.
+	let input = "fn a(){\nlet  x=1;\nlet  y=2;\nlet  z=3;\n}\n";
+	let cases: [(&[RangeInclusive<usize>], &str); _] = [
+		(&[2..=2], "fn a(){\n\tlet x = 1;\nlet  y=2;\nlet  z=3;\n}\n"),
+		(&[3..=3], "fn a(){\nlet  x=1;\n\tlet y = 2;\nlet  z=3;\n}\n"),
+		(&[2..=3], "fn a(){\n\tlet x = 1;\n\tlet y = 2;\nlet  z=3;\n}\n"),
+		(&[2..=2, 4..=4], "fn a(){\n\tlet x = 1;\nlet  y=2;\n\tlet z = 3;\n}\n"),
+	];
+
+	for (lines, expected) in cases {
+		self::test_case(input, lines, expected)
+			.with_context(|| format!("Test case {lines:?} failed"))?;
+	}
+
+	// Note: Merging `use`s changes multiple lines at once, so it may only
+	//       happen when all of them are within the ranges.
+	let input = "use c;\nuse b;\nuse a;\nfn f(){}\n";
+	let cases: [(&[RangeInclusive<usize>], &str); _] = [
+		(&[1..=1], input),
+		(&[3..=3], input),
+		(&[2..=4], "use c;\nuse {a, b};\nfn f() {}\n"),
+		(&[1..=3], "use {a, b, c};\nfn f(){}\n"),
+		(&[1..=1, 2..=3], "use {a, b, c};\nfn f(){}\n"),
+	];
+
+	for (lines, expected) in cases {
+		self::test_case(input, lines, expected)
+			.with_context(|| format!("Test case {lines:?} failed"))?;
+	}
+
+	Ok(())
+}
+
+fn test_case(
+	input: &str,
+	lines: &[RangeInclusive<usize>],
+	expected: &str,
+) -> Result<(), AppError> {
+	let mut crate_ = rustidy::parse(input, Path::new("<input>"))
+		.context("Unable to parse input")?;
+
+	let config = util::Config::default();
+	let found = rustidy::format_lines(input, &config, &mut crate_, lines, &mut vec![]);
+	ensure!(found.as_str() == expected, "Found wrong output.\nExpected: {expected:?}\nFound   : {found:?}");
+
+	Ok(())
+}
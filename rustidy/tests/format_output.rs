@@ -62,12 +62,29 @@ pub fn format_output() -> Result<(), AppError> {
 			is_blank: false,
 			multiline: Some(FormatMultilineOutput { prefix_ws_len: Some(2), prefix_len: 2, suffix_len: 3 }),
 		},
+		"\tabc" as IdentifierOrKeyword: FormatOutput {
+			prefix_ws_len: Some(1),
+			len: 4,
+			is_empty: false,
+			is_blank: false,
+			multiline: None,
+		},
+		"/* 日本 */\nabc" as IdentifierOrKeyword: FormatOutput {
+			prefix_ws_len: Some(11),
+			len: 14,
+			is_empty: false,
+			is_blank: false,
+			multiline: Some(FormatMultilineOutput { prefix_ws_len: Some(10), prefix_len: 10, suffix_len: 3 }),
+		},
 	}
 
 	Ok(())
 }
 
-pub fn test_case<T: Parse + Formattable>(input: &str, expected: FormatOutput) -> Result<(), AppError> {
+pub fn test_case<T: Parse + Formattable>(
+	input: &str,
+	expected: FormatOutput,
+) -> Result<(), AppError> {
 	let mut parser = Parser::new(input);
 	let mut value = parser
 		.parse::<T>()
@@ -98,10 +115,20 @@ pub fn format_multiline_output_from_str() -> Result<(), AppError> {
 			prefix_len: 5,
 			suffix_len: 3,
 		})),
+		("\t01\n\t\t2", Some(FormatMultilineOutput {
+			prefix_ws_len: None,
+			prefix_len: 6,
+			suffix_len: 9,
+		})),
+		("日本\n語🦀", Some(FormatMultilineOutput {
+			prefix_ws_len: None,
+			prefix_len: 4,
+			suffix_len: 4,
+		})),
 	];
 
 	for (input, expected) in cases {
-		let output = FormatMultilineOutput::from_str(input);
+		let output = FormatMultilineOutput::from_str(input, 4);
 		ensure!(output == expected, "Format output was different for input {input:?}\n{}", difference::Changeset::new(
 			&format!("{expected:#?}"),
 			&format!("{output:#?}"),
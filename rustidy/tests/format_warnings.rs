@@ -0,0 +1,84 @@
+//! Format warning tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	format::FormatOutput,
+	parse::ParserLoc,
+	std::path::Path,
+};
+
+#[test]
+pub fn format_warnings() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let cases: [(&str, &[(&str, &str)]); _] = [
+		("#[rustidy::config(max_chain_len = 2)]\nfn f() {}\n", &[]),
+		("#[inline]\n#[rustidy::config(max_chian_len = 2)]\nfn f() {}\n", &[
+			("2:19", "Malformed `#[rustidy]` attribute: Unknown configuration `max_chian_len`, did you mean `max_chain_len`?")
+		]),
+		("#![rustidy::config(indent = 4)]\n", &[
+			("1:20", "Malformed `#[rustidy]` attribute: Expected a string literal")
+		]),
+		("fn f() {\n\t#![rustidy::config(foo = 4)]\n}\n", &[
+			("2:21", "Malformed `#[rustidy]` attribute: Unknown configuration `foo`")
+		]),
+		("#[rustidy::skp]\nfn f() {}\n", &[
+			("1:3", "Malformed `#[rustidy]` attribute: Unknown attribute `rustidy::skp`, did you mean `rustidy::skip`?")
+		]),
+		("// rustidy::config(max_chian_len = 2)\nfn f() {}\n", &[
+			("1:20", "Malformed `rustidy` directive: Unknown configuration `max_chian_len`, did you mean `max_chain_len`?")
+		]),
+		("// rustidy::config(\nfn f() {}\n", &[
+			("1:4", "Malformed `rustidy` directive: \"rustidy::config(\"")
+		]),
+		("#[cfg_attr(unix, rustidy::skip)]\nfn f() {}\n", &[
+			("1:12", "Malformed `#[rustidy]` attribute: Unable to evaluate `cfg_attr` predicate, only the `rustidy` cfg is known"),
+		]),
+		("#[cfg_attr(all(rustidy, not(unix)), rustidy::config(indent = \"  \"))]\nfn f() {}\n", &[
+			("1:12", "Malformed `#[rustidy]` attribute: Unable to evaluate `cfg_attr` predicate, only the `rustidy` cfg is known"),
+		]),
+		("#[cfg_attr(rustidy, cfg_attr(unix, rustidy::skip))]\nfn f() {}\n", &[
+			("1:30", "Malformed `#[rustidy]` attribute: Unable to evaluate `cfg_attr` predicate, only the `rustidy` cfg is known"),
+		]),
+		("#[cfg_attr(unix, inline)]\n#[cfg_attr(all(unix, not(rustidy)), rustidy::skip)]\nfn f() {}\n", &[]),
+	];
+
+	for (input, expected) in cases {
+		self::test_case(input, expected)
+			.with_context(|| format!("Test case {input:?} failed"))?;
+	}
+
+	Ok(())
+}
+
+fn test_case(input: &str, expected: &[(&str, &str)]) -> Result<(), AppError> {
+	let mut crate_ = rustidy::parse(input, Path::new("<input>"))
+		.context("Unable to parse input")?;
+
+	let config = util::Config::default();
+	let mut warnings = vec![];
+	let _: FormatOutput = rustidy::format(input, &config, &mut crate_, &mut warnings);
+
+	let found = warnings
+		.iter()
+		.map(|warning| {
+			let pos = warning.pos.context("Warning had no position")?;
+			let loc = ParserLoc::from_pos(input, pos).to_string();
+			Ok((loc, warning.message.as_str()))
+		})
+		.collect::<Result<Vec<_>, AppError>>()?;
+	let found = found
+		.iter()
+		.map(|(loc, message)| (loc.as_str(), *message))
+		.collect::<Vec<_>>();
+	ensure!(found == expected, "Found wrong warnings.\nExpected: {expected:?}\nFound   : {found:?}");
+
+	Ok(())
+}
@@ -0,0 +1,75 @@
+//! Parallel formatting tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	std::{env, fs, path::Path, process::{self, Command}},
+};
+
+#[test]
+pub fn jobs() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let dir = env::temp_dir()
+		.join(format!("rustidy-test-jobs-{}", process::id()));
+	let files = [
+		("src/lib.rs", "mod foo;\nmod baz;\nfn lib(){}\n"),
+		("src/foo.rs", "fn foo(){}\n"),
+		("src/bar.rs", "fn bar(){}\n"),
+		("src/baz.rs", "mod qux;\nfn baz(){}\n"),
+		("src/baz/qux.rs", "fn qux(){}\n"),
+	];
+	for (path, contents) in files {
+		let path = dir.join(path);
+		fs::create_dir_all(path.parent().expect("File had no parent"))
+			.context("Unable to create directory")?;
+		fs::write(&path, contents)
+			.context("Unable to write file")?;
+	}
+
+	// Note: `src/foo.rs` is both passed directly and reached from `src/lib.rs`,
+	//       so it must always be emitted as a module of the latter.
+	let expected_files = ["src/lib.rs", "src/foo.rs", "src/baz.rs", "src/baz/qux.rs", "src/bar.rs"];
+	let expected = self::check_diff(&dir, 1)?;
+	let found_files = expected
+		.lines()
+		.filter_map(|line| line.strip_prefix("--- "))
+		.collect::<Vec<_>>();
+	ensure!(
+		found_files == expected_files,
+		"Found wrong file order.\nExpected: {expected_files:?}\nFound   : {found_files:?}"
+	);
+
+	for jobs in [2, 3, 4, 8].into_iter().cycle().take(20) {
+		let found = self::check_diff(&dir, jobs)?;
+		ensure!(
+			found == expected,
+			"Found different output with {jobs} jobs.\nExpected: {expected:?}\nFound   : {found:?}"
+		);
+	}
+
+	fs::remove_dir_all(&dir)
+		.context("Unable to remove directory")?;
+
+	Ok(())
+}
+
+/// Checks the files of `dir` with `jobs` jobs and returns the diff
+fn check_diff(dir: &Path, jobs: usize) -> Result<String, AppError> {
+	let output = Command::new(env!("CARGO_BIN_EXE_rustidy"))
+		.current_dir(dir)
+		.args(["--check", "--emit", "diff", "--jobs"])
+		.arg(jobs.to_string())
+		.args(["src/lib.rs", "src/bar.rs", "src/foo.rs"])
+		.output()
+		.context("Unable to run rustidy")?;
+
+	String::from_utf8(output.stdout)
+		.context("Output wasn't utf-8")
+}
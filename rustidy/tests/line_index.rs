@@ -0,0 +1,85 @@
+//! Line index tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	util::{AstPos, ColumnUnit, LineCol, LineIndex},
+};
+
+/// Test case, with the position and it's expected line and columns in bytes, chars and UTF-16
+type Case = (usize, (usize, usize, usize, usize));
+
+#[test]
+pub fn line_index() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let input = "aé😀b\r\n\nc\n";
+	let line_index = LineIndex::new(input);
+	ensure!(line_index.line_count() == 4, "Found wrong line count: {}", line_index.line_count());
+
+	let lines = (0..line_index
+		.line_count())
+		.map(|line| line_index.line(line))
+		.collect::<Vec<_>>();
+	ensure!(lines == [Some("aé😀b"), Some(""), Some("c"), Some("")], "Found wrong lines: {lines:?}");
+
+	let cases: [Case; _] = [
+		(0, (0, 0, 0, 0)),
+		(1, (0, 1, 1, 1)),
+		(3, (0, 3, 2, 2)),
+		(7, (0, 7, 3, 4)),
+		(8, (0, 8, 4, 5)),
+		(10, (1, 0, 0, 0)),
+		(11, (2, 0, 0, 0)),
+		(12, (2, 1, 1, 1)),
+		(13, (3, 0, 0, 0)),
+	];
+	for (pos, expected) in cases {
+		self::test_case(&line_index, pos, expected)
+			.with_context(|| format!("Test case {pos} failed"))?;
+	}
+
+	// Note: Columns past the end of the line are clamped, and columns in
+	//       the middle of a character are rounded down.
+	let clamped = [
+		((0, 100), ColumnUnit::Bytes, 8),
+		((0, 5), ColumnUnit::Bytes, 3),
+		((0, 3), ColumnUnit::Utf16, 3),
+		((2, 5), ColumnUnit::Chars, 12),
+	];
+	for ((line, column), unit, expected) in clamped {
+		let found = line_index.pos(LineCol { line, column }, unit);
+		ensure!(found == Some(AstPos(expected)), "Found wrong position for {line}:{column} in {unit:?}.\nExpected: {expected}\nFound   : {found:?}");
+	}
+	ensure!(line_index.pos(LineCol { line: 4, column: 0 }, ColumnUnit::Bytes).is_none(), "Found position for a missing line");
+
+	Ok(())
+}
+
+fn test_case(
+	line_index: &LineIndex,
+	pos: usize,
+	expected: (usize, usize, usize, usize),
+) -> Result<(), AppError> {
+	let (line, bytes, chars, utf16) = expected;
+	for (unit, column) in [
+		(ColumnUnit::Bytes, bytes),
+		(ColumnUnit::Chars, chars),
+		(ColumnUnit::Utf16, utf16)
+	] {
+		let expected = LineCol { line, column };
+		let found = line_index.line_col(AstPos(pos), unit);
+		ensure!(found == expected, "Found wrong location in {unit:?}.\nExpected: {expected:?}\nFound   : {found:?}");
+
+		let found = line_index.pos(expected, unit);
+		ensure!(found == Some(AstPos(pos)), "Found wrong position in {unit:?}.\nExpected: {pos}\nFound   : {found:?}");
+	}
+
+	Ok(())
+}
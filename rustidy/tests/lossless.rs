@@ -0,0 +1,75 @@
+//! Lossless serialization tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	format::FormatOutput,
+	print::Print,
+	std::path::Path,
+	util::Lossless,
+};
+
+#[test]
+pub fn lossless() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let cases = [
+		"fn main() {}\n",
+		"fn f(  a : u32 ,b:u32) -> u32 {\n    // Comment\n    a+b\n}\n",
+		"/* Block */ mod m {\n  struct A { a: u32, b: String }\n\n\n\n  impl A { fn f(&self) { let x = [1,2,3]; } }\n}\n",
+		"#[rustidy::config(indent = \"  \")]\nfn f() {\n\tif a { b } else {\n\t\tc\n\t}\n}\n",
+		"// rustidy::skip\nconst A: u32 = 1    +    2;\nconst B: u32 = 1    +    2;\n",
+		"const S: &str = \"é😀\";   fn   g ( ) { }\n",
+	];
+
+	for input in cases {
+		self::test_case(input)
+			.with_context(|| format!("Test case {input:?} failed"))?;
+	}
+
+	Ok(())
+}
+
+fn test_case(input: &str) -> Result<(), AppError> {
+	let crate_ = rustidy::parse(input, Path::new("<input>"))
+		.context("Unable to parse input")?;
+
+	let mut output = self::round_trip(&Lossless::new(input, crate_))?;
+	let found = output.ast.print_to(Print::print);
+	ensure!(found.as_str() == input, "Deserialized crate printed differently.\nExpected: {input:?}\nFound   : {:?}", found.as_str());
+
+	// Note: We format the deserialized crate, to ensure it's input ranges were kept
+	let config = util::Config::default();
+	let mut expected = rustidy::parse(input, Path::new("<input>"))
+		.context("Unable to parse input")?;
+	let _: FormatOutput = rustidy::format(input, &config, &mut expected, &mut vec![]);
+	let expected = expected.print_to(Print::print);
+	let _: FormatOutput = rustidy::format(
+		&output.input,
+		&config,
+		&mut output.ast,
+		&mut vec![],
+	);
+	let found = output.ast.print_to(Print::print);
+	ensure!(found.as_str() == expected.as_str(), "Deserialized crate formatted differently.\nExpected: {:?}\nFound   : {:?}", expected.as_str(), found.as_str());
+
+	let output = self::round_trip(&output)?;
+	let found = output.ast.print_to(Print::print);
+	ensure!(found.as_str() == expected.as_str(), "Deserialized formatted crate printed differently.\nExpected: {:?}\nFound   : {:?}", expected.as_str(), found.as_str());
+
+	Ok(())
+}
+
+/// Serializes and deserializes a crate
+fn round_trip(crate_: &Lossless<ast::Crate>) -> Result<Lossless<ast::Crate>, AppError> {
+	let output = serde_json::to_string(crate_)
+		.context("Unable to serialize crate")?;
+	serde_json::from_str(&output)
+		.context("Unable to deserialize crate")
+}
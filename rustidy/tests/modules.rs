@@ -0,0 +1,155 @@
+//! Module discovery tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	std::{env, fs, path::Path, process::{self, Command}},
+};
+
+#[test]
+pub fn modules() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let cases = [
+		Case {
+			files: &[
+				("src/lib.rs", "mod a;\nmod b {\n\tmod c;\n}\nfn f(){}\n"),
+				("src/a.rs", "mod d;\nfn f(){}\n"),
+				("src/a/d.rs", "fn f(){}\n"),
+				("src/b/c.rs", "fn f(){}\n"),
+			],
+			roots: &["src/lib.rs"],
+			expected: &["src/lib.rs", "src/a.rs", "src/a/d.rs", "src/b/c.rs"],
+			warnings: &[],
+		},
+		Case {
+			files: &[
+				("src/bin/tool.rs", "mod helper;\nfn f(){}\n"),
+				("src/bin/helper.rs", "fn f(){}\n"),
+			],
+			roots: &["src/bin/tool.rs"],
+			expected: &["src/bin/tool.rs", "src/bin/helper.rs"],
+			warnings: &[],
+		},
+		Case {
+			files: &[("src/foo.rs", "mod bar;\nfn f(){}\n"), ("src/bar.rs", "fn f(){}\n")],
+			roots: &["src/foo.rs"],
+			expected: &["src/foo.rs", "src/bar.rs"],
+			warnings: &[],
+		},
+		Case {
+			files: &[
+				("src/lib.rs", "#[path = \"x.rs\"]\nmod a;\n#[path = \"x.rs\"]\nmod b;\nfn f(){}\n"),
+				("src/x.rs", "fn f(){}\n"),
+			],
+			roots: &["src/lib.rs"],
+			expected: &["src/lib.rs", "src/x.rs"],
+			warnings: &[],
+		},
+		Case {
+			files: &[("src/lib.rs", "mod a;\nfn f(){}\n"), ("src/a.rs", "fn f(){}\n")],
+			roots: &["src/a.rs", "src/lib.rs", "src/a.rs"],
+			expected: &["src/a.rs", "src/lib.rs"],
+			warnings: &[],
+		},
+		Case {
+			files: &[
+				("src/lib.rs", "mod a;\nfn f(){}\n"),
+				("src/a.rs", "#[path = \"lib.rs\"]\nmod b;\nfn f(){}\n"),
+			],
+			roots: &["src/lib.rs"],
+			expected: &["src/lib.rs", "src/a.rs"],
+			warnings: &["Found module cycle, skipping: "],
+		},
+		Case {
+			files: &[
+				("src/lib.rs", "mod a;\nfn f(){}\n"),
+				("src/a.rs", "fn f(){}\n"),
+				("src/b.rs", "fn f(){}\n"),
+				("src/bin/c.rs", "fn f(){}\n"),
+			],
+			roots: &["src/lib.rs"],
+			expected: &["src/lib.rs", "src/a.rs"],
+			warnings: &["b.rs\": File isn't reachable from any module and wasn't formatted"],
+		},
+	];
+
+	for (idx, case) in cases.into_iter().enumerate() {
+		let dir = env::temp_dir().join(
+			format!("rustidy-test-modules-{}-{idx}", process::id()),
+		);
+		self::test_case(&dir, &case)
+			.with_context(|| format!("Test case {:?} failed", case.roots))?;
+		fs::remove_dir_all(&dir)
+			.context("Unable to remove directory")?;
+	}
+
+	Ok(())
+}
+
+/// Test case
+struct Case<'a> {
+	/// Files of the tree, alongside their contents
+	files:    &'a [(&'a str, &'a str)],
+
+	/// Files to pass to rustidy
+	roots:    &'a [&'a str],
+
+	/// Files expected to be formatted, in order
+	expected: &'a [&'a str],
+
+	/// Warnings expected to be emitted
+	warnings: &'a [&'a str],
+}
+
+fn test_case(dir: &Path, case: &Case) -> Result<(), AppError> {
+	for (path, contents) in case.files {
+		let path = dir.join(path);
+		fs::create_dir_all(path.parent().expect("File had no parent"))
+			.context("Unable to create directory")?;
+		fs::write(&path, contents)
+			.context("Unable to write file")?;
+	}
+
+	let output = Command::new(env!("CARGO_BIN_EXE_rustidy"))
+		.current_dir(dir)
+		.args(["--check", "--emit", "diff"])
+		.args(case.roots)
+		.output()
+		.context("Unable to run rustidy")?;
+	let stdout = String::from_utf8(output.stdout)
+		.context("Output wasn't utf-8")?;
+	let stderr = String::from_utf8(output.stderr)
+		.context("Output wasn't utf-8")?;
+
+	let found = stdout
+		.lines()
+		.filter_map(|line| line.strip_prefix("--- "))
+		.collect::<Vec<_>>();
+	ensure!(
+		found == case.expected,
+		"Found wrong files.\nExpected: {:?}\nFound   : {found:?}",
+		case.expected
+	);
+
+	let warnings = stderr
+		.lines()
+		.filter(|line| line.contains("WARN"))
+		.collect::<Vec<_>>();
+	ensure!(
+		warnings.len() == case.warnings.len() && case
+			.warnings
+			.iter()
+			.all(|warning| warnings.iter().any(|line| line.contains(warning))),
+		"Found wrong warnings.\nExpected: {:?}\nFound   : {warnings:?}",
+		case.warnings
+	);
+
+	Ok(())
+}
@@ -0,0 +1 @@
+fn a(){let y = @@;}
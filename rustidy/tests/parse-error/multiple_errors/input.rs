@@ -0,0 +1,16 @@
+fn f() {
+	let a = 1
+	let b = 2;
+}
+
+struct A {
+	a: u32
+	b: u32,
+}
+
+fn g() {
+	match a {
+		1 => 2
+		3 => 4,
+	}
+}
@@ -85,7 +85,7 @@ fn test_case(test_dir: &Path) -> Result<(), AppError> {
 				&output,
 				assert_json_diff::Config::new(assert_json_diff::CompareMode::Strict),
 			).map_err(
-				|err| app_error!("Crate differed from expected:\n{err}")
+				|err| app_error!("Crate differed from expected:\n{err}"),
 			)?;
 		},
 	}
@@ -17,7 +17,7 @@ pub fn parse_error() -> Result<(), AppError> {
 	match env::var_os("RUSTIDY_PARSE_ERROR_UPDATE_TESTS") {
 		Some(tests) => {
 			let tests = tests.to_str().context(
-				"`RUSTIDY_PARSE_ERROR_UPDATE_TESTS` must be utf-8"
+				"`RUSTIDY_PARSE_ERROR_UPDATE_TESTS` must be utf-8",
 			)?;
 			for test_dir in tests.split(':') {
 				self::test_case(Path::new(test_dir))
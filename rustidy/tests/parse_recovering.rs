@@ -0,0 +1,78 @@
+//! Error-recovering parse tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	format::FormatOutput,
+	print::Print,
+	std::path::Path,
+};
+
+/// Test case, with the input, the expected output and the expected skipped regions
+type Case = (&'static str, &'static str, &'static [(&'static str, &'static str)]);
+
+#[test]
+pub fn parse_recovering() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let cases: [Case; _] = [
+		("fn f(  ) {}\n", "fn f() {}\n", &[]),
+		("const A: u32 = 1 + ;\nfn f(  ) {}\n", "const A: u32 = 1 + ;\nfn f() {}\n", &[("1:1", "1:21")]),
+		("fn f() {\n    let a = 1 ;\n    foo(1 +);\n    if a { b } else {\n        c\n    }\n}\n", "fn f() {\n\tlet a = 1;\n\tfoo(1 +);\n\tif a { b } else { c }\n}\n", &[
+			("3:5", "3:14")
+		]),
+		("fn f() {\n    foo(1 +)\n}\n", "fn f() {\n\tfoo(1 +)\n}\n", &[("2:5", "2:13")]),
+		("impl S {\n    fn f() -> impl ! { \"}\" }\n    fn g(  ) {}\n}\n", "impl S {\n\tfn f() -> impl ! { \"}\" }\n\tfn g() {}\n}\n", &[
+			("2:5", "2:29")
+		]),
+		("mod m {\n    foo! 1 2;\n    struct A { }\n}\n", "mod m {\n\tfoo! 1 2;\n\tstruct A {}\n}\n", &[
+			("2:5", "2:14")
+		]),
+		("fn f() { a?? +; }\nfn g() {}\n", "fn f() {\n\ta?? +;\n}\nfn g() {}\n", &[("1:10", "1:16")]),
+		("impl S {\n    fn f() -> { } fn   g( ) {}\n}\n", "impl S {\n\tfn f() -> { }\n\tfn g() {}\n}\n", &[
+			("2:5", "2:18")
+		]),
+		("trait T {\n    fn f() -> { } fn   g( );\n}\n", "trait T {\n\tfn f() -> { }\n\tfn g();\n}\n", &[
+			("2:5", "2:18")
+		]),
+	];
+
+	for (input, expected_output, expected_skipped) in cases {
+		self::test_case(input, expected_output, expected_skipped)
+			.with_context(|| format!("Test case {input:?} failed"))?;
+	}
+
+	Ok(())
+}
+
+fn test_case(
+	input: &str,
+	expected_output: &str,
+	expected_skipped: &[(&str, &str)],
+) -> Result<(), AppError> {
+	let (mut crate_, skipped) = rustidy::parse_recovering(input, Path::new("<input>"))
+		.map_err(|err| err.to_app_error())
+		.context("Unable to parse input")?;
+
+	let skipped = skipped.iter().map(
+		|region| (region.start.to_string(), region.end.to_string()),
+	).collect::<Vec<_>>();
+	let skipped = skipped
+		.iter()
+		.map(|(start, end)| (start.as_str(), end.as_str()))
+		.collect::<Vec<_>>();
+	ensure!(skipped == expected_skipped, "Found wrong skipped regions.\nExpected: {expected_skipped:?}\nFound   : {skipped:?}");
+
+	let config = util::Config::default();
+	let _: FormatOutput = rustidy::format(input, &config, &mut crate_, &mut vec![]);
+	let output = crate_.print_to(Print::print);
+	ensure!(output.as_str() == expected_output, "Found wrong output.\nExpected: {expected_output:?}\nFound   : {:?}", output.as_str());
+
+	Ok(())
+}
@@ -0,0 +1,70 @@
+//! Check report tests
+
+// Features
+#![feature(yeet_expr)]
+
+// Lints
+#![expect(unused_crate_dependencies, reason = "They're used in other crates in this package")]
+
+// Imports
+use {
+	app_error::{AppError, Context, ensure},
+	std::{fs, path::Path, process::Command},
+};
+
+#[test]
+pub fn report() -> Result<(), AppError> {
+	let _logger = zutil_logger::Logger::new();
+
+	let dir = Path::new("tests/report");
+	let cases = [
+		("json", "output.json"),
+		("checkstyle", "output.xml"),
+		("sarif", "output.sarif"),
+	];
+	for (format, output) in cases {
+		self::test_case(dir, format, &dir.join(output))
+			.with_context(|| format!("Test case {format:?} failed"))?;
+	}
+
+	// Note: Absolute paths can't be relative uri references, so they must be `file://` uris.
+	let path = fs::canonicalize(dir.join("a b.rs"))
+		.context("Unable to canonicalize path")?;
+	let path = path.to_str().context("Path wasn't utf-8")?;
+	let output = self::run(dir, "sarif", &[path])?;
+	let output = serde_json::from_str::<serde_json::Value>(&output)
+		.context("Unable to parse report")?;
+	let location = &output["runs"][0]["results"][0]["locations"][0];
+	let uri = location["physicalLocation"]["artifactLocation"]["uri"]
+		.as_str()
+		.context("Missing uri")?;
+	ensure!(
+		uri.starts_with("file:///") && uri.ends_with("/tests/report/a%20b.rs"),
+		"Found wrong uri: {uri:?}"
+	);
+
+	Ok(())
+}
+
+fn test_case(dir: &Path, format: &str, output_path: &Path) -> Result<(), AppError> {
+	let found = self::run(dir, format, &["a b.rs", "err.rs"])?;
+	let expected = fs::read_to_string(output_path)
+		.context("Unable to read expected output")?
+		.replace("{version}", env!("CARGO_PKG_VERSION"));
+	ensure!(found == expected, "Found wrong output.\nExpected:\n{expected}\nFound:\n{found}");
+
+	Ok(())
+}
+
+/// Checks `files` within `dir`, returning the report in `format`
+fn run(dir: &Path, format: &str, files: &[&str]) -> Result<String, AppError> {
+	let output = Command::new(env!("CARGO_BIN_EXE_rustidy"))
+		.current_dir(dir)
+		.args(["--check", "--emit-report", format])
+		.args(files)
+		.output()
+		.context("Unable to run rustidy")?;
+
+	String::from_utf8(output.stdout)
+		.context("Output wasn't utf-8")
+}
@@ -0,0 +1,3 @@
+fn a() {
+	let y = 1
+}
@@ -31,7 +31,7 @@ fn test_case_with(
 		.parse::<Whitespace>()
 		.map_err(|err| err.to_app_error(&parser))
 		.with_context(
-			|| format!("Unable to parse whitespace: {source:?}")
+			|| format!("Unable to parse whitespace: {source:?}"),
 		)?;
 	ensure!(
 		parser.is_finished(),
@@ -98,9 +98,18 @@ fn test_cases_with(
 		[
 			(case.expected_remove, WhitespaceFormatKind::Remove),
 			(case.expected_set_single, WhitespaceFormatKind::Spaces { len: 1 }),
-			(case.expected_set_indent, WhitespaceFormatKind::Indent { use_prev: false, remove_if_pure: false, }),
-			(case.expected_set_prev_indent, WhitespaceFormatKind::Indent { use_prev: true, remove_if_pure: false, }),
-			(case.expected_set_prev_indent_or_remove, WhitespaceFormatKind::Indent { use_prev: true, remove_if_pure: true, }),
+			(case.expected_set_indent, WhitespaceFormatKind::Indent {
+				use_prev: false,
+				remove_if_pure: false,
+			}),
+			(case.expected_set_prev_indent, WhitespaceFormatKind::Indent {
+				use_prev: true,
+				remove_if_pure: false,
+			}),
+			(case.expected_set_prev_indent_or_remove, WhitespaceFormatKind::Indent {
+				use_prev: true,
+				remove_if_pure: true,
+			}),
 		].into_iter().map(|(expected, kind)| {
 			let mods = [("", ""), ("  ", ""), ("", "  "), ("  ", "  ")];
 			mods.into_iter().map(|(prefix, suffix)| {